@@ -0,0 +1,33 @@
+use aoc2017_cli::days::YEARS;
+use aoc2017_cli::scaffold::{
+    batch_or_exit, history_or_exit, load_config_or_exit, parse_args_or_exit, report_or_exit,
+    run_days, scaffold_or_exit, select_year_or_exit, submit_or_exit, watch_or_exit, Command,
+};
+
+/// Solves all of the AOC 2017 problems (or the single problem selected with --day), printing the
+/// solutions to stdout. Solvers are looked up in the registry for the year selected with --year
+/// (2017 by default). Other commands submit the answer for a single part ("submit"), generate the
+/// files for a new day ("scaffold"), re-run a day whenever its solver module or input file changes
+/// ("watch"), solve a day for every input file in a directory ("batch"), or report the time taken by
+/// each phase of each problem ("report"). With --record, each run is appended to the run history
+/// file, and "history" shows the trend of the execution times recorded for each day.
+pub fn main() {
+    let options = parse_args_or_exit(
+        "aoc [run|submit|scaffold|watch|batch|report|history] [--year <YEAR>] [--day <N>] \
+        [--part <1|2>] [--algo <NAME>] [--param <NAME=VALUE>]... [--visualize] [--cross-check] \
+        [--record] \
+        [--answer <ANSWER>] [--config <FILE>] [--input-dir <DIR>] [--format <text|compact>] \
+        [--inputs <DIR>] [--profile <FILE>] [--export <FILE>] [--export-dot <FILE>]",
+    );
+    let config = load_config_or_exit(&options);
+    let year = || select_year_or_exit(YEARS, &options);
+    match options.command {
+        Command::Run => run_days(year().days, &options, &config),
+        Command::Submit => submit_or_exit(year(), &options, &config),
+        Command::Scaffold => scaffold_or_exit(&options),
+        Command::Watch => watch_or_exit(year().days, &options, &config),
+        Command::Batch => batch_or_exit(year(), &options),
+        Command::Report => report_or_exit(year().days, &options, &config),
+        Command::History => history_or_exit(&options, &config),
+    }
+}
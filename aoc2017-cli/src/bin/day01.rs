@@ -0,0 +1,8 @@
+use aoc2017_cli::scaffold::run;
+use aoc2017_core::days::day01::Day01;
+
+/// Processes the AOC 2017 Day 01 input file and solves both parts of the problem. Solutions are
+/// printed to stdout.
+pub fn main() {
+    run::<Day01>();
+}
@@ -0,0 +1,8 @@
+use aoc2017_cli::scaffold::run;
+use aoc2017_core::days::day03::Day03;
+
+/// Processes the AOC 2017 Day 03 input file and solves both parts of the problem. Solutions are
+/// printed to stdout.
+pub fn main() {
+    run::<Day03>();
+}
@@ -0,0 +1,8 @@
+use aoc2017_cli::scaffold::run;
+use aoc2017_core::days::day05::Day05;
+
+/// Processes the AOC 2017 Day 05 input file and solves both parts of the problem. Solutions are
+/// printed to stdout.
+pub fn main() {
+    run::<Day05>();
+}
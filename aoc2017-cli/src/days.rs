@@ -0,0 +1,67 @@
+use aoc2017_core::days::*;
+
+use crate::scaffold::{DayEntry, YearEntry, AOC_YEAR};
+
+/// Registry of the solvers for each year, keyed by year. Only AOC 2017 is solved by this crate.
+pub const YEARS: &[YearEntry] = &[YearEntry {
+    year: AOC_YEAR,
+    days: DAYS,
+}];
+
+/// Registry of the solvers for each AOC 2017 problem, in day order.
+pub const DAYS: &[DayEntry] = &[
+    DayEntry::new::<day01::Day01>(),
+    DayEntry::new::<day02::Day02>(),
+    DayEntry::new::<day03::Day03>(),
+    DayEntry::new::<day04::Day04>(),
+    DayEntry::new::<day05::Day05>(),
+    DayEntry::new::<day06::Day06>(),
+    DayEntry::new::<day07::Day07>(),
+    DayEntry::new::<day08::Day08>(),
+    DayEntry::new::<day09::Day09>(),
+    DayEntry::new::<day10::Day10>(),
+    DayEntry::new::<day11::Day11>(),
+    DayEntry::new::<day12::Day12>(),
+    DayEntry::new::<day13::Day13>(),
+    DayEntry::new::<day14::Day14>(),
+    DayEntry::new::<day15::Day15>(),
+    DayEntry::new::<day16::Day16>(),
+    DayEntry::new::<day17::Day17>(),
+    DayEntry::new::<day18::Day18>(),
+    DayEntry::new::<day19::Day19>(),
+    DayEntry::new::<day20::Day20>(),
+    DayEntry::new::<day21::Day21>(),
+    DayEntry::new::<day22::Day22>(),
+    DayEntry::new::<day23::Day23>(),
+];
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::scaffold::Config;
+
+    /// Cross-checks the alternative algorithms of every problem that has them against the actual
+    /// problem input (found under the workspace root, as tests are run from the CLI crate
+    /// directory). Slow, so only run when ignored tests are requested.
+    #[test]
+    #[ignore]
+    fn test_cross_check_algorithms() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("../input")),
+            ..Config::default()
+        };
+        for entry in DAYS.iter().filter(|entry| entry.algorithms.len() > 1) {
+            for result in (entry.cross_check)(&config) {
+                assert!(
+                    result.is_consistent(),
+                    "Day {} {} algorithms disagree: {:?}",
+                    entry.day,
+                    result.part,
+                    result.answers
+                );
+            }
+        }
+    }
+}
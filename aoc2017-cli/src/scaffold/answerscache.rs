@@ -0,0 +1,311 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::scaffold::Part;
+
+/// Outcome of submitting an answer to adventofcode.com.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Incorrect answer, without any hint about whether it was too high or too low.
+    Incorrect,
+    /// Answer was submitted too soon after the previous one. Holds the wait time given in the
+    /// response, if any.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// Part has already been solved, so the answer was not checked.
+    AlreadySolved,
+    /// Response could not be matched to any of the known outcomes.
+    Unknown,
+}
+
+impl SubmissionOutcome {
+    /// Determines the outcome of a submission from the body of the adventofcode.com response.
+    pub fn from_response(body: &str) -> SubmissionOutcome {
+        if body.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if body.contains("answer is too high") {
+            SubmissionOutcome::TooHigh
+        } else if body.contains("answer is too low") {
+            SubmissionOutcome::TooLow
+        } else if body.contains("That's not the right answer") {
+            SubmissionOutcome::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            SubmissionOutcome::RateLimited { wait }
+        } else if body.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::AlreadySolved
+        } else {
+            SubmissionOutcome::Unknown
+        }
+    }
+
+    /// Checks if the outcome is a definitive verdict on the answer, and should be recorded in the
+    /// [`AnswersCache`].
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            SubmissionOutcome::Correct
+                | SubmissionOutcome::TooHigh
+                | SubmissionOutcome::TooLow
+                | SubmissionOutcome::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "correct"),
+            SubmissionOutcome::TooHigh => write!(f, "too high"),
+            SubmissionOutcome::TooLow => write!(f, "too low"),
+            SubmissionOutcome::Incorrect => write!(f, "incorrect"),
+            SubmissionOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate-limited ({wait} left to wait)")
+            }
+            SubmissionOutcome::RateLimited { wait: None } => write!(f, "rate-limited"),
+            SubmissionOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmissionOutcome::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Custom error type indicating that the answers cache file could not be read, written or parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnswersCacheError {
+    pub message: String,
+}
+
+impl fmt::Display for AnswersCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Answers cache error: {}", self.message)
+    }
+}
+
+/// Single answer recorded in the [`AnswersCache`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RecordedAnswer {
+    pub day: u64,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+/// Local record of the answers submitted to adventofcode.com and their outcomes, used to avoid
+/// resubmitting answers that are already known to be right or wrong.
+///
+/// The cache file holds one tab-separated record per line - day, part (1 or 2), outcome and answer.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct AnswersCache {
+    path: PathBuf,
+    records: Vec<RecordedAnswer>,
+}
+
+impl AnswersCache {
+    /// Loads the answers cache from the given file. If the file does not exist, the cache is empty.
+    pub fn load(path: &Path) -> Result<AnswersCache, AnswersCacheError> {
+        let records = if path.exists() {
+            let raw_cache = fs::read_to_string(path).map_err(|e| AnswersCacheError {
+                message: format!("could not read \"{}\" ({e})", path.display()),
+            })?;
+            raw_cache
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.parse::<RecordedAnswer>())
+                .collect::<Result<Vec<RecordedAnswer>, AnswersCacheError>>()?
+        } else {
+            vec![]
+        };
+        Ok(AnswersCache {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    /// Writes the answers cache back to the file it was loaded from.
+    pub fn save(&self) -> Result<(), AnswersCacheError> {
+        let raw_cache = self
+            .records
+            .iter()
+            .map(|record| format!("{record}\n"))
+            .collect::<String>();
+        fs::write(&self.path, raw_cache).map_err(|e| AnswersCacheError {
+            message: format!("could not write \"{}\" ({e})", self.path.display()),
+        })
+    }
+
+    /// Records the outcome of submitting the answer for the given day and part.
+    pub fn record(&mut self, day: u64, part: Part, answer: &str, outcome: SubmissionOutcome) {
+        self.records.push(RecordedAnswer {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        });
+    }
+
+    /// Gets the answers recorded for the given day and part, in submission order.
+    pub fn answers(&self, day: u64, part: Part) -> impl Iterator<Item = &RecordedAnswer> {
+        self.records
+            .iter()
+            .filter(move |record| record.day == day && record.part == part)
+    }
+
+    /// Gets the correct answer recorded for the given day and part, if any.
+    pub fn correct_answer(&self, day: u64, part: Part) -> Option<&str> {
+        self.answers(day, part)
+            .find(|record| record.outcome == SubmissionOutcome::Correct)
+            .map(|record| record.answer.as_str())
+    }
+
+    /// Checks the answer against the answers previously recorded for the given day and part. If the
+    /// answer is known to be wrong, the previous outcome that shows this is returned. Numeric
+    /// answers are also checked against the bounds given by previous "too high" and "too low"
+    /// outcomes.
+    pub fn known_outcome(&self, day: u64, part: Part, answer: &str) -> Option<&RecordedAnswer> {
+        let value = answer.parse::<i128>().ok();
+        self.answers(day, part).find(|record| {
+            if record.answer == answer {
+                return true;
+            }
+            match (value, record.answer.parse::<i128>()) {
+                (Some(value), Ok(bound)) => match record.outcome {
+                    SubmissionOutcome::TooHigh => value >= bound,
+                    SubmissionOutcome::TooLow => value <= bound,
+                    _ => false,
+                },
+                _ => false,
+            }
+        })
+    }
+}
+
+impl fmt::Display for RecordedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let outcome = match self.outcome {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::TooHigh => "too_high",
+            SubmissionOutcome::TooLow => "too_low",
+            _ => "incorrect",
+        };
+        write!(f, "{}\t{part}\t{outcome}\t{}", self.day, self.answer)
+    }
+}
+
+impl FromStr for RecordedAnswer {
+    type Err = AnswersCacheError;
+
+    /// Parses a single record from the answers cache file.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AnswersCacheError {
+            message: format!("invalid record \"{s}\""),
+        };
+        let mut fields = s.splitn(4, '\t');
+        let (Some(day), Some(part), Some(outcome), Some(answer)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid());
+        };
+        let day = day.parse::<u64>().map_err(|_| invalid())?;
+        let part = match part {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(invalid()),
+        };
+        let outcome = match outcome {
+            "correct" => SubmissionOutcome::Correct,
+            "too_high" => SubmissionOutcome::TooHigh,
+            "too_low" => SubmissionOutcome::TooLow,
+            "incorrect" => SubmissionOutcome::Incorrect,
+            _ => return Err(invalid()),
+        };
+        Ok(RecordedAnswer {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the outcomes determined from example adventofcode.com response text.
+    #[test]
+    fn test_submission_outcome_from_response() {
+        let cases = [
+            (
+                "<p>That's the right answer!  You are one gold star closer",
+                SubmissionOutcome::Correct,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too high.",
+                SubmissionOutcome::TooHigh,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too low.",
+                SubmissionOutcome::TooLow,
+            ),
+            (
+                "<p>That's not the right answer.  If you're stuck",
+                SubmissionOutcome::Incorrect,
+            ),
+            (
+                "<p>You gave an answer too recently. You have 37s left to wait.",
+                SubmissionOutcome::RateLimited {
+                    wait: Some(String::from("37s")),
+                },
+            ),
+            (
+                "<p>You don't seem to be solving the right level.",
+                SubmissionOutcome::AlreadySolved,
+            ),
+            ("<html></html>", SubmissionOutcome::Unknown),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(expected, SubmissionOutcome::from_response(body));
+        }
+    }
+
+    /// Tests the lookup of known outcomes, including the bounds given by previous numeric answers.
+    #[test]
+    fn test_answers_cache_known_outcome() {
+        let mut cache = AnswersCache::default();
+        cache.record(3, Part::One, "500", SubmissionOutcome::TooHigh);
+        cache.record(3, Part::One, "400", SubmissionOutcome::TooLow);
+        cache.record(3, Part::One, "abc", SubmissionOutcome::Incorrect);
+        assert!(cache.known_outcome(3, Part::One, "480").is_none());
+        assert_eq!(
+            SubmissionOutcome::TooHigh,
+            cache.known_outcome(3, Part::One, "501").unwrap().outcome
+        );
+        assert_eq!(
+            SubmissionOutcome::TooLow,
+            cache.known_outcome(3, Part::One, "400").unwrap().outcome
+        );
+        assert!(cache.known_outcome(3, Part::One, "abc").is_some());
+        assert!(cache.known_outcome(3, Part::Two, "501").is_none());
+        cache.record(3, Part::One, "480", SubmissionOutcome::Correct);
+        assert_eq!(Some("480"), cache.correct_answer(3, Part::One));
+        // Records are written and read back in the same form
+        let record = cache.answers(3, Part::One).last().unwrap();
+        assert_eq!(
+            *record,
+            record.to_string().parse::<RecordedAnswer>().unwrap()
+        );
+    }
+}
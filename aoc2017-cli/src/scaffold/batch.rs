@@ -0,0 +1,261 @@
+use std::any::Any;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use crate::scaffold::{solve_with, Answer, Part, RunOptions, Solver, YearEntry};
+
+/// Custom error type indicating that batch mode could not be run.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchError {
+    pub message: String,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Batch failed: {}", self.message)
+    }
+}
+
+/// Result of solving a problem for a single input file in batch mode.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BatchResult {
+    /// Input file that was solved.
+    pub input_file: PathBuf,
+    /// Solutions to parts 1 and 2, or the panic message if the input could not be solved.
+    pub solutions: Result<[Option<Answer>; 2], String>,
+    /// Total execution time, including input processing.
+    pub total: Duration,
+}
+
+/// Processes the input file and solves both parts of the problem using the named algorithm (or the
+/// default algorithm if None). A panic raised by the solver is caught and reported in the result,
+/// so that one bad input does not stop the rest of the batch.
+pub fn solve_input_file<S: Solver>(input_file: &Path, algorithm: Option<&str>) -> BatchResult {
+    let start = Instant::now();
+    let solutions = panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = S::process_input_file(&input_file.to_string_lossy());
+        [Part::One, Part::Two].map(|part| solve_with::<S>(&input, part, algorithm))
+    }))
+    .map_err(|payload| panic_message(payload.as_ref()));
+    BatchResult {
+        input_file: input_file.to_path_buf(),
+        solutions,
+        total: start.elapsed(),
+    }
+}
+
+/// Solves the day of the year selected by the options for every file in the inputs directory,
+/// printing a table comparing the answers and execution times for each input. Any error is printed
+/// to stderr and the process exits.
+pub fn batch_or_exit(year: &YearEntry, options: &RunOptions) {
+    match batch(year, options) {
+        Ok(lines) => lines.iter().for_each(|line| println!("{line}")),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs batch mode as described for [`batch_or_exit`], returning the lines of the table.
+fn batch(year: &YearEntry, options: &RunOptions) -> Result<Vec<String>, BatchError> {
+    let day = options.day.ok_or_else(|| BatchError {
+        message: String::from("--day is required to run a batch"),
+    })?;
+    let inputs_dir = options.inputs_dir.as_ref().ok_or_else(|| BatchError {
+        message: String::from("--inputs is required to run a batch"),
+    })?;
+    let entry = year.day(day).ok_or_else(|| BatchError {
+        message: format!("no solver registered for day {day}"),
+    })?;
+    let algorithm = options.algorithm.as_deref();
+    if let Some(algorithm) = algorithm.filter(|name| !entry.algorithms.contains(name)) {
+        return Err(BatchError {
+            message: format!("day {day} has no algorithm \"{algorithm}\""),
+        });
+    }
+    let input_files = list_input_files(inputs_dir)?;
+    // Silence the default panic message, since panics are reported in the table instead
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = input_files
+        .iter()
+        .map(|input_file| (entry.solve_file)(input_file, algorithm))
+        .collect::<Vec<BatchResult>>();
+    panic::set_hook(default_hook);
+    let mut lines = vec![format!(
+        "AOC {} Day {day} - \"{}\" ({} inputs)",
+        year.year,
+        entry.name,
+        results.len()
+    )];
+    lines.extend(format_batch_table(&results));
+    Ok(lines)
+}
+
+/// Lists the files held in the directory, sorted by name.
+fn list_input_files(dir: &Path) -> Result<Vec<PathBuf>, BatchError> {
+    let read_error = |e: std::io::Error| BatchError {
+        message: format!("cannot read inputs directory \"{}\" - {e}", dir.display()),
+    };
+    let mut input_files = fs::read_dir(dir)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        .map_err(read_error)?;
+    input_files.retain(|path| path.is_file());
+    input_files.sort();
+    if input_files.is_empty() {
+        return Err(BatchError {
+            message: format!("no input files found in \"{}\"", dir.display()),
+        });
+    }
+    Ok(input_files)
+}
+
+/// Formats the batch results as a table with one row per input file, giving the answers to each
+/// part and the total execution time. Inputs that could not be solved show the panic message in
+/// place of the answers.
+pub fn format_batch_table(results: &[BatchResult]) -> Vec<String> {
+    let mut rows = vec![[
+        String::from("Input"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+        String::from("Time"),
+    ]];
+    for result in results {
+        let name = result.input_file.file_name().map_or_else(
+            || result.input_file.display().to_string(),
+            |name| name.to_string_lossy().to_string(),
+        );
+        let (p1, p2) = match &result.solutions {
+            Ok([p1, p2]) => (format_answer(p1), format_answer(p2)),
+            Err(message) => (format!("error: {message}"), String::new()),
+        };
+        rows.push([name, p1, p2, format!("{:.2?}", result.total)]);
+    }
+    format_table(&rows)
+}
+
+/// Formats the rows as a table, with each column padded to the width of its widest cell and the
+/// columns separated by " | ".
+pub(crate) fn format_table<const N: usize>(rows: &[[String; N]]) -> Vec<String> {
+    let widths: [usize; N] = std::array::from_fn(|col| {
+        rows.iter()
+            .map(|row| row[col].chars().count())
+            .max()
+            .unwrap_or(0)
+    });
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<String>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Formats the answer for a part, with parts without an answer reported as not implemented.
+fn format_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => String::from("not implemented"),
+    }
+}
+
+/// Extracts the message from the payload of a caught panic.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => String::from("solver panicked"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scaffold::DayEntry;
+
+    /// Minimal solver that counts the lines of its input, and panics if the input cannot be read.
+    struct LineCounter;
+
+    impl Solver for LineCounter {
+        type Input = usize;
+
+        const DAY: u64 = 1;
+        const NAME: &'static str = "Line Counter";
+        const INPUT_FILE: &'static str = "./input/day01.txt";
+
+        fn process_input_file(filename: &str) -> Self::Input {
+            match fs::read_to_string(filename) {
+                Ok(raw_input) => raw_input.lines().count(),
+                Err(_) => panic!("Cannot read {filename}"),
+            }
+        }
+
+        fn solve_part1(input: &Self::Input) -> Option<Answer> {
+            Some((*input).into())
+        }
+
+        fn solve_part2(_input: &Self::Input) -> Option<Answer> {
+            None
+        }
+    }
+
+    /// Tests that solver panics are caught and reported in the batch table. Tests are run from the
+    /// CLI crate directory, so the input files are found under the workspace root.
+    #[test]
+    fn test_batch_table() {
+        let mut results = vec![
+            solve_input_file::<LineCounter>(Path::new("../input/day01.txt"), None),
+            solve_input_file::<LineCounter>(Path::new("./input/missing.txt"), None),
+        ];
+        assert_eq!(Ok([Some(Answer::Uint(1)), None]), results[0].solutions);
+        assert_eq!(
+            Err(String::from("Cannot read ./input/missing.txt")),
+            results[1].solutions
+        );
+        for result in results.iter_mut() {
+            result.total = Duration::from_millis(5);
+        }
+        let expected = vec![
+            "Input       | Part 1                                 | Part 2          | Time",
+            "day01.txt   | 1                                      | not implemented | 5.00ms",
+            "missing.txt | error: Cannot read ./input/missing.txt |                 | 5.00ms",
+        ];
+        assert_eq!(expected, format_batch_table(&results));
+    }
+
+    /// Tests that the batch header names the year of the registry entry the day is taken from.
+    #[test]
+    fn test_batch_header_year() {
+        const DAYS: &[DayEntry] = &[DayEntry::new::<LineCounter>()];
+        let year = YearEntry {
+            year: 2018,
+            days: DAYS,
+        };
+        let options = RunOptions {
+            day: Some(1),
+            inputs_dir: Some(PathBuf::from("../examples")),
+            ..RunOptions::default()
+        };
+        let lines = batch(&year, &options).unwrap();
+        let inputs = list_input_files(Path::new("../examples")).unwrap().len();
+        assert_eq!(
+            format!("AOC 2018 Day 1 - \"Line Counter\" ({inputs} inputs)"),
+            lines[0]
+        );
+    }
+}
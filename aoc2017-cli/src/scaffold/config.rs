@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::scaffold::RunOptions;
+
+/// Default location of the runner config file, relative to the working directory.
+pub const DEFAULT_CONFIG_FILE: &str = "./aoc.toml";
+
+/// Default location of the answers cache file, relative to the working directory.
+pub const DEFAULT_ANSWERS_FILE: &str = "./answers.txt";
+
+/// Default location of the run history file, relative to the working directory.
+pub const DEFAULT_HISTORY_FILE: &str = "./runs.jsonl";
+
+/// Custom error type indicating that the runner config file could not be read or is invalid.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigParseError {
+    pub message: String,
+}
+
+impl fmt::Display for ConfigParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid config: {}", self.message)
+    }
+}
+
+/// Format used by the runner when printing solutions and execution times.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum OutputFormat {
+    /// Multi-line block for each problem, with solutions followed by execution times.
+    #[default]
+    Text,
+    /// Single line for each problem, holding the solutions and total execution time.
+    Compact,
+}
+
+impl FromStr for OutputFormat {
+    type Err = ConfigParseError;
+
+    /// Converts the given string to the corresponding variant of [`OutputFormat`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "compact" => Ok(OutputFormat::Compact),
+            _ => Err(ConfigParseError {
+                message: format!("output format must be \"text\" or \"compact\", got \"{s}\""),
+            }),
+        }
+    }
+}
+
+/// Runner defaults loaded from the `aoc.toml` config file. Values given on the command line
+/// override those from the config file.
+///
+/// The config file uses a small subset of TOML - top-level keys, and a `[budgets]` table giving the
+/// time budget for each day:
+///
+/// ```toml
+/// input_dir = "./input"
+/// session_token_file = "./session.txt"
+/// answers_file = "./answers.txt"
+/// history_file = "./runs.jsonl"
+/// output_format = "compact"
+///
+/// [budgets]
+/// 16 = "10ms"
+/// 22 = "1s"
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Config {
+    /// Directory holding the problem input files, in place of the default input file locations.
+    pub input_dir: Option<PathBuf>,
+    /// File holding the adventofcode.com session token.
+    pub session_token_file: Option<PathBuf>,
+    /// File recording the answers submitted to adventofcode.com, in place of the default file.
+    pub answers_file: Option<PathBuf>,
+    /// File recording the runs made with `--record`, in place of the default file.
+    pub history_file: Option<PathBuf>,
+    /// Format used when printing solutions and execution times.
+    pub output_format: OutputFormat,
+    /// Maximum expected execution time for each day. Runs over budget are flagged in the output.
+    pub time_budgets: HashMap<u64, Duration>,
+}
+
+impl Config {
+    /// Loads the config file given in the options, or the default config file if present. If no
+    /// config file is given and the default file does not exist, the default config is used. Values
+    /// given in the options then override those from the config file.
+    pub fn load(options: &RunOptions) -> Result<Config, ConfigParseError> {
+        let mut config = match &options.config_file {
+            Some(path) => Config::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Config::from_file(Path::new(DEFAULT_CONFIG_FILE))?
+            }
+            None => Config::default(),
+        };
+        if let Some(input_dir) = &options.input_dir {
+            config.input_dir = Some(input_dir.clone());
+        }
+        if let Some(output_format) = options.output_format {
+            config.output_format = output_format;
+        }
+        Ok(config)
+    }
+
+    /// Reads and parses the given config file.
+    pub fn from_file(path: &Path) -> Result<Config, ConfigParseError> {
+        let raw_config = fs::read_to_string(path).map_err(|e| ConfigParseError {
+            message: format!("could not read \"{}\" ({e})", path.display()),
+        })?;
+        raw_config.parse::<Config>()
+    }
+
+    /// Gets the location of the answers cache file.
+    pub fn answers_file(&self) -> PathBuf {
+        self.answers_file
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE))
+    }
+
+    /// Gets the location of the run history file.
+    pub fn history_file(&self) -> PathBuf {
+        self.history_file
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY_FILE))
+    }
+
+    /// Gets the location of the input file for a problem, using the configured input directory (if
+    /// any) in place of the directory of the default input file.
+    pub fn input_file(&self, default_input_file: &str) -> PathBuf {
+        let default_input_file = Path::new(default_input_file);
+        match (&self.input_dir, default_input_file.file_name()) {
+            (Some(input_dir), Some(file_name)) => input_dir.join(file_name),
+            _ => default_input_file.to_path_buf(),
+        }
+    }
+}
+
+impl FromStr for Config {
+    type Err = ConfigParseError;
+
+    /// Parses the contents of a config file.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+        let mut in_budgets = false;
+        for (i, line) in s.lines().enumerate() {
+            let line_error = |message: String| ConfigParseError {
+                message: format!("line {}: {message}", i + 1),
+            };
+            // Skip blank lines and comments
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            // Check for start of table
+            if let Some(table) = line.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                match table.trim() {
+                    "budgets" => in_budgets = true,
+                    _ => return Err(line_error(format!("unknown table \"{table}\""))),
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(line_error(format!(
+                    "expected \"key = value\", got \"{line}\""
+                )));
+            };
+            let (key, value) = (
+                key.trim(),
+                parse_string_value(value.trim()).map_err(line_error)?,
+            );
+            if in_budgets {
+                let day = key
+                    .parse::<u64>()
+                    .map_err(|_| line_error(format!("invalid day \"{key}\"")))?;
+                let budget = parse_duration(&value).map_err(line_error)?;
+                config.time_budgets.insert(day, budget);
+                continue;
+            }
+            match key {
+                "input_dir" => config.input_dir = Some(PathBuf::from(value)),
+                "session_token_file" => config.session_token_file = Some(PathBuf::from(value)),
+                "answers_file" => config.answers_file = Some(PathBuf::from(value)),
+                "history_file" => config.history_file = Some(PathBuf::from(value)),
+                "output_format" => {
+                    config.output_format = value
+                        .parse()
+                        .map_err(|e: ConfigParseError| line_error(e.message))?
+                }
+                _ => return Err(line_error(format!("unknown key \"{key}\""))),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Parses a quoted string value from the config file.
+fn parse_string_value(value: &str) -> Result<String, String> {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .map(|v| v.to_string())
+        .ok_or_else(|| format!("expected quoted string, got {value}"))
+}
+
+/// Parses a duration given as an integer followed by a unit ("s", "ms", "us" or "µs").
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount = amount
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration \"{value}\""))?;
+    match unit {
+        "s" => Ok(Duration::from_secs(amount)),
+        "ms" => Ok(Duration::from_millis(amount)),
+        "us" | "µs" => Ok(Duration::from_micros(amount)),
+        _ => Err(format!("invalid duration unit in \"{value}\"")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests parsing of a config file holding all of the supported keys.
+    #[test]
+    fn test_config_from_str() {
+        let raw_config = "# Runner defaults\n\
+            input_dir = \"./puzzles\"\n\
+            session_token_file = \"./session.txt\"\n\
+            history_file = \"./timings/runs.jsonl\"\n\
+            output_format = \"compact\"  # one line per day\n\
+            \n\
+            [budgets]\n\
+            16 = \"10ms\"\n\
+            22 = \"2s\"\n";
+        let config = raw_config.parse::<Config>().unwrap();
+        assert_eq!(Some(PathBuf::from("./puzzles")), config.input_dir);
+        assert_eq!(
+            Some(PathBuf::from("./session.txt")),
+            config.session_token_file
+        );
+        assert_eq!(PathBuf::from("./timings/runs.jsonl"), config.history_file());
+        assert_eq!(OutputFormat::Compact, config.output_format);
+        assert_eq!(
+            Some(&Duration::from_millis(10)),
+            config.time_budgets.get(&16)
+        );
+        assert_eq!(Some(&Duration::from_secs(2)), config.time_budgets.get(&22));
+        assert_eq!(
+            PathBuf::from("./puzzles/day16.txt"),
+            config.input_file("./input/day16.txt")
+        );
+        assert!("input_dir = ./puzzles".parse::<Config>().is_err());
+        assert!("colour = \"red\"".parse::<Config>().is_err());
+        assert!("[budgets]\n16 = \"10 minutes\"".parse::<Config>().is_err());
+    }
+
+    /// Tests that values given in the options override those from the config file.
+    #[test]
+    fn test_config_load_overrides() {
+        let options = RunOptions {
+            config_file: Some(PathBuf::from("./does_not_exist.toml")),
+            ..RunOptions::default()
+        };
+        assert!(Config::load(&options).is_err());
+        let options = RunOptions {
+            input_dir: Some(PathBuf::from("./other")),
+            output_format: Some(OutputFormat::Compact),
+            ..RunOptions::default()
+        };
+        let config = Config::load(&options).unwrap();
+        assert_eq!(Some(PathBuf::from("./other")), config.input_dir);
+        assert_eq!(OutputFormat::Compact, config.output_format);
+    }
+}
@@ -0,0 +1,159 @@
+use std::process;
+use std::time::{Duration, Instant};
+
+use crate::scaffold::{solve_with, Answer, Config, DayEntry, Part, RunOptions, Solver};
+
+/// Answer given by one of the alternative algorithms for a part of a problem.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AlgorithmAnswer {
+    /// Name of the algorithm.
+    pub algorithm: &'static str,
+    /// Answer given by the algorithm, or None if the part is not implemented.
+    pub answer: Option<Answer>,
+    /// Execution time of the algorithm.
+    pub duration: Duration,
+}
+
+/// Answers given by each of the alternative algorithms for a single part of a problem.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrossCheckResult {
+    pub part: Part,
+    pub answers: Vec<AlgorithmAnswer>,
+}
+
+impl CrossCheckResult {
+    /// Checks if every algorithm gave the same answer.
+    pub fn is_consistent(&self) -> bool {
+        self.answers
+            .windows(2)
+            .all(|pair| pair[0].answer == pair[1].answer)
+    }
+}
+
+/// Processes the input file for the problem and solves both parts of the problem using each of
+/// the alternative algorithms in turn, returning the answers given for each part.
+pub fn cross_check<S: Solver>(config: &Config) -> Vec<CrossCheckResult> {
+    let input = S::process_input_file(&config.input_file(S::INPUT_FILE).to_string_lossy());
+    [Part::One, Part::Two]
+        .into_iter()
+        .map(|part| {
+            let answers = S::ALGORITHMS
+                .iter()
+                .map(|&algorithm| {
+                    let start = Instant::now();
+                    let answer = solve_with::<S>(&input, part, Some(algorithm));
+                    AlgorithmAnswer {
+                        algorithm,
+                        answer,
+                        duration: start.elapsed(),
+                    }
+                })
+                .collect();
+            CrossCheckResult { part, answers }
+        })
+        .collect()
+}
+
+/// Cross-checks the alternative algorithms of each problem selected by the options (all problems
+/// with more than one algorithm by default), printing the answers given by each algorithm to
+/// stdout. If any algorithms disagree, the process exits with an error once all of the selected
+/// problems have been checked.
+pub fn cross_check_or_exit(days: &[DayEntry], options: &RunOptions, config: &Config) {
+    if options.algorithm.is_some() {
+        eprintln!("Invalid arguments: --cross-check runs every algorithm, so cannot use --algo");
+        process::exit(2);
+    }
+    let selected = days
+        .iter()
+        .filter(|entry| options.day.is_none_or(|day| day == entry.day))
+        .filter(|entry| entry.algorithms.len() > 1)
+        .collect::<Vec<&DayEntry>>();
+    if selected.is_empty() {
+        match options.day {
+            Some(day) => eprintln!("Day {day} has no alternative algorithms to cross-check"),
+            None => eprintln!("No days have alternative algorithms to cross-check"),
+        }
+        process::exit(1);
+    }
+    let mut mismatches = 0;
+    for entry in selected {
+        let results = (entry.cross_check)(config);
+        mismatches += results.iter().filter(|r| !r.is_consistent()).count();
+        println!("AOC 2017 Day {} - \"{}\"", entry.day, entry.name);
+        format_cross_check(&results)
+            .iter()
+            .for_each(|line| println!("{line}"));
+    }
+    if mismatches > 0 {
+        eprintln!("Cross-check failed: {mismatches} part(s) with mismatched answers");
+        process::exit(1);
+    }
+}
+
+/// Formats the answers given by each algorithm for each part, one line per part. Parts where the
+/// algorithms disagree are flagged, with the answer given by each algorithm listed separately.
+pub fn format_cross_check(results: &[CrossCheckResult]) -> Vec<String> {
+    results
+        .iter()
+        .map(|result| {
+            let timings = result
+                .answers
+                .iter()
+                .map(|a| format!("{} {:.2?}", a.algorithm, a.duration))
+                .collect::<Vec<String>>()
+                .join(", ");
+            if result.is_consistent() {
+                let answer = format_answer(&result.answers.first().and_then(|a| a.answer.clone()));
+                format!("[+] {}: {answer} ({timings})", result.part)
+            } else {
+                let answers = result
+                    .answers
+                    .iter()
+                    .map(|a| format!("{}: {}", a.algorithm, format_answer(&a.answer)))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("[!] {}: MISMATCH - {answers} ({timings})", result.part)
+            }
+        })
+        .collect()
+}
+
+/// Formats the answer for a part, with parts without an answer reported as not implemented.
+fn format_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => String::from("not implemented"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that mismatched answers are flagged with the answer given by each algorithm.
+    #[test]
+    fn test_format_cross_check() {
+        let answer = |algorithm, answer: Option<u64>| AlgorithmAnswer {
+            algorithm,
+            answer: answer.map(Answer::from),
+            duration: Duration::from_millis(2),
+        };
+        let results = [
+            CrossCheckResult {
+                part: Part::One,
+                answers: vec![answer("brute", Some(24)), answer("sieve", Some(24))],
+            },
+            CrossCheckResult {
+                part: Part::Two,
+                answers: vec![answer("brute", Some(10)), answer("sieve", None)],
+            },
+        ];
+        assert!(results[0].is_consistent());
+        assert!(!results[1].is_consistent());
+        let expected = vec![
+            "[+] Part 1: 24 (brute 2.00ms, sieve 2.00ms)",
+            "[!] Part 2: MISMATCH - brute: 10, sieve: not implemented (brute 2.00ms, sieve 2.00ms)",
+        ];
+        assert_eq!(expected, format_cross_check(&results));
+    }
+}
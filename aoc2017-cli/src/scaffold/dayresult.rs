@@ -0,0 +1,98 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc2017_core::utils::fmt::hex;
+use sha2::{Digest, Sha256};
+
+use crate::scaffold::{Answer, Part, PhaseStats};
+
+/// Results of solving a problem, along with the metadata needed to tell where they came from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DayResult {
+    /// Day number of the problem.
+    pub day: u64,
+    /// Name of the problem.
+    pub name: String,
+    /// Solution to part 1, or None if it was not solved (or is not implemented).
+    pub part1: Option<Answer>,
+    /// Solution to part 2, or None if it was not solved (or is not implemented).
+    pub part2: Option<Answer>,
+    /// Execution time (and memory usage, if tracked) for each phase of solving the problem.
+    pub timings: Vec<(String, PhaseStats)>,
+    /// SHA-256 hash of the input file, as a lowercase hex string.
+    pub input_hash: String,
+}
+
+impl DayResult {
+    /// Gets the solution to the given part.
+    pub fn solution(&self, part: Part) -> &Option<Answer> {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    /// Calculates the total execution time across all phases.
+    pub fn total_duration(&self) -> Duration {
+        self.timings.iter().map(|(_, stats)| stats.duration).sum()
+    }
+}
+
+/// Calculates the SHA-256 hash of the file, returned as a lowercase hex string.
+pub fn hash_input_file(path: &Path) -> io::Result<String> {
+    Ok(hash_bytes(&fs::read(path)?))
+}
+
+/// Calculates the SHA-256 hash of the bytes, returned as a lowercase hex string.
+pub fn hash_bytes(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the hash against known SHA-256 digests.
+    #[test]
+    fn test_hash_bytes() {
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            hash_bytes(b"")
+        );
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            hash_bytes(b"abc")
+        );
+    }
+
+    /// Tests that a result can be persisted as JSON and re-loaded without losing any details.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_day_result_serde_round_trip() {
+        use crate::scaffold::MemoryUsage;
+
+        let stats = |micros| PhaseStats {
+            duration: Duration::from_micros(micros),
+            memory: Some(MemoryUsage {
+                allocated: 2048,
+                peak: 1024,
+            }),
+        };
+        let result = DayResult {
+            day: 7,
+            name: String::from("Recursive Circus"),
+            part1: Some(Answer::from("hlqnsbe")),
+            part2: Some(Answer::from(-1993i64)),
+            timings: vec![
+                (String::from("Input"), stats(250)),
+                (String::from("Both"), stats(40)),
+            ],
+            input_hash: hash_bytes(b"abc"),
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(result, serde_json::from_str::<DayResult>(&json).unwrap());
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::path::Path;
+use std::process;
+
+/// Custom error type indicating that a run could not be profiled.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProfileError {
+    pub message: String,
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Profiling failed: {}", self.message)
+    }
+}
+
+/// Runs the function under a sampling profiler, writing a flamegraph of the samples to the output
+/// file as an SVG. Any error is printed to stderr and the process exits.
+pub fn profile_or_exit(output: &Path, run: impl FnOnce()) {
+    if let Err(e) = profile(output, run) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Runs the profiler as described for [`profile_or_exit`].
+#[cfg(feature = "flamegraph")]
+fn profile(output: &Path, run: impl FnOnce()) -> Result<(), ProfileError> {
+    use std::fs::File;
+
+    /// Number of samples taken per second.
+    const SAMPLE_FREQUENCY: i32 = 1000;
+
+    let profile_error = |e: &dyn fmt::Display| ProfileError {
+        message: e.to_string(),
+    };
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(SAMPLE_FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|e| profile_error(&e))?;
+    run();
+    let report = guard.report().build().map_err(|e| profile_error(&e))?;
+    let file = File::create(output).map_err(|e| ProfileError {
+        message: format!("cannot create \"{}\" - {e}", output.display()),
+    })?;
+    report.flamegraph(file).map_err(|e| profile_error(&e))?;
+    println!("[~] Flamegraph written to {}", output.display());
+    Ok(())
+}
+
+/// Stand-in for the profiler when the crate is built without the "flamegraph" feature.
+#[cfg(not(feature = "flamegraph"))]
+fn profile(_output: &Path, _run: impl FnOnce()) -> Result<(), ProfileError> {
+    Err(ProfileError {
+        message: String::from(
+            "profiling is not available - rebuild with \"--features flamegraph\"",
+        ),
+    })
+}
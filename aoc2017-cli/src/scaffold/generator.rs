@@ -0,0 +1,207 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::scaffold::{RunOptions, AOC_YEAR};
+
+/// Template for the solver module of a new day.
+const DAY_TEMPLATE: &str = include_str!("../../../template/day00.rs");
+/// Template for the binary running the solver of a new day.
+const BIN_TEMPLATE: &str = include_str!("../../../template/bin_day00.rs");
+
+/// Custom error type indicating that the files for a new day could not be generated.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateError {
+    pub message: String,
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scaffolding failed: {}", self.message)
+    }
+}
+
+/// Generates the files for the day selected by the options, relative to the working directory. The
+/// created files are listed on stdout. Any error is printed to stderr and the process exits.
+pub fn scaffold_or_exit(options: &RunOptions) {
+    let result = match options.day {
+        Some(day) => generate_day(Path::new("."), options.year.unwrap_or(AOC_YEAR), day),
+        None => Err(GenerateError {
+            message: String::from("--day is required to scaffold a new day"),
+        }),
+    };
+    match result {
+        Ok(files) => {
+            for file in files {
+                println!("[+] {}", file.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Generates the files for a new day under the given workspace root directory - the solver module
+/// in the core crate (added to its module list), the binary in the CLI crate (with the day added to
+/// the day registry), and empty input and example files. Returns the files that were created or
+/// updated.
+///
+/// No files are written if the solver module or binary for the day already exists.
+pub fn generate_day(root: &Path, year: u64, day: u64) -> Result<Vec<PathBuf>, GenerateError> {
+    if year != AOC_YEAR {
+        return Err(GenerateError {
+            message: format!("only {AOC_YEAR} is supported, got {year}"),
+        });
+    }
+    let module_file = root.join(format!("src/days/day{day:02}.rs"));
+    let bin_file = root.join(format!("aoc2017-cli/src/bin/day{day:02}.rs"));
+    let modules_file = root.join("src/days/mod.rs");
+    let registry_file = root.join("aoc2017-cli/src/days.rs");
+    let input_file = root.join(format!("input/day{day:02}.txt"));
+    let example_file = root.join(format!("examples/day{day:02}_example1.txt"));
+    // Check that the day has not already been generated
+    for file in [&module_file, &bin_file] {
+        if file.exists() {
+            return Err(GenerateError {
+                message: format!("\"{}\" already exists", file.display()),
+            });
+        }
+    }
+    let modules = fs::read_to_string(&modules_file).map_err(|e| io_error(&modules_file, e))?;
+    let modules = register_day(
+        &modules,
+        day,
+        "pub mod day",
+        format!("pub mod day{day:02};"),
+    )?;
+    let registry = fs::read_to_string(&registry_file).map_err(|e| io_error(&registry_file, e))?;
+    let registry = register_day(
+        &registry,
+        day,
+        "    DayEntry::new::<day",
+        format!("    DayEntry::new::<day{day:02}::Day{day:02}>(),"),
+    )?;
+    // Write the generated files, keeping any existing input files
+    let mut files = vec![];
+    for (file, contents) in [
+        (&module_file, render_template(DAY_TEMPLATE, day)),
+        (&bin_file, render_template(BIN_TEMPLATE, day)),
+        (&modules_file, modules),
+        (&registry_file, registry),
+    ] {
+        fs::write(file, contents).map_err(|e| io_error(file, e))?;
+        files.push(file.clone());
+    }
+    for file in [&input_file, &example_file] {
+        if !file.exists() {
+            fs::create_dir_all(file.parent().unwrap()).map_err(|e| io_error(file, e))?;
+            fs::write(file, "").map_err(|e| io_error(file, e))?;
+            files.push(file.clone());
+        }
+    }
+    Ok(files)
+}
+
+/// Fills in the day number in the template.
+fn render_template(template: &str, day: u64) -> String {
+    template
+        .replace(
+            "PROBLEM_DAY: u64 = 0;",
+            &format!("PROBLEM_DAY: u64 = {day};"),
+        )
+        .replace("Day 00", &format!("Day {day:02}"))
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("day00", &format!("day{day:02}"))
+}
+
+/// Adds the line for the day to the contents of a registry file (the module declarations of the
+/// core crate, or the day registry of the CLI crate), keeping the lines starting with the prefix in
+/// day order.
+fn register_day(
+    registry: &str,
+    day: u64,
+    prefix: &str,
+    new_line: String,
+) -> Result<String, GenerateError> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<String>>();
+    // Find the day numbers of the existing lines
+    let existing = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let digits = line.strip_prefix(prefix)?.get(..2)?;
+            Some((i, digits.parse::<u64>().ok()?))
+        })
+        .collect::<Vec<(usize, u64)>>();
+    if existing.iter().any(|(_, d)| *d == day) {
+        return Err(GenerateError {
+            message: format!("day {day} is already in the day registry"),
+        });
+    }
+    let Some((last, _)) = existing.last() else {
+        return Err(GenerateError {
+            message: format!("no \"{}\" lines found in the day registry", prefix.trim()),
+        });
+    };
+    let index = existing
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(index, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates a [`GenerateError`] for a failed file operation.
+fn io_error(file: &Path, e: std::io::Error) -> GenerateError {
+    GenerateError {
+        message: format!("could not write \"{}\" ({e})", file.display()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the day number is filled in throughout the templates.
+    #[test]
+    fn test_render_template() {
+        let module = render_template(DAY_TEMPLATE, 24);
+        assert!(module.contains("const PROBLEM_INPUT_FILE: &str = \"./input/day24.txt\";"));
+        assert!(module.contains("const PROBLEM_DAY: u64 = 24;"));
+        assert!(module.contains("pub struct Day24;"));
+        assert!(module.contains("fn test_day24_part1_actual()"));
+        assert!(!module.contains("00"));
+        let bin = render_template(BIN_TEMPLATE, 24);
+        assert!(bin.contains("use aoc2017_core::days::day24::Day24;"));
+    }
+
+    /// Tests that new days are added to the module declarations and the registry in day order.
+    #[test]
+    fn test_register_day() {
+        let modules = "pub mod day01;\npub mod day03;\n";
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\npub mod day03;\n",
+            register_day(modules, 2, "pub mod day", String::from("pub mod day02;")).unwrap()
+        );
+        let registry = "pub const DAYS: &[DayEntry] = &[\n\
+            \x20   DayEntry::new::<day01::Day01>(),\n\
+            \x20   DayEntry::new::<day03::Day03>(),\n];\n";
+        let prefix = "    DayEntry::new::<day";
+        let expected = "pub const DAYS: &[DayEntry] = &[\n\
+            \x20   DayEntry::new::<day01::Day01>(),\n\
+            \x20   DayEntry::new::<day02::Day02>(),\n\
+            \x20   DayEntry::new::<day03::Day03>(),\n];\n";
+        let entry = |day: u64| format!("    DayEntry::new::<day{day:02}::Day{day:02}>(),");
+        assert_eq!(
+            expected,
+            register_day(registry, 2, prefix, entry(2)).unwrap()
+        );
+        let registry = register_day(registry, 4, prefix, entry(4)).unwrap();
+        assert!(registry.contains("<day03::Day03>(),\n    DayEntry::new::<day04::Day04>(),\n];"));
+        assert!(register_day(&registry, 3, prefix, entry(3)).is_err());
+        assert!(register_day(modules, 2, prefix, entry(2)).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::fs::{self, OpenOptions};
+#[cfg(feature = "serde")]
+use std::io::Write;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc2017_core::utils::fmt::format_duration;
+
+use crate::scaffold::batch::format_table;
+use crate::scaffold::{Answer, Config, DayResult, RunOptions};
+
+/// Number of the most recent comparable runs shown in the trend of each day.
+const TREND_RUNS: usize = 10;
+
+/// Characters used to draw the trend of each day, from fastest to slowest.
+const TREND_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Custom error type indicating that the run history file could not be read, written or parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HistoryError {
+    pub message: String,
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Run history error: {}", self.message)
+    }
+}
+
+/// Single run of a problem recorded in the run history file with `--record`.
+///
+/// Each record is written to the run history file as a JSON object on its own line. Reading and
+/// writing the file requires the "serde" feature.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunRecord {
+    /// Version of the crate that solved the problem, along with the git revision if known.
+    pub version: String,
+    /// Time the run was recorded, in seconds since the Unix epoch.
+    pub recorded_at: u64,
+    /// Day number of the problem.
+    pub day: u64,
+    /// Name of the problem.
+    pub name: String,
+    /// Solution to part 1, or None if it was not solved (or is not implemented).
+    pub part1: Option<Answer>,
+    /// Solution to part 2, or None if it was not solved (or is not implemented).
+    pub part2: Option<Answer>,
+    /// Execution time of each phase of solving the problem.
+    pub timings: Vec<(String, Duration)>,
+    /// SHA-256 hash of the input file, as a lowercase hex string.
+    pub input_hash: String,
+}
+
+impl RunRecord {
+    /// Creates a new [`RunRecord`] for the result, recorded now by the given version of the crate.
+    pub fn new(result: &DayResult, version: &str) -> RunRecord {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        RunRecord {
+            version: version.to_string(),
+            recorded_at,
+            day: result.day,
+            name: result.name.clone(),
+            part1: result.part1.clone(),
+            part2: result.part2.clone(),
+            timings: result
+                .timings
+                .iter()
+                .map(|(label, stats)| (label.clone(), stats.duration))
+                .collect(),
+            input_hash: result.input_hash.clone(),
+        }
+    }
+
+    /// Calculates the total execution time across all phases.
+    pub fn total_duration(&self) -> Duration {
+        self.timings.iter().map(|(_, duration)| *duration).sum()
+    }
+
+    /// Checks if the run can be compared with the other run - both solved the same day with the
+    /// same input file and the same phases.
+    pub fn is_comparable(&self, other: &RunRecord) -> bool {
+        self.day == other.day
+            && self.input_hash == other.input_hash
+            && self
+                .timings
+                .iter()
+                .map(|(label, _)| label)
+                .eq(other.timings.iter().map(|(label, _)| label))
+    }
+}
+
+/// Gets the version of the crate used when recording runs - the crate version, followed by the
+/// output of `git describe` for the working directory if it is within a git repository.
+pub fn crate_version() -> String {
+    let describe = process::Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|describe| describe.trim().to_string())
+        .filter(|describe| !describe.is_empty());
+    match describe {
+        Some(describe) => format!("{} ({describe})", env!("CARGO_PKG_VERSION")),
+        None => env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
+
+/// Appends the record to the end of the run history file, creating the file if it does not exist.
+#[cfg(feature = "serde")]
+pub fn append_record(path: &Path, record: &RunRecord) -> Result<(), HistoryError> {
+    let line = serde_json::to_string(record).map_err(|e| HistoryError {
+        message: format!("could not write record ({e})"),
+    })?;
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{line}"))
+        .map_err(|e| HistoryError {
+            message: format!("could not write \"{}\" ({e})", path.display()),
+        })
+}
+
+/// Stand-in for appending a record when the crate is built without the "serde" feature.
+#[cfg(not(feature = "serde"))]
+pub fn append_record(_path: &Path, _record: &RunRecord) -> Result<(), HistoryError> {
+    Err(unavailable())
+}
+
+/// Loads the records from the run history file, in the order they were recorded. If the file does
+/// not exist, there are no records.
+#[cfg(feature = "serde")]
+pub fn load_history(path: &Path) -> Result<Vec<RunRecord>, HistoryError> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let raw_history = fs::read_to_string(path).map_err(|e| HistoryError {
+        message: format!("could not read \"{}\" ({e})", path.display()),
+    })?;
+    raw_history
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str::<RunRecord>(line).map_err(|e| HistoryError {
+                message: format!("line {}: invalid record ({e}) \"{line}\"", i + 1),
+            })
+        })
+        .collect()
+}
+
+/// Stand-in for loading the run history when the crate is built without the "serde" feature.
+#[cfg(not(feature = "serde"))]
+pub fn load_history(_path: &Path) -> Result<Vec<RunRecord>, HistoryError> {
+    Err(unavailable())
+}
+
+/// Error given when the run history is used without the "serde" feature.
+#[cfg(not(feature = "serde"))]
+fn unavailable() -> HistoryError {
+    HistoryError {
+        message: String::from("run history is not available - rebuild with \"--features serde\""),
+    }
+}
+
+/// Records the result of solving a problem in the configured run history file. If the file cannot
+/// be written, the error is printed to stderr and the process exits.
+pub fn record_or_exit(result: &DayResult, config: &Config) {
+    let record = RunRecord::new(result, &crate_version());
+    if let Err(e) = append_record(&config.history_file(), &record) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Prints the timing trend of each day in the configured run history file (or only the day selected
+/// by the options). If the file cannot be read, the error is printed to stderr and the process
+/// exits.
+pub fn history_or_exit(options: &RunOptions, config: &Config) {
+    let path = config.history_file();
+    let records = match load_history(&path) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+    let records = records
+        .into_iter()
+        .filter(|record| options.day.is_none_or(|day| day == record.day))
+        .collect::<Vec<RunRecord>>();
+    if records.is_empty() {
+        println!(
+            "No runs recorded in {} - use --record to record runs",
+            path.display()
+        );
+        return;
+    }
+    format_history(&records)
+        .iter()
+        .for_each(|line| println!("{line}"));
+}
+
+/// Formats the timing trend of each day as a table with one row per day, giving the number of runs
+/// recorded, the best and latest total execution times, the change from the previous run and the
+/// trend across the most recent runs.
+///
+/// Only the runs comparable with the latest run of each day (see [`RunRecord::is_comparable`]) are
+/// used, so the trend is not skewed by runs of other input files or of a single part.
+pub fn format_history(records: &[RunRecord]) -> Vec<String> {
+    let mut days: BTreeMap<u64, Vec<&RunRecord>> = BTreeMap::new();
+    for record in records {
+        days.entry(record.day).or_default().push(record);
+    }
+    let mut rows = vec![[
+        "Day", "Runs", "Best", "Latest", "Change", "Trend", "Version",
+    ]
+    .map(String::from)];
+    for (day, runs) in days {
+        let latest = runs[runs.len() - 1];
+        let totals = runs
+            .iter()
+            .filter(|run| run.is_comparable(latest))
+            .map(|run| run.total_duration())
+            .collect::<Vec<Duration>>();
+        let change = match totals.as_slice() {
+            [.., previous, latest] => format_change(*previous, *latest),
+            _ => String::from("-"),
+        };
+        rows.push([
+            format!("{day:02}"),
+            totals.len().to_string(),
+            format_duration(*totals.iter().min().unwrap()),
+            format_duration(latest.total_duration()),
+            change,
+            format_trend(&totals[totals.len().saturating_sub(TREND_RUNS)..]),
+            latest.version.clone(),
+        ]);
+    }
+    format_table(&rows)
+}
+
+/// Formats the change from the previous to the latest duration as a percentage.
+fn format_change(previous: Duration, latest: Duration) -> String {
+    if previous.is_zero() {
+        return String::from("-");
+    }
+    let change = (latest.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1}%")
+}
+
+/// Draws the durations as a sparkline, scaled between the fastest and slowest of them.
+fn format_trend(durations: &[Duration]) -> String {
+    let (Some(min), Some(max)) = (durations.iter().min(), durations.iter().max()) else {
+        return String::new();
+    };
+    let range = (*max - *min).as_secs_f64();
+    durations
+        .iter()
+        .map(|duration| {
+            if range == 0.0 {
+                return TREND_LEVELS[0];
+            }
+            let level = (*duration - *min).as_secs_f64() / range * (TREND_LEVELS.len() - 1) as f64;
+            TREND_LEVELS[level.round() as usize]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a record of a run of the given day, with the given input hash and phase durations
+    /// (in milliseconds).
+    fn run(day: u64, input_hash: &str, timings: &[(&str, u64)]) -> RunRecord {
+        RunRecord {
+            version: String::from("0.1.0 (3392820)"),
+            recorded_at: 1_700_000_000,
+            day,
+            name: String::from("Example"),
+            part1: Some(Answer::Uint(7)),
+            part2: None,
+            timings: timings
+                .iter()
+                .map(|(label, ms)| (label.to_string(), Duration::from_millis(*ms)))
+                .collect(),
+            input_hash: input_hash.to_string(),
+        }
+    }
+
+    /// Tests that records appended to the history file are loaded back in order, in the same form.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_history_append_load() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(Ok(vec![]), load_history(&path));
+        let mut records = [run(1, "aa", &[("Both", 3)]), run(22, "bb", &[("Both", 40)])];
+        records[0].name = String::from("Inverse \"Captcha\"\t\\");
+        records[0].part1 = Some(Answer::Text(String::from("uownj")));
+        records[0].part2 = Some(Answer::Int(-596));
+        records[1].part1 = Some(Answer::Uint(u128::MAX));
+        for record in &records {
+            append_record(&path, record).unwrap();
+        }
+        assert_eq!(Ok(records.to_vec()), load_history(&path));
+        fs::write(&path, "{\"day\":1}\n").unwrap();
+        assert!(load_history(&path)
+            .unwrap_err()
+            .message
+            .starts_with("line 1:"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    /// Tests the timing trends, which only compare runs with the same input file and phases as the
+    /// latest run of each day.
+    #[test]
+    fn test_format_history() {
+        let both = |ms| [("Input", 1), ("Both", ms)];
+        let records = vec![
+            run(16, "aa", &both(19)),
+            run(3, "cc", &both(4)),
+            run(16, "aa", &both(9)),
+            run(16, "zz", &both(500)),
+            run(16, "aa", &[("Input", 1), ("Part 1", 2)]),
+            run(16, "aa", &both(11)),
+            run(16, "aa", &both(14)),
+        ];
+        let expected = vec![
+            "Day | Runs | Best    | Latest  | Change | Trend | Version",
+            "03  | 1    | 5.00ms  | 5.00ms  | -      | ▁     | 0.1.0 (3392820)",
+            "16  | 4    | 10.00ms | 15.00ms | +25.0% | █▁▂▅  | 0.1.0 (3392820)",
+        ];
+        assert_eq!(expected, format_history(&records));
+    }
+}
@@ -0,0 +1,45 @@
+pub mod answerscache;
+pub mod batch;
+pub mod config;
+pub mod crosscheck;
+pub mod dayresult;
+pub mod flamegraph;
+pub mod generator;
+pub mod history;
+pub mod options;
+pub mod profiling;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod submit;
+pub mod watch;
+
+pub use answerscache::{AnswersCache, AnswersCacheError, RecordedAnswer, SubmissionOutcome};
+pub use aoc2017_core::scaffold::{
+    Answer, Cache, ExportFormat, Parameter, ParameterError, Parameters, Phase, SolveOptions,
+    Solver, TimedSolution, Timings, TimingsReport,
+};
+pub use batch::{batch_or_exit, solve_input_file, BatchError, BatchResult};
+pub use config::{Config, ConfigParseError, OutputFormat};
+pub use crosscheck::{
+    cross_check, cross_check_or_exit, format_cross_check, AlgorithmAnswer, CrossCheckResult,
+};
+pub use dayresult::{hash_input_file, DayResult};
+pub use flamegraph::{profile_or_exit, ProfileError};
+pub use generator::{scaffold_or_exit, GenerateError};
+pub use history::{
+    append_record, format_history, history_or_exit, load_history, record_or_exit, HistoryError,
+    RunRecord,
+};
+pub use options::{Command, OptionsParseError, Part, RunOptions};
+pub use profiling::{lap_memory_phase, start_memory_phase, MemoryUsage, PhaseStats};
+pub use registry::{
+    find_year, select_year_or_exit, DayEntry, UnknownYearError, YearEntry, AOC_YEAR,
+};
+pub use report::{format_report, report_or_exit, solve_timed};
+pub use runner::{
+    load_config_or_exit, parse_args_or_exit, run, run_days, run_with_options, solve_day,
+    solve_part, solve_with,
+};
+pub use submit::{submit_or_exit, SubmitError};
+pub use watch::{watch_or_exit, RunSummary, WatchError};
@@ -0,0 +1,372 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::scaffold::{ConfigParseError, ExportFormat, OutputFormat, Parameters};
+
+/// Part of a problem that can be selected to run on its own.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// Custom error type indicating that the command line arguments given to the runner are invalid.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OptionsParseError {
+    pub message: String,
+}
+
+impl fmt::Display for OptionsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid arguments: {}", self.message)
+    }
+}
+
+/// Command carried out by the runner.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Command {
+    /// Solve the selected problems and print the solutions (default).
+    #[default]
+    Run,
+    /// Submit the answer for a single part of a problem to adventofcode.com.
+    Submit,
+    /// Generate the files for a new day from the template.
+    Scaffold,
+    /// Re-run a single day whenever its solver module or input file changes.
+    Watch,
+    /// Solve a single day for every input file in a directory, comparing the results.
+    Batch,
+    /// Solve the selected problems and report the time taken by each phase, with totals and
+    /// percentiles across the problems.
+    Report,
+    /// Show the trend of the execution times of each problem across the runs recorded with
+    /// `--record`.
+    History,
+}
+
+/// Options controlling which problems and parts are solved by the runner.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct RunOptions {
+    /// Command carried out by the runner.
+    pub command: Command,
+    /// Year of the problems, or None to use the year solved by this crate.
+    pub year: Option<u64>,
+    /// Day of the problem to solve on its own, or None to solve all problems.
+    pub day: Option<u64>,
+    /// Part to solve on its own, or None to solve both parts.
+    pub part: Option<Part>,
+    /// Config file to load, or None to use the default config file (if present).
+    pub config_file: Option<PathBuf>,
+    /// Directory holding the problem input files, overriding the config file.
+    pub input_dir: Option<PathBuf>,
+    /// Format used when printing results, overriding the config file.
+    pub output_format: Option<OutputFormat>,
+    /// Answer to submit, or None to submit the answer calculated by the solver.
+    pub answer: Option<String>,
+    /// Alternative algorithm used to solve the problem, or None to use the default algorithm.
+    pub algorithm: Option<String>,
+    /// Print a visualisation of the problem input (for problems that support it).
+    pub visualise: bool,
+    /// Solve the problem with each of its alternative algorithms and compare the answers.
+    pub cross_check: bool,
+    /// Append the results and execution times of each run to the run history file.
+    pub record: bool,
+    /// Directory holding the input files solved in batch mode.
+    pub inputs_dir: Option<PathBuf>,
+    /// File to write a flamegraph of the run to, or None to run without profiling.
+    pub profile: Option<PathBuf>,
+    /// Format and file to export the problem input to (for problems that support it).
+    pub export: Option<(ExportFormat, PathBuf)>,
+    /// Values overriding the default parameters of the problem (e.g. the number of rounds).
+    pub params: Parameters,
+}
+
+impl RunOptions {
+    /// Parses the runner options from the command line arguments (excluding the program name).
+    ///
+    /// The arguments may start with a command (`run`, `submit`, `scaffold`, `watch`, `batch`,
+    /// `report` or `history`), with `run` used if none is given. Supported arguments are
+    /// `--year <YEAR>`, `--day <N>`, `--part <1|2>`, `--config <FILE>`, `--input-dir <DIR>`,
+    /// `--format <text|compact>`, `--answer <ANSWER>`, `--algo <NAME>`, `--inputs <DIR>`,
+    /// `--profile <FILE>`, `--export <FILE>`, `--export-dot <FILE>` and `--param <NAME=VALUE>`,
+    /// with the value given either as the following argument or after an equals sign (e.g.
+    /// `--part=2`). The export format is taken from the extension of the `--export` file (".dot",
+    /// ".gv" or ".json"), and `--param` may be given more than once. The `--visualize`,
+    /// `--cross-check` and `--record` flags do not take a value.
+    pub fn from_args(
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<RunOptions, OptionsParseError> {
+        let mut options = RunOptions::default();
+        let mut args = args.into_iter().peekable();
+        // Check for command ahead of the other arguments
+        if let Some(command) = args.next_if(|arg| !arg.starts_with("--")) {
+            options.command = match command.as_str() {
+                "run" => Command::Run,
+                "submit" => Command::Submit,
+                "scaffold" => Command::Scaffold,
+                "watch" => Command::Watch,
+                "batch" => Command::Batch,
+                "report" => Command::Report,
+                "history" => Command::History,
+                _ => {
+                    return Err(OptionsParseError {
+                        message: format!("unrecognised command \"{command}\""),
+                    })
+                }
+            };
+        }
+        while let Some(arg) = args.next() {
+            if arg == "--visualize" {
+                options.visualise = true;
+                continue;
+            }
+            if arg == "--cross-check" {
+                options.cross_check = true;
+                continue;
+            }
+            if arg == "--record" {
+                options.record = true;
+                continue;
+            }
+            let (flag, value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), value.to_string()),
+                None => {
+                    let value = args.next().ok_or_else(|| OptionsParseError {
+                        message: format!("missing value for \"{arg}\""),
+                    })?;
+                    (arg, value)
+                }
+            };
+            match flag.as_str() {
+                "--year" => {
+                    options.year = Some(value.parse::<u64>().map_err(|_| OptionsParseError {
+                        message: format!("--year must be a number, got \"{value}\""),
+                    })?)
+                }
+                "--day" => options.day = Some(parse_day(&value)?),
+                "--part" => options.part = Some(parse_part(&value)?),
+                "--config" => options.config_file = Some(PathBuf::from(value)),
+                "--input-dir" => options.input_dir = Some(PathBuf::from(value)),
+                "--answer" => options.answer = Some(value),
+                "--algo" => options.algorithm = Some(value),
+                "--inputs" => options.inputs_dir = Some(PathBuf::from(value)),
+                "--profile" => options.profile = Some(PathBuf::from(value)),
+                "--export" => options.export = Some(parse_export(&value)?),
+                "--export-dot" => options.export = Some((ExportFormat::Dot, PathBuf::from(value))),
+                "--param" => {
+                    let (name, value) = parse_param(&value)?;
+                    options.params.set(name, value);
+                }
+                "--format" => {
+                    options.output_format = Some(
+                        value
+                            .parse()
+                            .map_err(|e: ConfigParseError| OptionsParseError {
+                                message: e.message,
+                            })?,
+                    )
+                }
+                _ => {
+                    return Err(OptionsParseError {
+                        message: format!("unrecognised argument \"{flag}\""),
+                    })
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Parses the value given for the --day argument.
+fn parse_day(value: &str) -> Result<u64, OptionsParseError> {
+    match value.parse::<u64>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(OptionsParseError {
+            message: format!("--day must be between 1 and 25, got \"{value}\""),
+        }),
+    }
+}
+
+/// Parses the value given for the --export argument, taking the export format from the extension of
+/// the file.
+fn parse_export(value: &str) -> Result<(ExportFormat, PathBuf), OptionsParseError> {
+    let path = PathBuf::from(value);
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("dot") | Some("gv") => ExportFormat::Dot,
+        Some("json") => ExportFormat::Json,
+        _ => {
+            return Err(OptionsParseError {
+                message: format!("--export file must end in .dot, .gv or .json, got \"{value}\""),
+            })
+        }
+    };
+    Ok((format, path))
+}
+
+/// Parses the value given for the --param argument, as the name of the parameter and its value.
+fn parse_param(value: &str) -> Result<(&str, u64), OptionsParseError> {
+    match value.split_once('=') {
+        Some((name, param)) if !name.is_empty() => match param.parse::<u64>() {
+            Ok(param) => Ok((name, param)),
+            Err(_) => Err(OptionsParseError {
+                message: format!("--param value for \"{name}\" must be a number, got \"{param}\""),
+            }),
+        },
+        _ => Err(OptionsParseError {
+            message: format!("--param must be given as NAME=VALUE, got \"{value}\""),
+        }),
+    }
+}
+
+/// Parses the value given for the --part argument.
+fn parse_part(value: &str) -> Result<Part, OptionsParseError> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(OptionsParseError {
+            message: format!("--part must be 1 or 2, got \"{value}\""),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests parsing of the supported and unsupported runner arguments.
+    #[test]
+    fn test_run_options_from_args() {
+        let parse = |args: &[&str]| RunOptions::from_args(args.iter().map(|s| s.to_string()));
+        assert_eq!(Ok(RunOptions::default()), parse(&[]));
+        let options = RunOptions {
+            part: Some(Part::One),
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["--part", "1"]));
+        let options = RunOptions {
+            day: Some(16),
+            part: Some(Part::Two),
+            visualise: true,
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Ok(options),
+            parse(&["--day", "16", "--visualize", "--part=2"])
+        );
+        let options = RunOptions {
+            day: Some(13),
+            cross_check: true,
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["--cross-check", "--day=13"]));
+        let options = RunOptions {
+            config_file: Some(PathBuf::from("my.toml")),
+            input_dir: Some(PathBuf::from("./puzzles")),
+            output_format: Some(OutputFormat::Compact),
+            algorithm: Some(String::from("sieve")),
+            profile: Some(PathBuf::from("day13.svg")),
+            export: Some((ExportFormat::Dot, PathBuf::from("day13.dot"))),
+            ..RunOptions::default()
+        };
+        let args = [
+            "--algo=sieve",
+            "--profile=day13.svg",
+            "--export-dot",
+            "day13.dot",
+            "--config=my.toml",
+            "--input-dir",
+            "./puzzles",
+            "--format",
+            "compact",
+        ];
+        assert_eq!(Ok(options), parse(&args));
+        assert!(parse(&["--format", "yaml"]).is_err());
+        let options = RunOptions {
+            export: Some((ExportFormat::Json, PathBuf::from("tower.json"))),
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["--export=tower.json"]));
+        assert!(parse(&["--export", "tower.txt"]).is_err());
+        let mut params = Parameters::new();
+        params.set("part1-bursts", 70);
+        params.set("part2-bursts", 100);
+        let options = RunOptions {
+            day: Some(22),
+            params,
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Ok(options),
+            parse(&[
+                "--day=22",
+                "--param",
+                "part1-bursts=70",
+                "--param=part2-bursts=100"
+            ])
+        );
+        assert!(parse(&["--param", "part1-bursts"]).is_err());
+        assert!(parse(&["--param", "part1-bursts=many"]).is_err());
+        let options = RunOptions {
+            command: Command::Submit,
+            day: Some(3),
+            part: Some(Part::One),
+            answer: Some(String::from("480")),
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Ok(options),
+            parse(&["submit", "--day=3", "--part=1", "--answer=480"])
+        );
+        assert!(parse(&["publish", "--day=3"]).is_err());
+        let options = RunOptions {
+            command: Command::Batch,
+            day: Some(13),
+            inputs_dir: Some(PathBuf::from("./inputs/day13")),
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Ok(options),
+            parse(&["batch", "--day=13", "--inputs", "./inputs/day13"])
+        );
+        let options = RunOptions {
+            command: Command::Report,
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["report"]));
+        let options = RunOptions {
+            day: Some(9),
+            record: true,
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["--record", "--day", "9"]));
+        let options = RunOptions {
+            command: Command::History,
+            day: Some(9),
+            ..RunOptions::default()
+        };
+        assert_eq!(Ok(options), parse(&["history", "--day=9"]));
+        let options = RunOptions {
+            command: Command::Scaffold,
+            year: Some(2017),
+            day: Some(24),
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            Ok(options),
+            parse(&["scaffold", "--year", "2017", "--day", "24"])
+        );
+        assert!(parse(&["--part"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--day", "26"]).is_err());
+        assert!(parse(&["--verbose", "1"]).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+/// Memory allocated while a phase of a problem was being solved. Only recorded when built with the
+/// "profiling" feature.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryUsage {
+    /// Total bytes allocated during the phase, including memory that was later freed.
+    pub allocated: u64,
+    /// Peak bytes held at any one time during the phase, above those held at the start of it.
+    pub peak: u64,
+}
+
+/// Execution time (and memory usage, if tracked) for a single phase of solving a problem.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseStats {
+    pub duration: Duration,
+    pub memory: Option<MemoryUsage>,
+}
+
+/// Starts measuring the memory allocated by the first phase of solving a problem.
+pub fn start_memory_phase() {
+    allocations::start_phase();
+}
+
+/// Ends the current phase, returning the memory it allocated (or None if memory usage is not
+/// tracked), and starts measuring the next phase.
+pub fn lap_memory_phase() -> Option<MemoryUsage> {
+    let memory = allocations::end_phase();
+    allocations::start_phase();
+    memory
+}
+
+/// Formats a number of bytes using binary units (e.g. "1.50 MiB").
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+/// Allocation tracking using a counting global allocator, enabled by the "profiling" feature.
+#[cfg(feature = "profiling")]
+mod allocations {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::MemoryUsage;
+
+    /// Bytes currently held.
+    static CURRENT: AtomicU64 = AtomicU64::new(0);
+    /// Peak bytes held since the start of the current phase.
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+    /// Total bytes allocated since the program started.
+    static TOTAL: AtomicU64 = AtomicU64::new(0);
+    /// Bytes held and total bytes allocated at the start of the current phase.
+    static PHASE_CURRENT: AtomicU64 = AtomicU64::new(0);
+    static PHASE_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+    /// Global allocator that counts the bytes allocated by the system allocator.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Records an allocation of the given number of bytes.
+    fn record_alloc(size: u64) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        TOTAL.fetch_add(size, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record_alloc(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+                record_alloc(new_size as u64);
+            }
+            new_ptr
+        }
+    }
+
+    /// Marks the start of a phase.
+    pub fn start_phase() {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PHASE_CURRENT.store(current, Ordering::Relaxed);
+        PHASE_TOTAL.store(TOTAL.load(Ordering::Relaxed), Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+    }
+
+    /// Marks the end of a phase, returning the memory used since the start of the phase.
+    pub fn end_phase() -> Option<MemoryUsage> {
+        let phase_current = PHASE_CURRENT.load(Ordering::Relaxed);
+        Some(MemoryUsage {
+            allocated: TOTAL.load(Ordering::Relaxed) - PHASE_TOTAL.load(Ordering::Relaxed),
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(phase_current),
+        })
+    }
+}
+
+/// Placeholder for allocation tracking when the "profiling" feature is disabled.
+#[cfg(not(feature = "profiling"))]
+mod allocations {
+    use super::MemoryUsage;
+
+    /// Marks the start of a phase.
+    pub fn start_phase() {}
+
+    /// Marks the end of a phase. Memory usage is not tracked, so None is returned.
+    pub fn end_phase() -> Option<MemoryUsage> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the formatting of byte counts.
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("0 B", format_bytes(0));
+        assert_eq!("1023 B", format_bytes(1023));
+        assert_eq!("1.50 KiB", format_bytes(1536));
+        assert_eq!("3.00 MiB", format_bytes(3 * 1024 * 1024));
+    }
+
+    /// Tests that allocations made during a phase are counted, with the peak reflecting memory
+    /// held at the same time.
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_memory_phase() {
+        start_memory_phase();
+        let buffers = (0..4).map(|_| vec![0u8; 1 << 20]).collect::<Vec<Vec<u8>>>();
+        drop(buffers);
+        let memory = lap_memory_phase().unwrap();
+        // Other tests may allocate at the same time, so only check lower bounds
+        assert!(memory.allocated >= 4 << 20);
+        assert!(memory.peak >= 4 << 20);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::path::Path;
+use std::process;
+
+use crate::scaffold::{
+    cross_check, run_with_options, solve_day, solve_input_file, solve_part, solve_timed, Answer,
+    BatchResult, Config, CrossCheckResult, DayResult, Parameter, Parameters, Part, RunOptions,
+    Solver, TimedSolution,
+};
+
+/// Year of the problems solved by this crate, used when no year is selected.
+pub const AOC_YEAR: u64 = 2017;
+
+/// Entry in the year registry, holding the day registry for the problems of a single year. Solvers
+/// for other years are plugged in by adding an entry for the year to the registry.
+#[derive(Copy, Clone, Debug)]
+pub struct YearEntry {
+    /// Year of the problems.
+    pub year: u64,
+    /// Registry of the solvers for each problem of the year, in day order.
+    pub days: &'static [DayEntry],
+}
+
+impl YearEntry {
+    /// Finds the registry entry for the given day of the year.
+    pub fn day(&self, day: u64) -> Option<&'static DayEntry> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+}
+
+/// Custom error type indicating that no solvers are registered for the selected year.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownYearError {
+    pub year: u64,
+    /// Years with solvers registered, in registry order.
+    pub available: Vec<u64>,
+}
+
+impl fmt::Display for UnknownYearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let available = self
+            .available
+            .iter()
+            .map(|year| year.to_string())
+            .collect::<Vec<String>>();
+        write!(
+            f,
+            "No solvers registered for {} (available years: {})",
+            self.year,
+            available.join(", ")
+        )
+    }
+}
+
+/// Finds the registry entry for the given year.
+pub fn find_year(
+    years: &'static [YearEntry],
+    year: u64,
+) -> Result<&'static YearEntry, UnknownYearError> {
+    years
+        .iter()
+        .find(|entry| entry.year == year)
+        .ok_or_else(|| UnknownYearError {
+            year,
+            available: years.iter().map(|entry| entry.year).collect(),
+        })
+}
+
+/// Finds the registry entry for the year selected by the options ([`AOC_YEAR`] if none is
+/// selected). If there is no entry for the year, the error is printed to stderr and the process
+/// exits.
+pub fn select_year_or_exit(
+    years: &'static [YearEntry],
+    options: &RunOptions,
+) -> &'static YearEntry {
+    match find_year(years, options.year.unwrap_or(AOC_YEAR)) {
+        Ok(entry) => entry,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    }
+}
+
+/// Entry in the day registry, allowing the solvers for different problems (with different input
+/// types) to be run through a common interface.
+#[derive(Copy, Clone, Debug)]
+pub struct DayEntry {
+    /// Day number of the problem.
+    pub day: u64,
+    /// Name of the problem.
+    pub name: &'static str,
+    /// Default location of the problem input file.
+    pub input_file: &'static str,
+    /// Names of the alternative algorithms that can be selected to solve the problem.
+    pub algorithms: &'static [&'static str],
+    /// Parameters of the problem that can be overridden.
+    pub parameters: &'static [Parameter],
+    /// Runs the solver for the problem, printing the solutions and execution times to stdout.
+    pub run: fn(&RunOptions, &Config),
+    /// Solves the parts of the problem selected by the options, returning the solutions along
+    /// with their metadata.
+    pub solve_day: fn(&RunOptions, &Config) -> DayResult,
+    /// Solves a single part of the problem, returning None if the part is not implemented.
+    pub solve: fn(Part, &Config) -> Option<Answer>,
+    /// Solves both parts of the problem for the given input file (using the named algorithm, if
+    /// any), catching any panic raised by the solver.
+    pub solve_file: fn(&Path, Option<&str>) -> BatchResult,
+    /// Solves both parts of the problem with each of the alternative algorithms, returning the
+    /// answers given by each algorithm.
+    pub cross_check: fn(&Config) -> Vec<CrossCheckResult>,
+    /// Solves each part of the problem in turn with the given parameters, returning the solutions
+    /// along with the time taken by each phase.
+    pub solve_timed: fn(&Parameters, &Config) -> TimedSolution,
+}
+
+impl DayEntry {
+    /// Creates a new [`DayEntry`] for the given solver.
+    pub const fn new<S: Solver>() -> DayEntry {
+        DayEntry {
+            day: S::DAY,
+            name: S::NAME,
+            input_file: S::INPUT_FILE,
+            algorithms: S::ALGORITHMS,
+            parameters: S::PARAMETERS,
+            run: run_with_options::<S>,
+            solve_day: solve_day::<S>,
+            solve: solve_part::<S>,
+            solve_file: solve_input_file::<S>,
+            cross_check: cross_check::<S>,
+            solve_timed: solve_timed::<S>,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::YEARS;
+
+    /// Tests that the registry is keyed by year and day, with an error listing the available years
+    /// if the selected year has no solvers.
+    #[test]
+    fn test_year_registry() {
+        let year = find_year(YEARS, AOC_YEAR).unwrap();
+        assert_eq!(2017, year.year);
+        assert_eq!(Some("Inverse Captcha"), year.day(1).map(|entry| entry.name));
+        assert!(year.day(26).is_none());
+        let error = find_year(YEARS, 2018).unwrap_err();
+        assert_eq!(vec![2017], error.available);
+        assert_eq!(
+            "No solvers registered for 2018 (available years: 2017)",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::process;
+
+use aoc2017_core::utils::fmt::format_duration;
+
+use crate::scaffold::batch::format_table;
+use crate::scaffold::{
+    Config, DayEntry, Parameters, Phase, RunOptions, Solver, TimedSolution, Timings, TimingsReport,
+};
+
+/// Percentiles of the total execution time per day given in the report.
+const REPORT_PERCENTILES: [f64; 3] = [50.0, 90.0, 99.0];
+
+/// Processes the input file for the problem and solves each part in turn with the given parameters,
+/// returning the solutions along with the time taken by each phase (see [`Solver::solve_timed`]).
+pub fn solve_timed<S: Solver>(params: &Parameters, config: &Config) -> TimedSolution {
+    S::solve_timed(&config.input_file(S::INPUT_FILE).to_string_lossy(), params)
+}
+
+/// Solves each problem in the registry selected by the options (all problems by default), printing
+/// a table of the time taken by each phase of each problem, followed by the totals and percentiles
+/// across the problems.
+pub fn report_or_exit(days: &[DayEntry], options: &RunOptions, config: &Config) {
+    if !options.params.is_empty() && options.day.is_none() {
+        eprintln!("Invalid arguments: --param requires --day");
+        process::exit(2);
+    }
+    let selected = days
+        .iter()
+        .filter(|entry| options.day.is_none_or(|day| day == entry.day))
+        .collect::<Vec<&DayEntry>>();
+    if selected.is_empty() {
+        eprintln!("No solver registered for day {}", options.day.unwrap());
+        process::exit(1);
+    }
+    let mut report = TimingsReport::new();
+    for entry in selected {
+        if let Err(e) = options.params.validate(entry.parameters) {
+            eprintln!("Day {} - {e}", entry.day);
+            process::exit(2);
+        }
+        let solution = (entry.solve_timed)(&options.params, config);
+        report.add(entry.day, solution.timings);
+    }
+    format_report(&report)
+        .iter()
+        .for_each(|line| println!("{line}"));
+}
+
+/// Formats the report as a table with one row per day and a row of totals, followed by the
+/// percentiles of the total time per day and the slowest day.
+pub fn format_report(report: &TimingsReport) -> Vec<String> {
+    let row = |label: String, timings: &Timings| {
+        [
+            label,
+            format_duration(timings.get(Phase::Input)),
+            format_duration(timings.get(Phase::Part1)),
+            format_duration(timings.get(Phase::Part2)),
+            format_duration(timings.total()),
+        ]
+    };
+    let mut rows = vec![["Day", "Input", "Part 1", "Part 2", "Total"].map(String::from)];
+    for (day, timings) in report.days() {
+        rows.push(row(format!("{day:02}"), timings));
+    }
+    rows.push(row(String::from("TOTAL"), &report.total()));
+    let mut lines = format_table(&rows);
+    if let Some((day, total)) = report.slowest() {
+        let percentiles = REPORT_PERCENTILES
+            .iter()
+            .map(|&p| format!("p{p}: {}", format_duration(report.percentile(p).unwrap())))
+            .collect::<Vec<String>>();
+        lines.push(format!(
+            "{} | slowest: day {day:02} ({})",
+            percentiles.join(" | "),
+            format_duration(total)
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Tests the formatting of a report with the timings of two days.
+    #[test]
+    fn test_format_report() {
+        let ms = Duration::from_millis;
+        let mut report = TimingsReport::new();
+        let timings = |parse, part1, part2| {
+            Timings::new(vec![
+                (Phase::Input, parse),
+                (Phase::Part1, part1),
+                (Phase::Part2, part2),
+            ])
+        };
+        report.add(1, timings(ms(1), ms(2), ms(3)));
+        report.add(15, timings(Duration::from_micros(500), ms(120), ms(250)));
+        let expected = vec![
+            "Day   | Input    | Part 1   | Part 2   | Total",
+            "01    | 1.00ms   | 2.00ms   | 3.00ms   | 6.00ms",
+            "15    | 500.00µs | 120.00ms | 250.00ms | 370.50ms",
+            "TOTAL | 1.50ms   | 122.00ms | 253.00ms | 376.50ms",
+            "p50: 6.00ms | p90: 370.50ms | p99: 370.50ms | slowest: day 15 (370.50ms)",
+        ];
+        assert_eq!(expected, format_report(&report));
+        assert_eq!(
+            vec![
+                "Day   | Input  | Part 1 | Part 2 | Total",
+                "TOTAL | 0.00ns | 0.00ns | 0.00ns | 0.00ns"
+            ],
+            format_report(&TimingsReport::new())
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use crate::scaffold::dayresult::hash_input_file;
+use crate::scaffold::profiling::{format_bytes, lap_memory_phase, start_memory_phase};
+use crate::scaffold::{
+    cross_check_or_exit, profile_or_exit, record_or_exit, Answer, Command, Config, DayEntry,
+    DayResult, OutputFormat, Parameters, Part, PhaseStats, RunOptions, SolveOptions, Solver,
+};
+
+/// Processes the input file for the problem and solves the parts of the problem selected by the
+/// command line arguments (both parts by default). Solutions and execution times are printed to
+/// stdout.
+pub fn run<S: Solver>() {
+    let options = parse_args_or_exit(&format!(
+        "day{:02} [--part <1|2>] [--algo <NAME>] [--visualize] [--cross-check] [--record] \
+        [--config <FILE>] \
+        [--input-dir <DIR>] [--format <text|compact>] [--profile <FILE>] [--export <FILE>] \
+        [--export-dot <FILE>]",
+        S::DAY
+    ));
+    if options.command != Command::Run {
+        eprintln!("Invalid arguments: use the aoc binary to submit answers");
+        process::exit(2);
+    }
+    if options.day.is_some_and(|day| day != S::DAY) {
+        eprintln!("Invalid arguments: this binary only solves day {}", S::DAY);
+        process::exit(2);
+    }
+    let config = load_config_or_exit(&options);
+    if options.cross_check {
+        let entry = DayEntry::new::<S>();
+        cross_check_or_exit(&[entry], &options, &config);
+        return;
+    }
+    match &options.profile {
+        Some(output) => profile_or_exit(output, || run_with_options::<S>(&options, &config)),
+        None => run_with_options::<S>(&options, &config),
+    }
+}
+
+/// Solves each problem in the registry selected by the options (all problems by default). Solutions
+/// and execution times are printed to stdout.
+///
+/// Parts that have not been implemented are reported as such, and the remaining problems are still
+/// solved. If profiling is selected, a flamegraph of the run is written to the given file. If
+/// cross-checking is selected, the alternative algorithms are compared instead (see
+/// [`cross_check_or_exit`]).
+pub fn run_days(days: &[DayEntry], options: &RunOptions, config: &Config) {
+    if options.cross_check {
+        cross_check_or_exit(days, options, config);
+        return;
+    }
+    if options.algorithm.is_some() && options.day.is_none() {
+        eprintln!("Invalid arguments: --algo requires --day");
+        process::exit(2);
+    }
+    if options.profile.is_some() && options.day.is_none() {
+        eprintln!("Invalid arguments: --profile requires --day");
+        process::exit(2);
+    }
+    if options.export.is_some() && options.day.is_none() {
+        eprintln!("Invalid arguments: --export requires --day");
+        process::exit(2);
+    }
+    if !options.params.is_empty() && options.day.is_none() {
+        eprintln!("Invalid arguments: --param requires --day");
+        process::exit(2);
+    }
+    let selected = days
+        .iter()
+        .filter(|entry| options.day.is_none_or(|day| day == entry.day))
+        .collect::<Vec<&DayEntry>>();
+    if selected.is_empty() {
+        eprintln!("No solver registered for day {}", options.day.unwrap());
+        process::exit(1);
+    }
+    for entry in selected {
+        match &options.profile {
+            Some(output) => profile_or_exit(output, || (entry.run)(options, config)),
+            None => (entry.run)(options, config),
+        }
+    }
+}
+
+/// Parses the runner options from the command line arguments. If the arguments are invalid, the
+/// error and usage are printed to stderr and the process exits.
+pub fn parse_args_or_exit(usage: &str) -> RunOptions {
+    match RunOptions::from_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("Usage: {usage}");
+            process::exit(2);
+        }
+    }
+}
+
+/// Loads the runner config, with values given in the options overriding those from the config
+/// file. If the config file cannot be loaded, the error is printed to stderr and the process exits.
+pub fn load_config_or_exit(options: &RunOptions) -> Config {
+    match Config::load(options) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(2);
+        }
+    }
+}
+
+/// Processes the input file for the problem and solves a single part of the problem, returning None
+/// if the part is not implemented.
+pub fn solve_part<S: Solver>(part: Part, config: &Config) -> Option<Answer> {
+    let input = S::process_input_file(&config.input_file(S::INPUT_FILE).to_string_lossy());
+    solve_with::<S>(&input, part, None)
+}
+
+/// Solves a single part of the problem using the named algorithm, or the default algorithm if None.
+pub fn solve_with<S: Solver>(
+    input: &S::Input,
+    part: Part,
+    algorithm: Option<&str>,
+) -> Option<Answer> {
+    match (part, algorithm) {
+        (Part::One, None) => S::solve_part1(input),
+        (Part::Two, None) => S::solve_part2(input),
+        (Part::One, Some(algorithm)) => S::solve_part1_with(input, algorithm),
+        (Part::Two, Some(algorithm)) => S::solve_part2_with(input, algorithm),
+    }
+}
+
+/// Checks that the algorithm selected by the options (if any) is one of the alternative algorithms
+/// for the problem. If not, the error is printed to stderr and the process exits.
+fn select_algorithm_or_exit<S: Solver>(options: &RunOptions) -> Option<&str> {
+    let algorithm = options.algorithm.as_deref()?;
+    if !S::ALGORITHMS.contains(&algorithm) {
+        match S::ALGORITHMS.is_empty() {
+            true => eprintln!("Day {} has no alternative algorithms to select", S::DAY),
+            false => eprintln!(
+                "Day {} has no algorithm \"{algorithm}\" (expected one of: {})",
+                S::DAY,
+                S::ALGORITHMS.join(", ")
+            ),
+        }
+        process::exit(2);
+    }
+    Some(algorithm)
+}
+
+/// Checks that the parameters overridden by the options (if any) are supported by the problem. If
+/// not, the error and the supported parameters are printed to stderr and the process exits.
+fn check_parameters_or_exit<S: Solver>(options: &RunOptions) {
+    if let Err(e) = options.params.validate(S::PARAMETERS) {
+        eprintln!("Day {} - {e}", S::DAY);
+        process::exit(2);
+    }
+}
+
+/// Processes the input file for the problem and solves the parts of the problem selected by the
+/// options, returning the solutions along with the execution times and hash of the input file.
+///
+/// If a single part is selected, the other part is not solved at all. If an alternative algorithm
+/// is selected, each part is solved separately using that algorithm. Parameters overridden by the
+/// options are applied to the input before solving.
+pub fn solve_day<S: Solver>(options: &RunOptions, config: &Config) -> DayResult {
+    let algorithm = select_algorithm_or_exit::<S>(options);
+    check_parameters_or_exit::<S>(options);
+    solve_day_with_input::<S>(options, config, algorithm).0
+}
+
+/// Solves the problem as described for [`solve_day`], also returning the processed input.
+fn solve_day_with_input<S: Solver>(
+    options: &RunOptions,
+    config: &Config,
+    algorithm: Option<&str>,
+) -> (DayResult, S::Input) {
+    let input_file = config.input_file(S::INPUT_FILE);
+    let input_hash = hash_input_file(&input_file)
+        .unwrap_or_else(|e| panic!("Cannot read input file \"{}\" - {e}", input_file.display()));
+    let solve_options = SolveOptions {
+        part1: options.part != Some(Part::Two),
+        part2: options.part != Some(Part::One),
+        algorithm,
+        combined: true,
+    };
+    let mut memory = vec![];
+    start_memory_phase();
+    let (solution, input) = S::solve_timed_with(
+        &input_file.to_string_lossy(),
+        &options.params,
+        &solve_options,
+        |_| memory.push(lap_memory_phase()),
+    );
+    let timings = solution
+        .timings
+        .phases()
+        .iter()
+        .zip(memory)
+        .map(|((phase, duration), memory)| {
+            let stats = PhaseStats {
+                duration: *duration,
+                memory,
+            };
+            (phase.to_string(), stats)
+        })
+        .collect();
+    let result = DayResult {
+        day: S::DAY,
+        name: S::NAME.to_string(),
+        part1: solution.part1,
+        part2: solution.part2,
+        timings,
+        input_hash,
+    };
+    (result, input)
+}
+
+/// Processes the input file for the problem and solves the parts of the problem selected by the
+/// options (see [`solve_day`]). Solutions and execution times are printed to stdout in the
+/// configured format.
+///
+/// If visualisation is selected, the visualisation of the input is printed after the solutions
+/// (outside of the timings). If export is selected, the input is exported in the selected format to
+/// the given file afterwards. When built with the "profiling" feature, the memory allocated in each
+/// phase is reported alongside its execution time. If recording is selected, the results and
+/// execution times are appended to the run history file.
+pub fn run_with_options<S: Solver>(options: &RunOptions, config: &Config) {
+    let algorithm = select_algorithm_or_exit::<S>(options);
+    check_parameters_or_exit::<S>(options);
+    let (result, input) = solve_day_with_input::<S>(options, config, algorithm);
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    let budget = config.time_budgets.get(&S::DAY).copied();
+    match config.output_format {
+        OutputFormat::Text => print_text(&result, &parts, budget, algorithm, &options.params),
+        OutputFormat::Compact => print_compact(&result, &parts, budget),
+    }
+    if options.record {
+        record_or_exit(&result, config);
+    }
+    if options.visualise {
+        match S::visualise(&input) {
+            Some(visualisation) => print!("{visualisation}"),
+            None => println!("Day {} does not support visualisation", S::DAY),
+        }
+    }
+    if let Some((format, output)) = &options.export {
+        match S::export(&input, *format) {
+            Some(export) => write_export_or_exit(output, &export),
+            None => println!("Day {} does not support {format} export", S::DAY),
+        }
+    }
+}
+
+/// Writes the exported problem input to the output file. If the file cannot be written, the error
+/// is printed to stderr and the process exits.
+fn write_export_or_exit(output: &Path, contents: &str) {
+    if let Err(e) = fs::write(output, contents) {
+        eprintln!("Cannot write \"{}\" - {e}", output.display());
+        process::exit(1);
+    }
+    println!("[~] Export written to {}", output.display());
+}
+
+/// Formats the solution for a part, with parts without a solution reported as not implemented.
+fn format_solution(solution: &Option<Answer>) -> String {
+    match solution {
+        Some(solution) => solution.to_string(),
+        None => String::from("not implemented"),
+    }
+}
+
+/// Prints the problem title, solutions and execution times to stdout as a multi-line block. If the
+/// total execution time is over the time budget for the problem, this is flagged after the total.
+/// The selected algorithm and overridden parameters (if any) are given after the problem title, and
+/// the memory used by each phase (if tracked) is given after its execution time.
+fn print_text(
+    result: &DayResult,
+    parts: &[Part],
+    budget: Option<Duration>,
+    algorithm: Option<&str>,
+    params: &Parameters,
+) {
+    println!("==================================================");
+    let mut title = format!("AOC 2017 Day {} - \"{}\"", result.day, result.name);
+    if let Some(algorithm) = algorithm {
+        title.push_str(&format!(" (algorithm: {algorithm})"));
+    }
+    for (name, value) in params.overrides() {
+        title.push_str(&format!(" ({name}: {value})"));
+    }
+    println!("{title}");
+    for part in parts {
+        println!("[+] {part}: {}", format_solution(result.solution(*part)));
+    }
+    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
+    println!("Execution times:");
+    for (label, stats) in result.timings.iter() {
+        let label = format!("{label}:");
+        match stats.memory {
+            Some(memory) => println!(
+                "[+] {label:<8}{:<12}(allocated {}, peak {})",
+                format!("{:.2?}", stats.duration),
+                format_bytes(memory.allocated),
+                format_bytes(memory.peak)
+            ),
+            None => println!("[+] {label:<8}{:.2?}", stats.duration),
+        }
+    }
+    let total = result.total_duration();
+    println!("[*] TOTAL:  {total:.2?}");
+    if let Some(budget) = budget.filter(|budget| total > *budget) {
+        println!("[!] Over time budget of {budget:.2?}");
+    }
+    println!("==================================================");
+}
+
+/// Prints the solutions and total execution time for the problem to stdout as a single line. If the
+/// total execution time is over the time budget for the problem, this is flagged at the end.
+fn print_compact(result: &DayResult, parts: &[Part], budget: Option<Duration>) {
+    let mut line = format!("Day {:02}", result.day);
+    for part in parts {
+        let solution = format_solution(result.solution(*part));
+        line.push_str(&format!(" | {part}: {solution}"));
+    }
+    let total = result.total_duration();
+    line.push_str(&format!(" | {total:.2?}"));
+    if let Some(budget) = budget.filter(|budget| total > *budget) {
+        line.push_str(&format!(" (over budget of {budget:.2?})"));
+    }
+    println!("{line}");
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::process;
+
+use crate::scaffold::{AnswersCache, Config, Part, RunOptions, SubmissionOutcome, YearEntry};
+
+/// Environment variable checked for the session token if no session token file is configured.
+#[cfg(feature = "submit")]
+const SESSION_TOKEN_VAR: &str = "AOC_SESSION";
+
+/// Custom error type indicating that an answer could not be submitted to adventofcode.com.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SubmitError {
+    pub message: String,
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Submission failed: {}", self.message)
+    }
+}
+
+/// Submits the answer for the day and part selected by the options to adventofcode.com, and
+/// records the outcome in the answers cache. The answer given in the options is used if present,
+/// otherwise the answer is calculated by the solver for the problem.
+///
+/// The answer is not submitted if the answers cache shows that the part is already solved, or that
+/// the answer is wrong. Any error is printed to stderr and the process exits.
+pub fn submit_or_exit(year: &YearEntry, options: &RunOptions, config: &Config) {
+    if let Err(e) = submit(year, options, config) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Submits the answer as described for [`submit_or_exit`].
+fn submit(year: &YearEntry, options: &RunOptions, config: &Config) -> Result<(), SubmitError> {
+    let (Some(day), Some(part)) = (options.day, options.part) else {
+        return Err(SubmitError {
+            message: String::from("--day and --part are required to submit an answer"),
+        });
+    };
+    // Determine the answer to submit
+    let answer = match &options.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let entry = year.day(day).ok_or_else(|| SubmitError {
+                message: format!("no solver registered for day {day}"),
+            })?;
+            (entry.solve)(part, config)
+                .ok_or_else(|| SubmitError {
+                    message: format!("day {day} {part} is not implemented"),
+                })?
+                .to_string()
+        }
+    };
+    // Check the answer against previous submissions
+    let mut cache = AnswersCache::load(&config.answers_file()).map_err(|e| SubmitError {
+        message: e.to_string(),
+    })?;
+    if let Some(correct) = cache.correct_answer(day, part) {
+        println!("Day {day} {part} already solved with answer {correct} (given {answer})");
+        return Ok(());
+    }
+    if let Some(record) = cache.known_outcome(day, part, &answer) {
+        println!(
+            "Not submitting {answer} for day {day} {part} - previous answer {} was {}",
+            record.answer, record.outcome
+        );
+        return Ok(());
+    }
+    // Submit the answer and record the outcome
+    let outcome = post_answer(config, year.year, day, part, &answer)?;
+    println!("Day {day} {part} answer {answer}: {outcome}");
+    if outcome.is_verdict() {
+        cache.record(day, part, &answer, outcome);
+        cache.save().map_err(|e| SubmitError {
+            message: e.to_string(),
+        })?;
+    }
+    Ok(())
+}
+
+/// Reads the session token from the configured session token file, or from the AOC_SESSION
+/// environment variable if no file is configured.
+#[cfg(feature = "submit")]
+fn read_session_token(config: &Config) -> Result<String, SubmitError> {
+    let token = match &config.session_token_file {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| SubmitError {
+            message: format!("could not read session token \"{}\" ({e})", path.display()),
+        })?,
+        None => std::env::var(SESSION_TOKEN_VAR).map_err(|_| SubmitError {
+            message: format!("no session_token_file configured and {SESSION_TOKEN_VAR} not set"),
+        })?,
+    };
+    Ok(token.trim().to_string())
+}
+
+/// Posts the answer to adventofcode.com (using the configured session token) and determines the
+/// outcome from the response.
+#[cfg(feature = "submit")]
+fn post_answer(
+    config: &Config,
+    year: u64,
+    day: u64,
+    part: Part,
+    answer: &str,
+) -> Result<SubmissionOutcome, SubmitError> {
+    let session_token = read_session_token(config)?;
+    let url = format!("https://adventofcode.com/{year}/day/{day}/answer");
+    let level = match part {
+        Part::One => "1",
+        Part::Two => "2",
+    };
+    let body = ureq::post(&url)
+        .set("Cookie", &format!("session={session_token}"))
+        .set(
+            "User-Agent",
+            "github.com/cmooneycollett/aoc2017 by connor.mooneycollett@gmail.com",
+        )
+        .send_form(&[("level", level), ("answer", answer)])
+        .map_err(|e| SubmitError {
+            message: e.to_string(),
+        })?
+        .into_string()
+        .map_err(|e| SubmitError {
+            message: e.to_string(),
+        })?;
+    Ok(SubmissionOutcome::from_response(&body))
+}
+
+/// Stand-in for posting the answer when the crate is built without the "submit" feature.
+#[cfg(not(feature = "submit"))]
+fn post_answer(
+    _config: &Config,
+    year: u64,
+    day: u64,
+    part: Part,
+    _answer: &str,
+) -> Result<SubmissionOutcome, SubmitError> {
+    Err(SubmitError {
+        message: format!(
+            "cannot submit day {day} {part} for {year} - rebuild with \"--features submit\""
+        ),
+    })
+}
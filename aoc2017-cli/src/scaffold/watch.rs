@@ -0,0 +1,227 @@
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+use crate::scaffold::{Config, DayEntry, RunOptions};
+
+/// Custom error type indicating that watch mode could not be started or has failed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct WatchError {
+    pub message: String,
+}
+
+impl fmt::Display for WatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Watch failed: {}", self.message)
+    }
+}
+
+/// Summary of a single run of a day, parsed from the compact output of the day binary (e.g.
+/// "Day 03 | Part 1: 480 | Part 2: 349975 | 510.58µs").
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RunSummary {
+    /// Label and answer for each part that was solved.
+    pub solutions: Vec<(String, String)>,
+    /// Total execution time, as printed by the day binary.
+    pub total: String,
+}
+
+impl RunSummary {
+    /// Describes the changes in the answers and total execution time from the previous run.
+    pub fn changes_from(&self, previous: &RunSummary) -> Vec<String> {
+        let mut changes = vec![];
+        for (part, answer) in &self.solutions {
+            let previous_answer = previous
+                .solutions
+                .iter()
+                .find(|(previous_part, _)| previous_part == part)
+                .map(|(_, previous_answer)| previous_answer);
+            match previous_answer {
+                Some(previous_answer) if previous_answer != answer => {
+                    changes.push(format!("{part}: {previous_answer} -> {answer}"));
+                }
+                Some(_) => (),
+                None => changes.push(format!("{part}: {answer} (new)")),
+            }
+        }
+        changes.push(format!("Time: {} -> {}", previous.total, self.total));
+        changes
+    }
+}
+
+impl FromStr for RunSummary {
+    type Err = WatchError;
+
+    /// Parses the compact output line for a day.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || WatchError {
+            message: format!("unexpected output \"{s}\""),
+        };
+        let mut fields = s.split(" | ");
+        if !fields.next().is_some_and(|field| field.starts_with("Day ")) {
+            return Err(invalid());
+        }
+        let fields = fields.collect::<Vec<&str>>();
+        let Some((total, solutions)) = fields.split_last() else {
+            return Err(invalid());
+        };
+        let solutions = solutions
+            .iter()
+            .map(|field| {
+                let (part, answer) = field.split_once(": ").ok_or_else(invalid)?;
+                Ok((part.to_string(), answer.to_string()))
+            })
+            .collect::<Result<Vec<(String, String)>, WatchError>>()?;
+        // Drop any over budget note following the total time
+        let total = total.split(" (").next().unwrap().to_string();
+        Ok(RunSummary { solutions, total })
+    }
+}
+
+/// Re-runs the day selected by the options whenever its solver module or input file changes,
+/// printing the results and the changes from the previous run. Any error is printed to stderr and
+/// the process exits.
+pub fn watch_or_exit(days: &[DayEntry], options: &RunOptions, config: &Config) {
+    if let Err(e) = watch(days, options, config) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Runs watch mode as described for [`watch_or_exit`].
+#[cfg(feature = "watch")]
+fn watch(days: &[DayEntry], options: &RunOptions, config: &Config) -> Result<(), WatchError> {
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use notify::{RecursiveMode, Watcher};
+
+    let watch_error = |e: &dyn fmt::Display| WatchError {
+        message: e.to_string(),
+    };
+    let day = options.day.ok_or_else(|| WatchError {
+        message: String::from("--day is required to watch a day"),
+    })?;
+    let entry = days
+        .iter()
+        .find(|entry| entry.day == day)
+        .ok_or_else(|| WatchError {
+            message: format!("no solver registered for day {day}"),
+        })?;
+    // Watch the directories holding the files, as editors often replace files when saving them
+    let files = [
+        PathBuf::from(format!("./src/days/day{day:02}.rs")),
+        config.input_file(entry.input_file),
+    ]
+    .iter()
+    .map(|file| file.canonicalize().map_err(|e| watch_error(&e)))
+    .collect::<Result<Vec<PathBuf>, WatchError>>()?;
+    let dirs = files
+        .iter()
+        .filter_map(|file| file.parent())
+        .collect::<HashSet<_>>();
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| watch_error(&e))?;
+    for dir in dirs {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|e| watch_error(&e))?;
+    }
+    for file in &files {
+        println!("[~] Watching {} for changes", file.display());
+    }
+    let mut previous = run_day(day, options);
+    for event in &receiver {
+        let Ok(event) = event else {
+            continue;
+        };
+        if !event.kind.is_modify() && !event.kind.is_create() {
+            continue;
+        }
+        if !event.paths.iter().any(|path| files.contains(path)) {
+            continue;
+        }
+        // Wait for the burst of events from a single save to finish
+        while receiver.recv_timeout(Duration::from_millis(200)).is_ok() {}
+        println!("[~] Change detected - re-running day {day}");
+        let current = run_day(day, options);
+        if let (Some(previous), Some(current)) = (&previous, &current) {
+            for change in current.changes_from(previous) {
+                println!("[~] {change}");
+            }
+        }
+        if current.is_some() {
+            previous = current;
+        }
+    }
+    Ok(())
+}
+
+/// Builds and runs the binary for the day, printing its output. Returns the summary of the run, or
+/// None if the binary failed to build or run.
+#[cfg(feature = "watch")]
+fn run_day(day: u64, options: &RunOptions) -> Option<RunSummary> {
+    use std::process::{Command, Stdio};
+
+    let mut command = Command::new("cargo");
+    command
+        .args(["run", "--release", "--quiet", "--bin"])
+        .arg(format!("day{day:02}"))
+        .args(["--", "--format", "compact"]);
+    if let Some(part) = options.part {
+        command.arg("--part").arg(match part {
+            crate::scaffold::Part::One => "1",
+            crate::scaffold::Part::Two => "2",
+        });
+    }
+    if let Some(config_file) = &options.config_file {
+        command.arg("--config").arg(config_file);
+    }
+    if let Some(input_dir) = &options.input_dir {
+        command.arg("--input-dir").arg(input_dir);
+    }
+    if let Some(algorithm) = &options.algorithm {
+        command.arg("--algo").arg(algorithm);
+    }
+    for (name, value) in options.params.overrides() {
+        command.arg("--param").arg(format!("{name}={value}"));
+    }
+    let output = command.stderr(Stdio::inherit()).output().ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{stdout}");
+    if !output.status.success() {
+        println!("[!] Day {day} failed to build or run");
+        return None;
+    }
+    stdout.lines().last()?.parse::<RunSummary>().ok()
+}
+
+/// Stand-in for watch mode when the crate is built without the "watch" feature.
+#[cfg(not(feature = "watch"))]
+fn watch(_days: &[DayEntry], _options: &RunOptions, _config: &Config) -> Result<(), WatchError> {
+    Err(WatchError {
+        message: String::from("watch mode is not available - rebuild with \"--features watch\""),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests parsing of the compact output of a day binary, and the changes between two runs.
+    #[test]
+    fn test_run_summary_changes() {
+        let previous = "Day 03 | Part 1: 470 | Part 2: 349975 | 600.85µs (over budget of 10.00µs)"
+            .parse::<RunSummary>()
+            .unwrap();
+        assert_eq!("600.85µs", previous.total);
+        let current = "Day 03 | Part 1: 480 | Part 2: 349975 | 510.58µs"
+            .parse::<RunSummary>()
+            .unwrap();
+        let expected = vec!["Part 1: 470 -> 480", "Time: 600.85µs -> 510.58µs"];
+        assert_eq!(expected, current.changes_from(&previous));
+        assert!("[+] Part 1: 480".parse::<RunSummary>().is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use std::{env, fs, process};
+
+use aoc2017_core::utils::machines::soundcomputer::Instruction;
+use aoc2017_viz::dashboard::run_dashboard;
+use aoc2017_viz::duet::DuetSession;
+
+/// Input file used if no path is given on the command line.
+const DEFAULT_INPUT_FILE: &str = "./input/day18.txt";
+
+/// Runs the AOC 2017 Day 18 part 2 duet in an interactive terminal dashboard. The input file path
+/// can be given as the first argument, otherwise the Day 18 input file is used.
+pub fn main() {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_INPUT_FILE.to_string());
+    let raw_input = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Failed to read input file {path}: {e}");
+        process::exit(1);
+    });
+    let program = Instruction::parse_program(&raw_input).unwrap_or_else(|e| {
+        eprintln!("Invalid program in {path}: {e}");
+        process::exit(1);
+    });
+    let session = DuetSession::new(&program).unwrap_or_else(|e| {
+        eprintln!("Program cannot be run as a duet: {e:?}");
+        process::exit(1);
+    });
+    if let Err(e) = run_dashboard(session) {
+        eprintln!("Dashboard failed: {e}");
+        process::exit(1);
+    }
+}
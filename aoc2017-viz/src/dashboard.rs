@@ -0,0 +1,126 @@
+use std::io;
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::duet::{DuetSession, MachineStatus, MachineView};
+
+/// Time between each redraw of the dashboard.
+const TICK_RATE: Duration = Duration::from_millis(50);
+/// Maximum number of duet steps taken between each redraw.
+const MAX_STEPS_PER_TICK: u64 = 1 << 20;
+
+/// Runs the dashboard for the duet session in the terminal until the user quits.
+///
+/// Controls: space pauses or resumes the duet, "s" or the right arrow takes a single step, "+" and
+/// "-" double or halve the number of steps taken between redraws, and "q" or escape quits.
+pub fn run_dashboard(session: DuetSession) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run_loop(&mut terminal, session);
+    ratatui::restore();
+    result
+}
+
+/// Redraws the dashboard and handles user input until the user quits.
+fn run_loop(terminal: &mut DefaultTerminal, mut session: DuetSession) -> io::Result<()> {
+    let mut steps_per_tick: u64 = 1;
+    loop {
+        terminal.draw(|frame| draw(frame, &session, steps_per_tick))?;
+        if event::poll(TICK_RATE)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') => session.toggle_pause(),
+                    KeyCode::Char('s') | KeyCode::Right => {
+                        session.step();
+                    }
+                    KeyCode::Char('+') => {
+                        steps_per_tick = (steps_per_tick * 2).min(MAX_STEPS_PER_TICK)
+                    }
+                    KeyCode::Char('-') => steps_per_tick = (steps_per_tick / 2).max(1),
+                    _ => (),
+                }
+            }
+        }
+        session.tick(steps_per_tick);
+    }
+}
+
+/// Draws the full dashboard - status line, one panel for each program and the help line.
+fn draw(frame: &mut Frame, session: &DuetSession, steps_per_tick: u64) {
+    let [status_area, machines_area, help_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let state = match session.outcome() {
+        None if session.is_paused() => String::from("paused"),
+        None => String::from("running"),
+        Some(Ok(report)) => format!("finished ({:?})", report.termination),
+        Some(Err(e)) => format!("failed ({e:?})"),
+    };
+    let status = format!(
+        "AOC 2017 Day 18 duet - {state} - step {} - {steps_per_tick} steps per tick",
+        session.steps()
+    );
+    frame.render_widget(
+        Paragraph::new(status).style(Style::new().add_modifier(Modifier::BOLD)),
+        status_area,
+    );
+    let [left, right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(machines_area);
+    draw_machine(frame, &session.machine(0), left);
+    draw_machine(frame, &session.machine(1), right);
+    frame.render_widget(
+        Paragraph::new("space: pause/resume | s/→: step | +/-: speed | q/esc: quit"),
+        help_area,
+    );
+}
+
+/// Draws the panel for one of the programs - execution summary above a table of its registers.
+fn draw_machine(frame: &mut Frame, machine: &MachineView, area: Rect) {
+    let block = Block::bordered().title(format!(" Program {} ", machine.id));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let [summary_area, registers_area] =
+        Layout::vertical([Constraint::Length(5), Constraint::Min(0)]).areas(inner);
+    let status = match machine.status {
+        MachineStatus::Running => "running",
+        MachineStatus::AwaitingInput => "awaiting input",
+        MachineStatus::Halted => "halted",
+    };
+    let summary = vec![
+        Line::from(format!("status: {status}")),
+        Line::from(format!(
+            "pc: {} ({})",
+            machine.pc,
+            machine.opcode.unwrap_or("-")
+        )),
+        Line::from(format!(
+            "receive queue: {} (max {})",
+            machine.received_queue_len, machine.max_received_queue_len
+        )),
+        Line::from(format!("sounds sent: {}", machine.total_sounds_sent)),
+        Line::from(format!(
+            "sounds received: {}",
+            machine.total_sounds_received
+        )),
+    ];
+    frame.render_widget(Paragraph::new(summary), summary_area);
+    let rows = machine
+        .registers
+        .iter()
+        .map(|(reg, value)| Row::new([reg.to_string(), value.to_string()]));
+    let table = Table::new(rows, [Constraint::Length(8), Constraint::Min(0)])
+        .header(Row::new(["register", "value"]).style(Style::new().add_modifier(Modifier::BOLD)))
+        .block(Block::new().title("Registers"));
+    frame.render_widget(table, registers_area);
+}
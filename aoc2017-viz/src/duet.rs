@@ -0,0 +1,207 @@
+use aoc2017_core::utils::machines::duet::{Duet, DuetReport};
+use aoc2017_core::utils::machines::soundcomputer::{
+    ExecutionError, Instruction, InstructionArgument, ProgramLoadError,
+};
+
+/// Execution status of one of the programs in a [`DuetSession`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MachineStatus {
+    Running,
+    AwaitingInput,
+    Halted,
+}
+
+/// State of one of the programs in a [`DuetSession`], as shown on the dashboard.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MachineView {
+    /// Program ID (0 or 1).
+    pub id: usize,
+    pub status: MachineStatus,
+    /// Location of the next instruction to be executed.
+    pub pc: usize,
+    /// Opcode of the next instruction to be executed, or None if the program counter is outside of
+    /// the program.
+    pub opcode: Option<&'static str>,
+    /// Values of the registers referenced by the program (along with "p"), in register order.
+    pub registers: Vec<(char, i128)>,
+    /// Number of sounds waiting to be received by the program.
+    pub received_queue_len: usize,
+    /// Maximum number of sounds waiting to be received by the program at any one time.
+    pub max_received_queue_len: usize,
+    pub total_sounds_sent: u64,
+    pub total_sounds_received: u64,
+}
+
+/// Interactive run of the AOC 2017 Day 18 duet, advanced one step at a time (see [`Duet::step`])
+/// so the state of both programs can be shown as the duet runs.
+///
+/// The session starts paused. While paused, [`DuetSession::tick`] does nothing, but single steps
+/// can still be taken with [`DuetSession::step`].
+pub struct DuetSession {
+    duet: Duet,
+    program: Vec<Instruction>,
+    registers: Vec<char>,
+    paused: bool,
+    steps: u64,
+    outcome: Option<Result<DuetReport, ExecutionError>>,
+}
+
+impl DuetSession {
+    /// Creates a new [`DuetSession`] with both programs loaded with the given instructions.
+    ///
+    /// Returns a [`ProgramLoadError`] if the program cannot be run in duet mode.
+    pub fn new(instructions: &[Instruction]) -> Result<DuetSession, ProgramLoadError> {
+        Ok(DuetSession {
+            duet: Duet::new(instructions)?,
+            program: instructions.to_vec(),
+            registers: referenced_registers(instructions),
+            paused: true,
+            steps: 0,
+            outcome: None,
+        })
+    }
+
+    /// Checks if the session is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses the session if it is running, or resumes it if it is paused.
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Gets the number of steps taken so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Gets the outcome of the duet - the final report, or the error raised by either program - or
+    /// None if the duet is still running.
+    pub fn outcome(&self) -> Option<&Result<DuetReport, ExecutionError>> {
+        self.outcome.as_ref()
+    }
+
+    /// Takes a single step of the duet, unless it has already finished. Returns true if a step was
+    /// taken.
+    pub fn step(&mut self) -> bool {
+        if self.outcome.is_some() {
+            return false;
+        }
+        self.steps += 1;
+        match self.duet.step() {
+            Ok(Some(report)) => self.outcome = Some(Ok(report)),
+            Ok(None) => (),
+            Err(e) => self.outcome = Some(Err(e)),
+        }
+        true
+    }
+
+    /// Takes up to the given number of steps, unless the session is paused. Stops early if the duet
+    /// finishes.
+    pub fn tick(&mut self, steps: u64) {
+        if self.paused {
+            return;
+        }
+        for _ in 0..steps {
+            if !self.step() {
+                break;
+            }
+        }
+    }
+
+    /// Gets the current state of the program with the given ID (0 or 1).
+    pub fn machine(&self, id: usize) -> MachineView {
+        let machine = self.duet.machine(id);
+        let state = machine.snapshot();
+        let status = if state.halted {
+            MachineStatus::Halted
+        } else if state.awaiting_input {
+            MachineStatus::AwaitingInput
+        } else {
+            MachineStatus::Running
+        };
+        MachineView {
+            id,
+            status,
+            pc: state.pc,
+            opcode: self.program.get(state.pc).map(Instruction::opcode),
+            registers: self
+                .registers
+                .iter()
+                .map(|&reg| (reg, machine.read_register_wide(&reg).unwrap()))
+                .collect(),
+            received_queue_len: state.sounds_received.len(),
+            max_received_queue_len: state.max_received_queue_len,
+            total_sounds_sent: state.total_sounds_sent,
+            total_sounds_received: state.total_sounds_received,
+        }
+    }
+}
+
+/// Gets the registers referenced by the instructions, along with the program ID register "p", in
+/// register order.
+fn referenced_registers(instructions: &[Instruction]) -> Vec<char> {
+    let mut registers = vec!['p'];
+    for instruction in instructions {
+        let (reg, args) = match *instruction {
+            Instruction::Snd { arg } => (None, [Some(arg), None]),
+            Instruction::Rcv { reg } => (Some(reg), [None, None]),
+            Instruction::Set { reg, arg }
+            | Instruction::Add { reg, arg }
+            | Instruction::Mul { reg, arg }
+            | Instruction::Mod { reg, arg }
+            | Instruction::Sub { reg, arg } => (Some(reg), [Some(arg), None]),
+            Instruction::Jgz { arg1, arg2 } | Instruction::Jnz { arg1, arg2 } => {
+                (None, [Some(arg1), Some(arg2)])
+            }
+        };
+        let arg_regs = args.into_iter().flatten().filter_map(|arg| match arg {
+            InstructionArgument::Register { reg } => Some(reg),
+            InstructionArgument::Value { .. } => None,
+        });
+        registers.extend(reg.into_iter().chain(arg_regs));
+    }
+    registers.sort_unstable();
+    registers.dedup();
+    registers
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Example program given in the AOC 2017 Day 18 problem statement for duet mode.
+    const EXAMPLE_PROGRAM: &str = "snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d";
+
+    /// Tests that the session only advances on ticks while running, and that single steps can be
+    /// taken while paused.
+    #[test]
+    fn test_duet_session_controls() {
+        let program = Instruction::parse_program(EXAMPLE_PROGRAM).unwrap();
+        let mut session = DuetSession::new(&program).unwrap();
+        assert!(session.is_paused());
+        session.tick(10);
+        assert_eq!(0, session.steps());
+        assert!(session.step());
+        assert_eq!(1, session.steps());
+        let machine = session.machine(1);
+        assert_eq!(MachineStatus::Running, machine.status);
+        assert_eq!((1, Some("snd")), (machine.pc, machine.opcode));
+        assert_eq!(1, machine.received_queue_len);
+        session.toggle_pause();
+        session.tick(100);
+        assert_eq!(7, session.steps());
+        assert_eq!(
+            [3, 3],
+            session.outcome().unwrap().unwrap().total_sounds_sent
+        );
+        assert!(!session.step());
+        let machine = session.machine(0);
+        assert_eq!(MachineStatus::AwaitingInput, machine.status);
+        assert_eq!(
+            vec![('a', 1), ('b', 2), ('c', 1), ('d', 0), ('p', 0)],
+            machine.registers
+        );
+    }
+}
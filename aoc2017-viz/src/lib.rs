@@ -0,0 +1,4 @@
+pub mod duet;
+
+#[cfg(feature = "tui")]
+pub mod dashboard;
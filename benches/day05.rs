@@ -0,0 +1,21 @@
+use aoc2017_core::days::day05::Day05;
+use aoc2017_core::scaffold::Solver;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks solving AOC 2017 Day 05 Part 2 (around 25 million jumps) with the branchless hot loop
+/// against the original branching loop.
+fn bench_jumpspace(c: &mut Criterion) {
+    let input = Day05::process_input_file(Day05::INPUT_FILE);
+    for algorithm in Day05::ALGORITHMS {
+        c.bench_function(&format!("day05_part2_{algorithm}"), |b| {
+            b.iter(|| Day05::solve_part2_with(&input, algorithm))
+        });
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_jumpspace
+}
+criterion_main!(benches);
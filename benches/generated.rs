@@ -0,0 +1,29 @@
+use aoc2017_core::days::day05::Day05;
+use aoc2017_core::scaffold::Solver;
+use aoc2017_core::utils::testgen::jump_offsets;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Benchmarks solving AOC 2017 Day 05 Part 2 with the branchless hot loop against the original
+/// branching loop, on generated jump spaces of increasing size. Requires the "testgen" feature.
+fn bench_jumpspace_generated(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day05_part2_generated");
+    for size in [100, 1_000, 10_000] {
+        let input = jump_offsets(42, size)
+            .lines()
+            .map(|line| line.parse::<i32>().unwrap())
+            .collect::<Box<[i32]>>();
+        for algorithm in Day05::ALGORITHMS {
+            group.bench_with_input(BenchmarkId::new(*algorithm, size), &input, |b, input| {
+                b.iter(|| Day05::solve_part2_with(input, algorithm))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_jumpspace_generated
+}
+criterion_main!(benches);
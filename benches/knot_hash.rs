@@ -0,0 +1,59 @@
+use aoc2017_core::utils::knot_hash::{calculate_knot_hash, knot_hash_bytes};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmarks the knot hash calculation for a single input string, and for the 128 row strings used
+/// to generate the AOC 2017 Day 14 disk grid.
+fn bench_knot_hash(c: &mut Criterion) {
+    c.bench_function("knot_hash_single", |b| {
+        b.iter(|| calculate_knot_hash(black_box("flqrgnkx-0")))
+    });
+    c.bench_function("knot_hash_disk_grid", |b| {
+        b.iter(|| {
+            (0..128)
+                .map(|row| calculate_knot_hash(black_box(&format!("flqrgnkx-{row}"))))
+                .collect::<Vec<String>>()
+        })
+    });
+    // Long input (3KB) where the 64 rounds of the sparse hash dominate the input and output processing
+    let long_input = (0..3072)
+        .map(|i| char::from(b' ' + (i * 7 % 95) as u8))
+        .collect::<String>();
+    c.bench_function("knot_hash_3kb", |b| {
+        b.iter(|| calculate_knot_hash(black_box(&long_input)))
+    });
+}
+
+/// Benchmarks counting the used squares of the AOC 2017 Day 14 disk grid by converting each row
+/// hash to a binary string and counting its '1' characters, against counting the set bits of the
+/// row hash bytes directly.
+fn bench_used_squares(c: &mut Criterion) {
+    let rows = (0..128)
+        .map(|row| format!("flqrgnkx-{row}"))
+        .collect::<Vec<String>>();
+    c.bench_function("used_squares_binary_string", |b| {
+        b.iter(|| {
+            rows.iter()
+                .map(|row| {
+                    calculate_knot_hash(black_box(row))
+                        .chars()
+                        .map(|c| format!("{:04b}", c.to_digit(16).unwrap()))
+                        .collect::<String>()
+                        .chars()
+                        .filter(|c| *c == '1')
+                        .count()
+                })
+                .sum::<usize>()
+        })
+    });
+    c.bench_function("used_squares_count_ones", |b| {
+        b.iter(|| {
+            rows.iter()
+                .flat_map(|row| knot_hash_bytes(black_box(row)))
+                .map(|byte| byte.count_ones() as usize)
+                .sum::<usize>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_knot_hash, bench_used_squares);
+criterion_main!(benches);
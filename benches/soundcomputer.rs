@@ -0,0 +1,33 @@
+use aoc2017_core::utils::machines::soundcomputer::{Instruction, IsaProfile, SoundComputer};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Day 23 program with register "b" seeded with 81, so that the prime-checking loop executes
+/// several hundred thousand instructions without the optimiser.
+const DAY23_PROGRAM: &str = "set b 81\nset c b\njnz a 2\njnz 1 5\nmul b 100\nsub b -100000\n\
+    set c b\nsub c -17000\nset f 1\nset d 2\nset e 2\nset g d\nmul g e\nsub g b\njnz g 2\n\
+    set f 0\nsub e -1\nset g e\nsub g b\njnz g -8\nsub d -1\nset g d\nsub g b\njnz g -13\n\
+    jnz f 2\nsub h -1\nset g b\nsub g c\njnz g 2\njnz 1 3\nsub b -17\njnz 1 -23";
+
+/// Benchmarks the execution of the Day 23 program by the interpreter and by compiled code.
+fn bench_sound_computer(c: &mut Criterion) {
+    let program = Instruction::parse_program(DAY23_PROGRAM).unwrap();
+    for compile in [false, true] {
+        let name = match compile {
+            true => "sound_computer_day23_compiled",
+            false => "sound_computer_day23_interpreted",
+        };
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                let mut comp = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+                if compile {
+                    comp.compile();
+                }
+                comp.execute().unwrap();
+                comp.get_mul_executions_count()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_sound_computer);
+criterion_main!(benches);
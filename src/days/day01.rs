@@ -0,0 +1,121 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::AocError;
+use crate::utils::parse::parse_digits;
+
+const PROBLEM_NAME: &str = "Inverse Captcha";
+const PROBLEM_INPUT_FILE: &str = "./input/day01.txt";
+const PROBLEM_DAY: u64 = 1;
+
+/// Solver for AOC 2017 Day 01.
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Vec<u32>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 01 input file in the format required by the solver functions.
+///
+/// Returned value is vector of digits given in the input file.
+fn process_input_file(filename: &str) -> Vec<u32> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_file_contents(&raw_input).unwrap()
+}
+
+/// Parses the content of the input file to generate the data structure needed as input to the
+/// problem solver functions.
+///
+/// Returns an [`AocError`] giving the line and column of the first malformed value.
+fn parse_input_file_contents(s: &str) -> Result<Vec<u32>, AocError> {
+    parse_digits(s)
+}
+
+/// Solves AOC 2017 Day 01 Part 1.
+///
+/// Determines the sum of all digits that match the next digit in the sequence (circular).
+fn solve_part1(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| **c == digits[(i + 1) % digits.len()])
+        .map(|(_i, c)| c)
+        .sum()
+}
+
+/// Solves AOC 2017 Day 01 Part 2.
+///
+/// Determines the sum of all digits that match the digit half-way around the sequence (circular).
+fn solve_part2(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| **c == digits[(i + digits.len() / 2) % digits.len()])
+        .map(|(_i, c)| c)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 01 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(1150, solution);
+    }
+
+    /// Tests the Day 01 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day01_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(1064, solution);
+    }
+
+    /// Tests that malformed inputs are rejected with the line and column of the first bad digit.
+    #[test]
+    fn test_day01_parse_malformed() {
+        assert_eq!(Ok(vec![1, 1, 2, 2]), parse_input_file_contents("1122\n"));
+        let error = parse_input_file_contents("\n12a4").unwrap_err();
+        assert_eq!(
+            "Parsing of input failed at line 2, column 3 [a]",
+            error.to_string()
+        );
+        let error = parse_input_file_contents("91 2").unwrap_err();
+        assert_eq!(
+            "Parsing of input failed at line 1, column 3 [ ]",
+            error.to_string()
+        );
+    }
+
+    /// Tests the Day 01 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day01_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(3, solve_part1(&input));
+        let input = process_input_file(&example_path(PROBLEM_DAY, 2));
+        assert_eq!(6, solve_part2(&input));
+    }
+}
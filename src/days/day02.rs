@@ -0,0 +1,130 @@
+use std::fs;
+
+use itertools::iproduct;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::AocError;
+use crate::utils::parse::parse_rows;
+
+const PROBLEM_NAME: &str = "Corruption Checksum";
+const PROBLEM_INPUT_FILE: &str = "./input/day02.txt";
+const PROBLEM_DAY: u64 = 2;
+
+/// Solver for AOC 2017 Day 02.
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = Vec<Vec<u64>>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 02 input file in the format required by the solver functions.
+///
+/// Returned value is vector of vectors containing values given in the lines of the input file.
+fn process_input_file(filename: &str) -> Vec<Vec<u64>> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_file_contents(&raw_input).unwrap()
+}
+
+/// Parses the content of the input file to generate the data structure needed as input to the
+/// problem solver functions.
+///
+/// Returns an [`AocError`] giving the line and column of the first malformed value.
+fn parse_input_file_contents(s: &str) -> Result<Vec<Vec<u64>>, AocError> {
+    parse_rows::<u64>(s)
+}
+
+/// Solves AOC 2017 Day 02 Part 1.
+///
+/// Determines the checksum of the sheet by determining the sum of the difference between the
+/// minimum and maximum values for each row.
+fn solve_part1(sheet: &[Vec<u64>]) -> u64 {
+    sheet
+        .iter()
+        .map(|row| row.iter().max().unwrap() - row.iter().min().unwrap())
+        .sum()
+}
+
+/// Solves AOC 2017 Day 02 Part 2.
+///
+/// Determines the checksum of the sheet by finding the sum of the division result of the two values
+/// from each row that are evenly divisible.
+fn solve_part2(sheet: &[Vec<u64>]) -> u64 {
+    sheet
+        .iter()
+        .map(|row| {
+            iproduct!(row.iter().enumerate(), row.iter().enumerate())
+                .filter(|((i, &val_a), (j, &val_b))| i != j && val_a % val_b == 0)
+                .map(|((_, val_a), (_, val_b))| val_a / val_b)
+                .next()
+                .unwrap()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 02 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(45158, solution);
+    }
+
+    /// Tests the Day 02 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day02_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(294, solution);
+    }
+
+    /// Tests that malformed inputs are rejected with the line and column of the first bad value.
+    #[test]
+    fn test_day02_parse_malformed() {
+        assert_eq!(
+            Ok(vec![vec![5, 1, 9, 5], vec![7, 5, 3]]),
+            parse_input_file_contents("5 1 9 5\n7\t5 3\n")
+        );
+        let error = parse_input_file_contents("5 1 9 5\n7 -5 3\n").unwrap_err();
+        assert_eq!(
+            "Parsing of input failed at line 2, column 3 [-5]",
+            error.to_string()
+        );
+        let error = parse_input_file_contents("2 4 6x 8").unwrap_err();
+        assert_eq!(
+            "Parsing of input failed at line 1, column 5 [6x]",
+            error.to_string()
+        );
+    }
+
+    /// Tests the Day 02 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day02_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(18, solve_part1(&input));
+        let input = process_input_file(&example_path(PROBLEM_DAY, 2));
+        assert_eq!(9, solve_part2(&input));
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::cartography::Point2D;
+use crate::utils::spiral::{first_spiral_sum_exceeding, spiral_coordinates};
+
+const PROBLEM_NAME: &str = "Spiral Memory";
+const PROBLEM_INPUT_FILE: &str = "./input/day03.txt";
+const PROBLEM_DAY: u64 = 3;
+
+/// Solver for AOC 2017 Day 03.
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = u64;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["simulated", "closed-form"];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_part1_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "closed-form" => Some(solve_part1_closed_form(input).into()),
+            _ => Self::solve_part1(input),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 03 input file in the format required by the solver functions.
+///
+/// Returned value is value given in the input file.
+fn process_input_file(filename: &str) -> u64 {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    raw_input.trim().parse::<u64>().unwrap()
+}
+
+/// Solves AOC 2017 Day 03 Part 1.
+///
+/// Determines the number of steps needed to carry the data from the target square to the access
+/// port in the centre of the simple spiral.
+fn solve_part1(target: &u64) -> u64 {
+    let (_value, loc) = generate_simple_spiral(*target);
+    loc.manhattan(&Point2D::new(0, 0))
+}
+
+/// Solves AOC 2017 Day 03 Part 1 directly from the location of the target square (see
+/// [`spiral_coordinates`]), without generating the spiral.
+fn solve_part1_closed_form(target: &u64) -> u64 {
+    spiral_coordinates(*target).manhattan(&Point2D::new(0, 0))
+}
+
+/// Solves AOC 2017 Day 03 Part 2.
+///
+/// Determines the first value over the target value that is generated in the complex spiral.
+fn solve_part2(target: &u64) -> u64 {
+    first_spiral_sum_exceeding(*target)
+}
+
+/// Generates a simple spiral and returns the first value over the given target value and its
+/// location.
+fn generate_simple_spiral(target: u64) -> (u64, Point2D) {
+    let mut value = 1;
+    let mut loc = Point2D::new(0, 0);
+    let mut ring = 0;
+    let mut delta = (1, 0);
+    while value < target {
+        if value == u64::pow(2 * ring + 1, 2) {
+            // bottom right
+            proceed_to_next_ring_simple_spiral(&mut ring, &mut loc, &mut delta, &mut value);
+            continue;
+        } else if value == u64::pow(2 * ring + 1, 2) - ring * 2 {
+            // bottom left
+            delta = (1, 0);
+        } else if value == u64::pow(2 * ring + 1, 2) - ring * 4 {
+            // top left
+            delta = (0, 1);
+        } else if value == u64::pow(2 * ring + 1, 2) - ring * 6 {
+            // top right
+            delta = (-1, 0);
+        }
+        loc.shift(delta.0, delta.1);
+        value += 1;
+    }
+    (value, loc)
+}
+
+/// Updates the spiral parameters to proceed to the next ring going outwards.
+fn proceed_to_next_ring_simple_spiral(
+    ring: &mut u64,
+    loc: &mut Point2D,
+    delta: &mut (i64, i64),
+    value: &mut u64,
+) {
+    *ring += 1;
+    loc.shift(delta.0, delta.1);
+    *delta = (0, -1);
+    *value += 1;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 03 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day03_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(480, solution);
+    }
+
+    /// Tests that the "simulated" and "closed-form" algorithms for Day 03 Part 1 agree, using the
+    /// examples given in the problem statement and the first few rings of the spiral.
+    #[test]
+    fn test_day03_part1_algorithms_agree() {
+        for (target, steps) in [(1, 0), (12, 3), (23, 2), (1024, 31)] {
+            assert_eq!(steps, solve_part1_closed_form(&target));
+        }
+        for target in 1..=200 {
+            assert_eq!(solve_part1(&target), solve_part1_closed_form(&target));
+        }
+    }
+
+    /// Tests the Day 03 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day03_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(349975, solution);
+    }
+
+    /// Tests the Day 03 Part 1 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day03_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(31, solve_part1(&input));
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::Hash;
+
+use itertools::Itertools;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::ParseError;
+use crate::utils::parse::parse_lines_with;
+
+const PROBLEM_NAME: &str = "High-Entropy Passphrases";
+const PROBLEM_INPUT_FILE: &str = "./input/day04.txt";
+const PROBLEM_DAY: u64 = 4;
+
+/// Solver for AOC 2017 Day 04.
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<Vec<String>>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 04 input file in the format required by the solver functions.
+///
+/// Returned value is vector of containing vector of words separated by whitespace in the input file
+/// lines.
+fn process_input_file(filename: &str) -> Vec<Vec<String>> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_lines_with(&raw_input, parse_passphrase).unwrap()
+}
+
+/// Parses a passphrase into its words, which are separated by ASCII whitespace.
+///
+/// Returns a [`ParseError`] if any word contains a character that is not a printable ASCII
+/// character, since the words are compared byte by byte.
+fn parse_passphrase(line: &str) -> Result<Vec<String>, ParseError> {
+    line.split_ascii_whitespace()
+        .map(|word| match word.chars().find(|c| !c.is_ascii_graphic()) {
+            Some(c) => Err(ParseError {
+                message: format!("invalid character '{c}' in word \"{word}\""),
+            }),
+            None => Ok(word.to_string()),
+        })
+        .collect()
+}
+
+/// Result of checking a passphrase against both of the validity policies. Offending word pairs are
+/// given as the indices of the two words, with the pair found first when reading the passphrase
+/// from left to right (i.e. the pair with the lowest index of its second word).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PassphraseReport {
+    /// Pair of identical words, if any.
+    pub duplicate: Option<(usize, usize)>,
+    /// Pair of words that are anagrams of each other (including identical words), if any.
+    pub anagram: Option<(usize, usize)>,
+}
+
+impl PassphraseReport {
+    /// Checks if the passphrase is valid under the policy that no word may appear twice.
+    pub fn is_valid_no_duplicates(&self) -> bool {
+        self.duplicate.is_none()
+    }
+
+    /// Checks if the passphrase is valid under the policy that no two words may be anagrams of
+    /// each other.
+    pub fn is_valid_no_anagrams(&self) -> bool {
+        self.anagram.is_none()
+    }
+}
+
+/// Checks the words of the passphrase against both of the validity policies.
+pub fn check_passphrase(words: &[String]) -> PassphraseReport {
+    PassphraseReport {
+        duplicate: find_pair(words, |word| word.to_string()),
+        anagram: find_pair(words, |word| word.bytes().sorted().collect::<Vec<u8>>()),
+    }
+}
+
+/// Finds the first pair of words sharing the same key, returning their indices.
+fn find_pair<K: Eq + Hash>(words: &[String], key: impl Fn(&str) -> K) -> Option<(usize, usize)> {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    for (j, word) in words.iter().enumerate() {
+        if let Some(&i) = seen.get(&key(word)) {
+            return Some((i, j));
+        }
+        seen.insert(key(word), j);
+    }
+    None
+}
+
+/// Solves AOC 2017 Day 04 Part 1.
+///
+/// Counts the number of passphrases that do not no contain any duplicate words.
+fn solve_part1(passphrases: &[Vec<String>]) -> usize {
+    passphrases
+        .iter()
+        .filter(|pass| check_passphrase(pass).is_valid_no_duplicates())
+        .count()
+}
+
+/// Solves AOC 2017 Day 04 Part 2.
+///
+/// Counts the number of passphrases that do not contain two strings which are anagrams of each
+/// other.
+fn solve_part2(passphrases: &[Vec<String>]) -> usize {
+    passphrases
+        .iter()
+        .filter(|pass| check_passphrase(pass).is_valid_no_anagrams())
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+    use crate::utils::testgen::passphrases;
+
+    /// Tests that passphrases containing non-ASCII characters are rejected.
+    #[test]
+    fn test_day04_parse_passphrase() {
+        assert_eq!(
+            vec!["ab", "ba", "c"],
+            parse_passphrase("ab  ba\tc").unwrap()
+        );
+        let error = parse_passphrase("ab é").unwrap_err();
+        assert_eq!("invalid character 'é' in word \"é\"", error.message);
+        assert!(parse_passphrase("ab\u{a0}cd").is_err());
+    }
+
+    /// Tests the passphrase checks against the examples given in the problem statement, including
+    /// the offending word pairs.
+    #[test]
+    fn test_day04_check_passphrase() {
+        let cases = [
+            ("aa bb cc dd ee", None, None),
+            ("aa bb cc dd aa", Some((0, 4)), Some((0, 4))),
+            ("aa bb cc dd aaa", None, None),
+            ("abcde fghij", None, None),
+            ("abcde xyz ecdab", None, Some((0, 2))),
+            ("a ab abc abd abf abj", None, None),
+            ("iiii oiii ooii oooi oooo", None, None),
+            ("oiii ioii iioi iiio", None, Some((0, 1))),
+            ("xy yx ab ab", Some((2, 3)), Some((0, 1))),
+        ];
+        for (passphrase, duplicate, anagram) in cases {
+            let words = parse_passphrase(passphrase).unwrap();
+            let report = check_passphrase(&words);
+            assert_eq!(PassphraseReport { duplicate, anagram }, report);
+            assert_eq!(duplicate.is_none(), report.is_valid_no_duplicates());
+        }
+    }
+
+    /// Tests the passphrase checks against a comparison of every pair of words, using randomly
+    /// generated passphrases.
+    #[test]
+    fn test_day04_check_passphrase_generated() {
+        let raw_input = passphrases(7, 500);
+        let input = raw_input
+            .lines()
+            .map(|line| parse_passphrase(line).unwrap())
+            .collect::<Vec<Vec<String>>>();
+        for words in &input {
+            let report = check_passphrase(words);
+            let any_pair = |same: &dyn Fn(&String, &String) -> bool| {
+                words.iter().tuple_combinations().any(|(a, b)| same(a, b))
+            };
+            let is_anagram = |a: &String, b: &String| a.bytes().sorted().eq(b.bytes().sorted());
+            assert_eq!(any_pair(&|a, b| a == b), !report.is_valid_no_duplicates());
+            assert_eq!(any_pair(&is_anagram), !report.is_valid_no_anagrams());
+        }
+        // Both policies reject some of the passphrases, with the anagram policy rejecting more
+        assert!(solve_part1(&input) < input.len());
+        assert!(solve_part2(&input) < solve_part1(&input));
+    }
+
+    /// Tests the Day 04 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day04_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(386, solution);
+    }
+
+    /// Tests the Day 04 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day04_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(208, solution);
+    }
+
+    /// Tests the Day 04 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day04_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(2, solve_part1(&input));
+        let input = process_input_file(&example_path(PROBLEM_DAY, 2));
+        assert_eq!(3, solve_part2(&input));
+    }
+}
@@ -0,0 +1,207 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::parse::parse_lines;
+
+const PROBLEM_NAME: &str = "A Maze of Twisty Trampolines, All Alike";
+const PROBLEM_INPUT_FILE: &str = "./input/day05.txt";
+const PROBLEM_DAY: u64 = 5;
+
+/// Solver for AOC 2017 Day 05.
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Box<[i32]>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["branchless", "branching"];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_part1_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "branching" => Some(calculate_steps_to_exit_jumpspace_branching(input, false).into()),
+            _ => Self::solve_part1(input),
+        }
+    }
+
+    fn solve_part2_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "branching" => Some(calculate_steps_to_exit_jumpspace_branching(input, true).into()),
+            _ => Self::solve_part2(input),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 05 input file in the format required by the solver functions.
+///
+/// Returned value is boxed slice of integer values given in the lines of the input file.
+fn process_input_file(filename: &str) -> Box<[i32]> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_lines::<i32>(&raw_input).unwrap().into_boxed_slice()
+}
+
+/// Solves AOC 2017 Day 05 Part 1.
+///
+/// Determines the number of steps needed for the cursor to exit the jump space.
+fn solve_part1(jumps: &[i32]) -> u64 {
+    calculate_steps_to_exit_jumpspace(jumps, false)
+}
+
+/// Solves AOC 2017 Day 05 Part 2.
+///
+/// Determines the number of steps needed for the cursor to exit the jump space, using strange
+/// jumps.
+fn solve_part2(jumps: &[i32]) -> u64 {
+    calculate_steps_to_exit_jumpspace(jumps, true)
+}
+
+/// Calculates the number of steps needed for the cursor to exit the jump space.
+///
+/// If strange jumps are used, the location value that is being jumped from by the cursor is
+/// decreased by 1 if the offset was 3 or more; otherwise (or if not using strange jumps), the
+/// location value is increased by 1.
+///
+/// The hot loop has no data-dependent branches: the update is calculated arithmetically from the
+/// comparison against the threshold, and the cursor wraps around to a huge value if it jumps off
+/// the start of the jump space, so that the single bounds check of [`slice::get_mut`] also detects
+/// the cursor exiting from either end.
+fn calculate_steps_to_exit_jumpspace(jumps: &[i32], strange_jumps: bool) -> u64 {
+    let mut jumps: Box<[i32]> = jumps.into();
+    // Offsets at or above the threshold are decreased instead of increased
+    let threshold = if strange_jumps { 3 } else { i32::MAX };
+    let mut cursor: usize = 0;
+    let mut steps = 0;
+    while let Some(offset) = jumps.get_mut(cursor) {
+        let delta = *offset;
+        *offset += 1 - 2 * i32::from(delta >= threshold);
+        cursor = cursor.wrapping_add_signed(delta as isize);
+        steps += 1;
+    }
+    steps
+}
+
+/// Calculates the number of steps needed for the cursor to exit the jump space, branching on the
+/// jump rules and the direction of each jump (see [`calculate_steps_to_exit_jumpspace`]).
+fn calculate_steps_to_exit_jumpspace_branching(jumps: &[i32], strange_jumps: bool) -> u64 {
+    // Check if the jump space is empty
+    if jumps.is_empty() {
+        return 0;
+    }
+    // Initialise
+    let mut jumps = jumps
+        .iter()
+        .map(|&jump| jump as isize)
+        .collect::<Vec<isize>>();
+    let mut cursor = 0;
+    let mut steps = 0;
+    loop {
+        let delta = jumps[cursor];
+        // Update the location value being jumped from by the cursor
+        jumps[cursor] += {
+            if delta >= 3 && strange_jumps {
+                -1
+            } else {
+                1
+            }
+        };
+        steps += 1;
+        // Check if jump takes cursor outside of jump space, and update cursor location
+        match delta.is_positive() {
+            true => {
+                if delta.unsigned_abs() + cursor >= jumps.len() {
+                    break;
+                }
+                cursor += delta.unsigned_abs();
+            }
+            false => {
+                if delta.unsigned_abs() > cursor {
+                    break;
+                }
+                cursor -= delta.unsigned_abs();
+            }
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+    use crate::utils::testgen::jump_offsets;
+
+    /// Tests the Day 05 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(358131, solution);
+    }
+
+    /// Tests the Day 05 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day05_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(25558839, solution);
+    }
+
+    /// Tests that the "branchless" and "branching" algorithms agree, using the example given in the
+    /// problem statement and jumps off either end of the jump space.
+    #[test]
+    fn test_day05_algorithms_agree() {
+        let jump_spaces: [&[i32]; 5] = [&[0, 3, 0, 1, -3], &[], &[-1], &[1], &[2, -3, 0, -1, 4]];
+        for (strange_jumps, expected) in [(false, 5), (true, 10)] {
+            assert_eq!(
+                expected,
+                calculate_steps_to_exit_jumpspace(jump_spaces[0], strange_jumps)
+            );
+        }
+        for jumps in jump_spaces {
+            for strange_jumps in [false, true] {
+                assert_eq!(
+                    calculate_steps_to_exit_jumpspace_branching(jumps, strange_jumps),
+                    calculate_steps_to_exit_jumpspace(jumps, strange_jumps)
+                );
+            }
+        }
+    }
+
+    /// Tests that the "branchless" and "branching" algorithms agree on randomly generated jump
+    /// spaces.
+    #[test]
+    fn test_day05_algorithms_agree_generated() {
+        for seed in 0..20 {
+            let jumps = parse_lines::<i32>(&jump_offsets(seed, 500)).unwrap();
+            for strange_jumps in [false, true] {
+                assert_eq!(
+                    calculate_steps_to_exit_jumpspace_branching(&jumps, strange_jumps),
+                    calculate_steps_to_exit_jumpspace(&jumps, strange_jumps)
+                );
+            }
+        }
+    }
+
+    /// Tests the Day 05 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day05_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(5, solve_part1(&input));
+        assert_eq!(10, solve_part2(&input));
+    }
+}
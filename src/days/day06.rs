@@ -0,0 +1,270 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+
+use crate::scaffold::{Answer, Cache, Solver};
+use crate::utils::parse::parse_split;
+
+const PROBLEM_NAME: &str = "Memory Reallocation";
+const PROBLEM_INPUT_FILE: &str = "./input/day06.txt";
+const PROBLEM_DAY: u64 = 6;
+
+/// Custom error type indicating that redistribution was attempted on an empty group of membanks.
+#[derive(Debug)]
+pub struct EmptyBanksError;
+
+/// Result of the search for the first repeated arrangement of the banks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RepeatedArrangement {
+    /// Number of redistribution cycles completed before the repeated arrangement was produced.
+    pub steps: u64,
+    /// Length of the cycle between the repeated arrangements in steps.
+    pub cycle_steps: u64,
+    /// Arrangement of the banks at which the cycle starts.
+    pub arrangement: Vec<u64>,
+}
+
+impl RepeatedArrangement {
+    /// Returns an iterator over the arrangements of the banks in the cycle, starting with the
+    /// repeated arrangement and ending with the arrangement produced just before it repeats.
+    pub fn cycle_arrangements(&self) -> impl Iterator<Item = Vec<u64>> + '_ {
+        std::iter::once(self.arrangement.clone())
+            .chain(Redistributions::new(&self.arrangement).take(self.cycle_steps as usize - 1))
+    }
+}
+
+/// Iterator over the arrangements of the banks produced by successive redistribution cycles,
+/// starting from (but not including) the initial arrangement. No arrangements are produced if
+/// there are no banks.
+pub struct Redistributions {
+    banks: Vec<u64>,
+}
+
+impl Redistributions {
+    /// Creates a new [`Redistributions`] iterator starting from the given arrangement.
+    pub fn new(banks: &[u64]) -> Redistributions {
+        Redistributions {
+            banks: banks.to_vec(),
+        }
+    }
+}
+
+impl Iterator for Redistributions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        conduct_redistribution_cycle(&mut self.banks).ok()?;
+        Some(self.banks.clone())
+    }
+}
+
+/// Custom type representing the input to the problem solver functions. The repeated arrangement
+/// search is shared by both parts, so its result is cached after it is first conducted.
+pub struct ProblemInput {
+    banks: Vec<u64>,
+    repeated_arrangement: Cache<Result<RepeatedArrangement, EmptyBanksError>>,
+}
+
+impl ProblemInput {
+    /// Returns the result of the repeated banks arrangement search, conducting the search if it
+    /// has not already been done.
+    pub fn repeated_arrangement(&self) -> &Result<RepeatedArrangement, EmptyBanksError> {
+        self.repeated_arrangement
+            .get_or_compute(|| find_repeated_banks_arrangement_steps(&self.banks))
+    }
+}
+
+/// Solver for AOC 2017 Day 06.
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_both(input: &Self::Input) -> Option<(Answer, Answer)> {
+        // Both parts are answered by a single search for the repeated arrangement
+        Some((solve_part1(input).into(), solve_part2(input).into()))
+    }
+}
+
+/// Processes the AOC 2017 Day 06 input file in the format required by the solver functions.
+///
+/// Returned value is [`ProblemInput`] holding the vector of values given as whitespace-separated
+/// values in the input file.
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    let banks = parse_split::<u64>(&raw_input, " ").unwrap();
+    ProblemInput {
+        banks,
+        repeated_arrangement: Cache::new(),
+    }
+}
+
+/// Solves AOC 2017 Day 06 Part 1.
+///
+/// Determines how many redistribution cycles must be completed before a configuration is produced
+/// that has already been observed.
+fn solve_part1(input: &ProblemInput) -> u64 {
+    match input.repeated_arrangement() {
+        Ok(repeated) => repeated.steps,
+        Err(EmptyBanksError) => panic!("The banks are empty and cannot be redistributed!"),
+    }
+}
+
+/// Solves AOC 2017 Day 06 Part 2.
+///
+/// Determines the length of the cycle in steps between the repeated arrangments of the banks. A
+/// single redistribution cycle is conducted on each step.
+fn solve_part2(input: &ProblemInput) -> u64 {
+    match input.repeated_arrangement() {
+        Ok(repeated) => repeated.cycle_steps,
+        Err(EmptyBanksError) => panic!("The banks are empty and cannot be redistributed!"),
+    }
+}
+
+/// Finds the total number of steps needed to reach a repeated banks arrangement after conducting
+/// redistribution cycles, as well as the length of the cycle between repeated arrangements in
+/// steps and the repeated arrangement itself.
+///
+/// Returns [`EmptyBanksError`] if the input collection is empty.
+fn find_repeated_banks_arrangement_steps(
+    banks: &[u64],
+) -> Result<RepeatedArrangement, EmptyBanksError> {
+    if banks.is_empty() {
+        return Err(EmptyBanksError);
+    }
+    let mut banks = banks.to_vec();
+    let mut steps = 0;
+    let mut observed: HashMap<u64, u64> = HashMap::from([(hash_banks(&banks), steps)]);
+    loop {
+        steps += 1;
+        conduct_redistribution_cycle(&mut banks)?;
+        // Record banks hash and check if it has already been observed
+        if let Some(last_steps) = observed.insert(hash_banks(&banks), steps) {
+            return Ok(RepeatedArrangement {
+                steps,
+                cycle_steps: steps - last_steps,
+                arrangement: banks,
+            });
+        }
+    }
+}
+
+/// Conduct a single redistribution cycle of blocks between the banks.
+///
+/// Returns [`EmptyBanksError`] if the given collection is empty.
+fn conduct_redistribution_cycle(banks: &mut [u64]) -> Result<(), EmptyBanksError> {
+    let mut i = find_index_of_largest_bank(banks)?;
+    let mut blocks = banks[i];
+    banks[i] = 0;
+    while blocks > 0 {
+        i = (i + 1) % banks.len();
+        banks[i] += 1;
+        blocks -= 1;
+    }
+    Ok(())
+}
+
+/// Finds the index of the bank with the largest number of blocks. Ties are broken by selecting the
+/// bank with the lower-numbered index.
+///
+/// Returns [`EmptyBanksError`] if the given collection is empty.
+fn find_index_of_largest_bank(banks: &[u64]) -> Result<usize, EmptyBanksError> {
+    if banks.is_empty() {
+        return Err(EmptyBanksError);
+    }
+    let mut i: Option<usize> = None;
+    let mut max_value: Option<u64> = None;
+    for (j, value) in banks.iter().enumerate() {
+        if max_value.is_none() || max_value.unwrap() < *value {
+            i = Some(j);
+            max_value = Some(*value);
+        }
+    }
+    Ok(i.unwrap())
+}
+
+/// Calculates the hash of the banks collection.
+fn hash_banks(banks: &[u64]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    banks.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 06 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day06_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(7864, solution);
+    }
+
+    /// Tests the repeated arrangement and the arrangements in its cycle against the example given
+    /// in the problem statement.
+    #[test]
+    fn test_day06_repeated_arrangement_example() {
+        let repeated = find_repeated_banks_arrangement_steps(&[0, 2, 7, 0]).unwrap();
+        assert_eq!(5, repeated.steps);
+        assert_eq!(4, repeated.cycle_steps);
+        assert_eq!(vec![2, 4, 1, 2], repeated.arrangement);
+        let cycle = repeated.cycle_arrangements().collect::<Vec<Vec<u64>>>();
+        assert_eq!(
+            vec![
+                vec![2, 4, 1, 2],
+                vec![3, 1, 2, 3],
+                vec![0, 2, 3, 4],
+                vec![1, 3, 4, 1],
+            ],
+            cycle
+        );
+        assert_eq!(0, Redistributions::new(&[]).count());
+    }
+
+    /// Tests the Day 06 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day06_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(1695, solution);
+    }
+
+    /// Tests the Day 06 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day06_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(5, solve_part1(&input));
+        assert_eq!(4, solve_part2(&input));
+    }
+
+    /// Tests that solving both parts together gives the same solutions as the separate solvers.
+    #[test]
+    fn test_day06_solve_both() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let (p1_solution, p2_solution) = Day06::solve_both(&input).unwrap();
+        assert_eq!(Answer::from(5u64), p1_solution);
+        assert_eq!(Answer::from(4u64), p2_solution);
+    }
+}
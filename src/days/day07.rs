@@ -0,0 +1,210 @@
+use std::fs;
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
+use fancy_regex::Regex;
+
+use crate::scaffold::{Answer, ExportFormat, Solver};
+use crate::utils::intern::{Interner, Symbol};
+#[cfg(not(feature = "regex"))]
+use crate::utils::parse::{expect_end, expect_literal, split_tuple, take_integer};
+use crate::utils::tree::WeightedTree;
+
+const PROBLEM_NAME: &str = "Recursive Circus";
+const PROBLEM_INPUT_FILE: &str = "./input/day07.txt";
+const PROBLEM_DAY: u64 = 7;
+
+/// Solver for AOC 2017 Day 07.
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = WeightedTree;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn visualise(input: &Self::Input) -> Option<String> {
+        Some(input.render_tree())
+    }
+
+    fn export(input: &Self::Input, format: ExportFormat) -> Option<String> {
+        match format {
+            ExportFormat::Dot => Some(input.to_dot()),
+            ExportFormat::Json => Some(input.to_json()),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 07 input file in the format required by the solver functions.
+///
+/// Returned value is the program tower, as a tree of programs with the weight given for each in the
+/// input file. The programs are checked to form a single tree, with no duplicated programs,
+/// references to undefined programs or cycles.
+fn process_input_file(filename: &str) -> WeightedTree {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_file_contents(&raw_input)
+}
+
+/// Parses the content of the input file into the program tower, panicking if any line is badly
+/// formatted or the programs do not form a single tree.
+///
+/// Program names are interned as they are parsed, so each distinct name is only stored once.
+fn parse_input_file_contents(raw_input: &str) -> WeightedTree {
+    let mut interner = Interner::new();
+    let mut programs: Vec<(Symbol, u64, Vec<Symbol>)> = vec![];
+    for line in raw_input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+    {
+        match parse_input_file_line(line) {
+            Some((program, weight, children)) => programs.push((
+                interner.intern(program),
+                weight,
+                children.map(|name| interner.intern(name)).collect(),
+            )),
+            None => panic!("Bad format input line! // {line}"),
+        }
+    }
+    match WeightedTree::from_symbols(interner, &programs) {
+        Ok(tower) => tower,
+        Err(e) => panic!("Invalid program tower in input file! // {e}"),
+    }
+}
+
+/// Parses a line of the input file (e.g. "fwft (72) -> ktlj, cntj, xhth") into the name, weight and
+/// children of the program. Returns None if the line is badly formatted.
+#[cfg(feature = "regex")]
+fn parse_input_file_line(line: &str) -> Option<(&str, u64, impl Iterator<Item = &str>)> {
+    static LINE_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex =
+        LINE_REGEX.get_or_init(|| Regex::new(r"^([a-z]+) \((\d+)\)(?: -> (.+))?$").unwrap());
+    let caps = regex.captures(line).ok()??;
+    let program = caps.get(1).unwrap().as_str();
+    let weight = caps[2].parse::<u64>().ok()?;
+    let children = caps
+        .get(3)
+        .into_iter()
+        .flat_map(|children| children.as_str().trim().split(", "));
+    Some((program, weight, children))
+}
+
+/// Parses a line of the input file (e.g. "fwft (72) -> ktlj, cntj, xhth") into the name, weight and
+/// children of the program. Returns None if the line is badly formatted.
+#[cfg(not(feature = "regex"))]
+fn parse_input_file_line(line: &str) -> Option<(&str, u64, impl Iterator<Item = &str>)> {
+    let (program, rest) = split_tuple(line, " (").ok()?;
+    if program.is_empty() || !program.bytes().all(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    let (weight, rest) = take_integer::<u64>(rest).ok()?;
+    let rest = expect_literal(rest, ")").ok()?;
+    let children = match expect_end(rest) {
+        Ok(()) => None,
+        Err(_) => Some(expect_literal(rest, " -> ").ok()?.trim()),
+    };
+    let children = children
+        .into_iter()
+        .flat_map(|children| children.split(", "));
+    Some((program, weight, children))
+}
+
+/// Solves AOC 2017 Day 07 Part 1.
+///
+/// Determines the name of the program at the bottom of the tower.
+fn solve_part1(tower: &WeightedTree) -> String {
+    tower.name(tower.root()).to_string()
+}
+
+/// Solves AOC 2017 Day 07 Part 2.
+///
+/// Given that exactly one program is the wrong weight, finds the weight that it would need to be to
+/// balance the entire program tower.
+fn solve_part2(tower: &WeightedTree) -> u64 {
+    match tower.find_unbalanced() {
+        Some(unbalanced) => unbalanced.corrected_weight,
+        None => panic!("Failed to find corrected weight! Program tower is already balanced."),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::{example, example_path};
+
+    /// Tests the Day 07 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day07_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!("hlqnsbe", solution);
+    }
+
+    /// Tests the Day 07 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day07_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(1993, solution);
+    }
+
+    /// Snapshot tests of the DOT and JSON exports of the tower given in the problem statement
+    /// example, checking the parsed names, weights, children and subtree weights.
+    #[test]
+    fn test_day07_export_example() {
+        let tower = parse_input_file_contents(&example(PROBLEM_DAY, 1));
+        let expected_json = r#"{
+  "root": "tknk",
+  "nodes": [
+    {"name": "tknk", "weight": 41, "subtree_weight": 778, "children": ["ugml", "padx", "fwft"]},
+    {"name": "ugml", "weight": 68, "subtree_weight": 251, "children": ["gyxo", "ebii", "jptl"]},
+    {"name": "gyxo", "weight": 61, "subtree_weight": 61, "children": []},
+    {"name": "ebii", "weight": 61, "subtree_weight": 61, "children": []},
+    {"name": "jptl", "weight": 61, "subtree_weight": 61, "children": []},
+    {"name": "padx", "weight": 45, "subtree_weight": 243, "children": ["pbga", "havc", "qoyq"]},
+    {"name": "pbga", "weight": 66, "subtree_weight": 66, "children": []},
+    {"name": "havc", "weight": 66, "subtree_weight": 66, "children": []},
+    {"name": "qoyq", "weight": 66, "subtree_weight": 66, "children": []},
+    {"name": "fwft", "weight": 72, "subtree_weight": 243, "children": ["ktlj", "cntj", "xhth"]},
+    {"name": "ktlj", "weight": 57, "subtree_weight": 57, "children": []},
+    {"name": "cntj", "weight": 57, "subtree_weight": 57, "children": []},
+    {"name": "xhth", "weight": 57, "subtree_weight": 57, "children": []}
+  ]
+}
+"#;
+        assert_eq!(
+            expected_json,
+            Day07::export(&tower, ExportFormat::Json).unwrap()
+        );
+        let dot = Day07::export(&tower, ExportFormat::Dot).unwrap();
+        assert!(dot.starts_with("digraph tower {\n    \"tknk\" [label=\"tknk (41) [778]\"];\n"));
+        assert!(dot.contains("    \"tknk\" -> \"ugml\";\n"));
+        assert!(dot.contains("    \"ugml\" [label=\"ugml (68) [251]\"];\n"));
+        assert_eq!(13, dot.matches("[label=").count());
+        assert_eq!(12, dot.matches(" -> ").count());
+    }
+
+    /// Tests the Day 07 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day07_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!("tknk", solve_part1(&input));
+        assert_eq!(60, solve_part2(&input));
+    }
+}
@@ -0,0 +1,730 @@
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
+use fancy_regex::Regex;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::ParseError;
+use crate::utils::intern::{Interner, Symbol};
+use crate::utils::machines::registervm::{Flow, Op, RegisterVm, VmState};
+use crate::utils::parse::parse_integer;
+
+const PROBLEM_NAME: &str = "I Heard You Like Registers";
+const PROBLEM_INPUT_FILE: &str = "./input/day08.txt";
+const PROBLEM_DAY: u64 = 8;
+
+/// Custom error type indicating that the parsing of an Instruction has failed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InstructionParseError {
+    /// Text of the instruction that could not be parsed.
+    pub line: String,
+    /// Description of the failure, including the offending token (if any).
+    pub message: String,
+}
+
+impl fmt::Display for InstructionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid instruction \"{}\": {}", self.line, self.message)
+    }
+}
+
+/// Custom error type indicating that one or more lines of a program could not be parsed as
+/// Instructions.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProgramParseError {
+    /// Line number (starting at 1) and error for each line that could not be parsed, in order.
+    pub errors: Vec<(usize, InstructionParseError)>,
+}
+
+impl fmt::Display for ProgramParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Parsing of program failed at {} line(s)",
+            self.errors.len()
+        )?;
+        for (line, err) in self.errors.iter() {
+            write!(f, "\n  line {line}: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Custom error type indicating that an instruction processed by a [`RegisterMachine`] has failed.
+/// Each variant holds the index of the failing instruction in the program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionError {
+    /// The instruction attempted to divide a register by zero.
+    DivideByZero { index: usize },
+    /// Result of the instruction does not fit in a 64-bit register.
+    Overflow { index: usize },
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::DivideByZero { index } => {
+                write!(f, "Instruction {index} divided by zero")
+            }
+            ExecutionError::Overflow { index } => write!(f, "Instruction {index} overflowed"),
+        }
+    }
+}
+
+/// Represents a single instruction used in this problem, with registers referred to by name (or by
+/// [`Symbol`] once interned for execution by a [`RegisterMachine`]).
+#[derive(Clone)]
+pub struct Instruction<R = String> {
+    reg_target: R,
+    op: Operation,
+    operand: Operand<R>,
+    reg_check: R,
+    comp: Comparator,
+    check_operand: Operand<R>,
+}
+
+impl Instruction {
+    /// Gets the instruction with each register name replaced by its interned [`Symbol`].
+    fn intern(&self, interner: &mut Interner) -> Instruction<Symbol> {
+        Instruction {
+            reg_target: interner.intern(&self.reg_target),
+            op: self.op,
+            operand: self.operand.intern(interner),
+            reg_check: interner.intern(&self.reg_check),
+            comp: self.comp,
+            check_operand: self.check_operand.intern(interner),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_instruction(s).map_err(|err| InstructionParseError {
+            line: s.to_string(),
+            message: err.message,
+        })
+    }
+}
+
+/// Parses the text of an instruction (e.g. "b inc 5 if a > 1").
+fn parse_instruction(s: &str) -> Result<Instruction, ParseError> {
+    let [reg_target, op, operand, reg_check, comp, check_operand] = tokenise_instruction(s)
+        .ok_or_else(|| ParseError {
+            message: String::from(
+                "expected \"<register> <operation> <operand> if <register> <comparator> <operand>\"",
+            ),
+        })?;
+    Ok(Instruction {
+        reg_target: parse_register_name(reg_target)?,
+        op: Operation::from_str(op)?,
+        operand: Operand::from_str(operand)?,
+        reg_check: parse_register_name(reg_check)?,
+        comp: Comparator::from_str(comp)?,
+        check_operand: Operand::from_str(check_operand)?,
+    })
+}
+
+/// Parses each non-empty line of the program (with surrounding whitespace trimmed) as an
+/// [`Instruction`].
+///
+/// If any lines cannot be parsed, a [`ProgramParseError`] is returned giving the error for every
+/// malformed line, rather than only the first.
+pub fn parse_program(raw_input: &str) -> Result<Vec<Instruction>, ProgramParseError> {
+    let mut instructions: Vec<Instruction> = vec![];
+    let mut errors: Vec<(usize, InstructionParseError)> = vec![];
+    for (i, line) in raw_input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match Instruction::from_str(line) {
+            Ok(instruction) => instructions.push(instruction),
+            Err(err) => errors.push((i + 1, err)),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(instructions),
+        false => Err(ProgramParseError { errors }),
+    }
+}
+
+/// Splits the text of an instruction (e.g. "b inc 5 if a > 1") into the target register,
+/// operation, operand, checked register, comparator and check operand. Returns None if the text
+/// does not have the form of an instruction.
+#[cfg(feature = "regex")]
+fn tokenise_instruction(s: &str) -> Option<[&str; 6]> {
+    static INSTRUCTION_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = INSTRUCTION_REGEX.get_or_init(|| {
+        // Tokens are checked by their own parsers, so that errors can give the offending token
+        Regex::new(r"^(\S+) (\S+) (\S+) if (\S+) (\S+) (\S+)$").unwrap()
+    });
+    let caps = regex.captures(s).ok()??;
+    Some([1, 2, 3, 4, 5, 6].map(|i| caps.get(i).unwrap().as_str()))
+}
+
+/// Splits the text of an instruction (e.g. "b inc 5 if a > 1") into the target register,
+/// operation, operand, checked register, comparator and check operand. Returns None if the text
+/// does not have the form of an instruction.
+#[cfg(not(feature = "regex"))]
+fn tokenise_instruction(s: &str) -> Option<[&str; 6]> {
+    match s.split(' ').collect::<Vec<&str>>().as_slice() {
+        [reg_target, op, operand, "if", reg_check, comp, check_operand] => {
+            Some([reg_target, op, operand, reg_check, comp, check_operand])
+        }
+        _ => None,
+    }
+}
+
+/// Parses the name of a register, consisting of one or more lowercase letters.
+fn parse_register_name(s: &str) -> Result<String, ParseError> {
+    match !s.is_empty() && s.chars().all(|c| c.is_ascii_lowercase()) {
+        true => Ok(s.to_string()),
+        false => Err(ParseError {
+            message: format!("invalid register name \"{s}\""),
+        }),
+    }
+}
+
+/// Represents the value used by an operation or comparison, given either as a literal value or as
+/// the name of the register holding the value.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Operand<R = String> {
+    Value(i64),
+    Register(R),
+}
+
+impl Operand {
+    /// Gets the operand with the register name (if any) replaced by its interned [`Symbol`].
+    fn intern(&self, interner: &mut Interner) -> Operand<Symbol> {
+        match self {
+            Operand::Value(value) => Operand::Value(*value),
+            Operand::Register(name) => Operand::Register(interner.intern(name)),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_integer::<i64>(s) {
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => parse_register_name(s)
+                .map(Operand::Register)
+                .map_err(|_| ParseError {
+                    message: format!("invalid operand \"{s}\""),
+                }),
+        }
+    }
+}
+
+/// Represents the operations that can be applied to a register value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operation {
+    Increase,
+    Decrease,
+    Multiply,
+    Divide,
+    Set,
+}
+
+impl Operation {
+    /// Applies the operation to the register value using the given operand value. Returns None if
+    /// the operation divides by zero or the result does not fit in 64 bits.
+    pub fn apply(&self, value: i64, operand: i64) -> Option<i64> {
+        match self {
+            Operation::Increase => value.checked_add(operand),
+            Operation::Decrease => value.checked_sub(operand),
+            Operation::Multiply => value.checked_mul(operand),
+            Operation::Divide => value.checked_div(operand),
+            Operation::Set => Some(operand),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inc" => Ok(Operation::Increase),
+            "dec" => Ok(Operation::Decrease),
+            "mul" => Ok(Operation::Multiply),
+            "div" => Ok(Operation::Divide),
+            "set" => Ok(Operation::Set),
+            _ => Err(ParseError {
+                message: format!("unknown operation \"{s}\""),
+            }),
+        }
+    }
+}
+
+/// Represents the comparators that occur in the instructions from the problem.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Comparator {
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equal,
+    LessThan,
+    LessThanOrEqual,
+    NotEqual,
+}
+
+impl Comparator {
+    /// Evaluates the comparison between the left- and right-hand values.
+    pub fn evaluate(&self, left: i64, right: i64) -> bool {
+        match self {
+            Comparator::GreaterThan => left > right,
+            Comparator::GreaterThanOrEqual => left >= right,
+            Comparator::Equal => left == right,
+            Comparator::LessThan => left < right,
+            Comparator::LessThanOrEqual => left <= right,
+            Comparator::NotEqual => left != right,
+        }
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ">" => Ok(Comparator::GreaterThan),
+            ">=" => Ok(Comparator::GreaterThanOrEqual),
+            "==" => Ok(Comparator::Equal),
+            "<" => Ok(Comparator::LessThan),
+            "<=" => Ok(Comparator::LessThanOrEqual),
+            "!=" => Ok(Comparator::NotEqual),
+            _ => Err(ParseError {
+                message: format!("unknown comparator \"{s}\""),
+            }),
+        }
+    }
+}
+
+/// Record of a single instruction processed by a [`RegisterMachine`] with tracing enabled.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraceEntry {
+    /// Index of the instruction in the program.
+    pub index: usize,
+    /// Register checked by the condition, and its value when the condition was evaluated.
+    pub check: (Symbol, i64),
+    /// Whether the condition was met (and so the target register was updated).
+    pub condition_met: bool,
+    /// Target register, and its values before and after the instruction.
+    pub target: (Symbol, i64, i64),
+}
+
+/// Instruction set context used by a [`RegisterMachine`], holding the maximum register value seen
+/// and the trace of the instructions processed (if enabled).
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct TraceState {
+    max_value_seen: Option<i64>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl Op for Instruction<Symbol> {
+    type Registers = Vec<Option<i64>>;
+    type Context = TraceState;
+    type Error = ExecutionError;
+
+    /// Processes the instruction, recording it in the trace (if enabled). Registers used as
+    /// operands are treated in the same way as the check register.
+    fn execute(
+        &self,
+        state: &mut VmState<Vec<Option<i64>>, TraceState>,
+    ) -> Result<Flow, ExecutionError> {
+        let index = state.pc;
+        let check_value = touch(state, self.reg_check);
+        let old_value = touch(state, self.reg_target);
+        let right = operand_value(state, &self.check_operand);
+        let condition_met = self.comp.evaluate(check_value, right);
+        let new_value = match condition_met {
+            true => {
+                let operand = operand_value(state, &self.operand);
+                let error = match (self.op, operand) {
+                    (Operation::Divide, 0) => ExecutionError::DivideByZero { index },
+                    _ => ExecutionError::Overflow { index },
+                };
+                self.op.apply(old_value, operand).ok_or(error)?
+            }
+            false => old_value,
+        };
+        state.registers[self.reg_target.index()] = Some(new_value);
+        let context = &mut state.context;
+        context.max_value_seen = context.max_value_seen.max(Some(new_value));
+        if let Some(trace) = context.trace.as_mut() {
+            trace.push(TraceEntry {
+                index,
+                check: (self.reg_check, check_value),
+                condition_met,
+                target: (self.reg_target, old_value, new_value),
+            });
+        }
+        Ok(Flow::Next)
+    }
+}
+
+/// Gets the value of the operand, adding the register it refers to (if any) to the machine.
+fn operand_value(
+    state: &mut VmState<Vec<Option<i64>>, TraceState>,
+    operand: &Operand<Symbol>,
+) -> i64 {
+    match operand {
+        Operand::Value(value) => *value,
+        Operand::Register(register) => touch(state, *register),
+    }
+}
+
+/// Adds the register to the machine (if not already present), returning its value.
+fn touch(state: &mut VmState<Vec<Option<i64>>, TraceState>, register: Symbol) -> i64 {
+    let value = *state.registers[register.index()].get_or_insert(0);
+    let context = &mut state.context;
+    context.max_value_seen = context.max_value_seen.max(Some(value));
+    value
+}
+
+/// Machine processing the conditional register instructions used in this problem, running on a
+/// [`RegisterVm`]. All registers start with the value 0, and are added to the machine when first
+/// referred to by an instruction.
+///
+/// Register names are interned when the program is loaded, so that the registers are held in a
+/// vector indexed by [`Symbol`]. The names can be looked up with [`RegisterMachine::name`].
+pub struct RegisterMachine {
+    vm: RegisterVm<Instruction<Symbol>>,
+    interner: Interner,
+}
+
+impl RegisterMachine {
+    /// Creates a new [`RegisterMachine`] loaded with the given program.
+    pub fn new(instructions: &[Instruction]) -> RegisterMachine {
+        RegisterMachine::with_context(instructions, TraceState::default())
+    }
+
+    /// Creates a new [`RegisterMachine`] loaded with the given program, that records a
+    /// [`TraceEntry`] for each instruction.
+    pub fn with_trace(instructions: &[Instruction]) -> RegisterMachine {
+        let context = TraceState {
+            trace: Some(vec![]),
+            ..TraceState::default()
+        };
+        RegisterMachine::with_context(instructions, context)
+    }
+
+    /// Creates a new [`RegisterMachine`] loaded with the given program (after interning its register
+    /// names) and instruction set context.
+    fn with_context(instructions: &[Instruction], context: TraceState) -> RegisterMachine {
+        let mut interner = Interner::new();
+        let program = instructions
+            .iter()
+            .map(|instruction| instruction.intern(&mut interner))
+            .collect::<Vec<Instruction<Symbol>>>();
+        RegisterMachine {
+            vm: RegisterVm::new(&program, vec![None; interner.len()], context),
+            interner,
+        }
+    }
+
+    /// Processes the remaining instructions in order, stopping at the first instruction that
+    /// divides by zero or overflows.
+    pub fn run(&mut self) -> Result<(), ExecutionError> {
+        self.vm.run().map(|_| ())
+    }
+
+    /// Gets the value of the named register. Registers not yet referred to have the value 0.
+    pub fn register(&self, name: &str) -> i64 {
+        self.interner
+            .get(name)
+            .and_then(|register| self.vm.state().registers[register.index()])
+            .unwrap_or(0)
+    }
+
+    /// Gets the names and values of all registers referred to by the instructions processed so far.
+    pub fn registers(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.interner.iter().filter_map(|(register, name)| {
+            self.vm.state().registers[register.index()].map(|value| (name, value))
+        })
+    }
+
+    /// Gets the name of the register used by the program.
+    pub fn name(&self, register: Symbol) -> &str {
+        self.interner.resolve(register)
+    }
+
+    /// Gets the [`Symbol`] for the named register, or None if the program does not use the
+    /// register.
+    pub fn symbol(&self, name: &str) -> Option<Symbol> {
+        self.interner.get(name)
+    }
+
+    /// Gets the current maximum register value, or None if no instructions have been processed.
+    pub fn max_register_value(&self) -> Option<i64> {
+        self.registers().map(|(_, value)| value).max()
+    }
+
+    /// Gets the maximum value held by any register at any point, or None if no instructions have
+    /// been processed.
+    pub fn max_value_seen(&self) -> Option<i64> {
+        self.vm.state().context.max_value_seen
+    }
+
+    /// Gets the trace of the instructions processed, or None if tracing is not enabled.
+    pub fn trace(&self) -> Option<&[TraceEntry]> {
+        self.vm.state().context.trace.as_deref()
+    }
+}
+
+/// Solver for AOC 2017 Day 08.
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Vec<Instruction>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 08 input file in the format required by the solver functions.
+///
+/// Returned value is vector of Instructions parsed from the lines of the input file.
+fn process_input_file(filename: &str) -> Vec<Instruction> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_program(&raw_input).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Solves AOC 2017 Day 08 Part 1.
+///
+/// Determines the maximum register value after all instructions have been processed.
+fn solve_part1(instructions: &[Instruction]) -> i64 {
+    let mut machine = RegisterMachine::new(instructions);
+    machine.run().unwrap();
+    machine
+        .max_register_value()
+        .expect("Failed to find maximum register value at end of instruction processing!")
+}
+
+/// Solves AOC 2017 Day 08 Part 2.
+///
+/// Determines the maximum register value at any point during the processing of the instructions.
+fn solve_part2(instructions: &[Instruction]) -> i64 {
+    let mut machine = RegisterMachine::new(instructions);
+    machine.run().unwrap();
+    machine
+        .max_value_seen()
+        .expect("Failed to find maximum register value during processing of instructions!")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 08 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day08_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(4902, solution);
+    }
+
+    /// Tests the Day 08 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day08_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(7037, solution);
+    }
+
+    /// Tests the final register state and trace of the [`RegisterMachine`] against the example
+    /// given in the problem statement.
+    #[test]
+    fn test_day08_register_machine_example() {
+        let instructions = [
+            "b inc 5 if a > 1",
+            "a inc 1 if b < 5",
+            "c dec -10 if a >= 1",
+            "c inc -20 if c == 10",
+        ]
+        .iter()
+        .map(|line| Instruction::from_str(line).unwrap())
+        .collect::<Vec<Instruction>>();
+        let mut machine = RegisterMachine::with_trace(&instructions);
+        machine.run().unwrap();
+        assert_eq!(1, machine.register("a"));
+        assert_eq!(0, machine.register("b"));
+        assert_eq!(-10, machine.register("c"));
+        assert_eq!(0, machine.register("unused"));
+        assert_eq!(Some(1), machine.max_register_value());
+        assert_eq!(Some(10), machine.max_value_seen());
+        let trace = machine.trace().unwrap();
+        assert_eq!(
+            vec![false, true, true, true],
+            trace.iter().map(|e| e.condition_met).collect::<Vec<bool>>()
+        );
+        assert_eq!(
+            TraceEntry {
+                index: 2,
+                check: (machine.symbol("a").unwrap(), 1),
+                condition_met: true,
+                target: (machine.symbol("c").unwrap(), 0, 10),
+            },
+            trace[2]
+        );
+        assert_eq!("c", machine.name(trace[3].target.0));
+        let mut registers = machine.registers().collect::<Vec<(&str, i64)>>();
+        registers.sort_unstable();
+        assert_eq!(vec![("a", 1), ("b", 0), ("c", -10)], registers);
+    }
+
+    /// Tests the additional operations, along with operations and comparisons using registers in
+    /// place of literal values.
+    #[test]
+    fn test_day08_extended_instructions() {
+        let program = [
+            "a set 6 if z == 0",
+            "b set a if a > z",
+            "b mul b if b != 0",
+            "c inc b if b >= a",
+            "c div 4 if c == 36",
+            "d dec c if a <= c",
+        ]
+        .iter()
+        .map(|line| Instruction::from_str(line).unwrap())
+        .collect::<Vec<Instruction>>();
+        let mut machine = RegisterMachine::new(&program);
+        machine.run().unwrap();
+        assert_eq!(6, machine.register("a"));
+        assert_eq!(36, machine.register("b"));
+        assert_eq!(9, machine.register("c"));
+        assert_eq!(-9, machine.register("d"));
+        let divide_by_zero = ["a inc 1 if a == 0", "a div z if a > 0", "a inc 1 if a > 0"]
+            .iter()
+            .map(|line| Instruction::from_str(line).unwrap())
+            .collect::<Vec<Instruction>>();
+        let mut machine = RegisterMachine::new(&divide_by_zero);
+        assert_eq!(
+            Err(ExecutionError::DivideByZero { index: 1 }),
+            machine.run()
+        );
+        assert_eq!(1, machine.register("a"));
+        assert!(Instruction::from_str("a inc 2 unless b > 0").is_err());
+        assert!(Instruction::from_str("a inc 2 if b > 0 ").is_err());
+    }
+
+    /// Tests that arithmetic overflowing 64 bits is reported as an error, separately from division
+    /// by zero, leaving the register unchanged.
+    #[test]
+    fn test_day08_overflow() {
+        for (lines, index, expected) in [
+            (
+                vec!["a set 9223372036854775807 if z == 0", "a inc 1 if z == 0"],
+                1,
+                i64::MAX,
+            ),
+            (
+                vec!["a set -9223372036854775807 if z == 0", "a dec 2 if z == 0"],
+                1,
+                -i64::MAX,
+            ),
+            (
+                vec!["a set 4294967296 if z == 0", "a mul a if z == 0"],
+                1,
+                1 << 32,
+            ),
+            (
+                vec![
+                    "a set -9223372036854775807 if z == 0",
+                    "a dec 1 if z == 0",
+                    "a div -1 if z == 0",
+                ],
+                2,
+                i64::MIN,
+            ),
+        ] {
+            let program = lines
+                .iter()
+                .map(|line| Instruction::from_str(line).unwrap())
+                .collect::<Vec<Instruction>>();
+            let mut machine = RegisterMachine::new(&program);
+            let error = ExecutionError::Overflow { index };
+            assert_eq!(Err(error), machine.run());
+            assert_eq!(expected, machine.register("a"));
+        }
+        assert_eq!(
+            "Instruction 1 overflowed",
+            ExecutionError::Overflow { index: 1 }.to_string()
+        );
+    }
+
+    /// Tests that parse errors give the offending token, and that every malformed line of a program
+    /// is reported.
+    #[test]
+    fn test_day08_parse_errors() {
+        for (line, message) in [
+            ("a pow 2 if b > 0", "unknown operation \"pow\""),
+            ("a inc +2 if b > 0", "invalid operand \"+2\""),
+            ("A inc 2 if b > 0", "invalid register name \"A\""),
+        ] {
+            let err = Instruction::from_str(line).err().unwrap();
+            assert_eq!(line, err.line);
+            assert_eq!(message, err.message);
+        }
+        let err = Instruction::from_str("a inc 2 if b ~ 0").err().unwrap();
+        assert_eq!(
+            "Invalid instruction \"a inc 2 if b ~ 0\": unknown comparator \"~\"",
+            err.to_string()
+        );
+        let program =
+            "b inc 5 if a > 1\na pow 1 if b < 5\n\nc dec -10 if a >= 1\nc inc if c == 10\n";
+        let err = parse_program(program).err().unwrap();
+        assert_eq!(
+            vec![2, 5],
+            err.errors
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<usize>>()
+        );
+        assert_eq!(
+            "Parsing of program failed at 2 line(s)\n  line 2: Invalid instruction \"a pow 1 if b \
+             < 5\": unknown operation \"pow\"\n  line 5: Invalid instruction \"c inc if c == 10\": \
+             expected \"<register> <operation> <operand> if <register> <comparator> <operand>\"",
+            err.to_string()
+        );
+        assert_eq!(
+            4,
+            parse_program(&program.replace("pow", "inc").replace("inc if", "inc 1 if"))
+                .unwrap()
+                .len()
+        );
+    }
+
+    /// Tests the Day 08 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day08_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(1, solve_part1(&input));
+        assert_eq!(10, solve_part2(&input));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fs::File;
+use std::io::BufReader;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::stream::{parse_stream, StreamMode, StreamState};
+
+const PROBLEM_NAME: &str = "Stream Processing";
+const PROBLEM_INPUT_FILE: &str = "./input/day09.txt";
+const PROBLEM_DAY: u64 = 9;
+
+/// Solver for AOC 2017 Day 09.
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = StreamState;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 09 input file in the format required by the solver functions.
+///
+/// Returned value is the final [`StreamState`] after the input file is parsed as a stream, without
+/// loading the whole file into memory. Panics if the stream is malformed (e.g. ends with groups or
+/// garbage left open).
+fn process_input_file(filename: &str) -> StreamState {
+    // Open problem input file and parse its contents chunk by chunk
+    let file = File::open(filename).unwrap();
+    match parse_stream(BufReader::new(file), StreamMode::Strict) {
+        Ok(stream) => stream,
+        Err(e) => panic!("Invalid stream in input file! // {e}"),
+    }
+}
+
+/// Solves AOC 2017 Day 09 Part 1.
+///
+/// Calculates the total score for all groups in the stream.
+fn solve_part1(stream: &StreamState) -> u64 {
+    stream.score()
+}
+
+/// Solves AOC 2017 Day 09 Part 2.
+///
+/// Counts the number of non-cancelled characters within the garbage sections of the stream.
+fn solve_part2(stream: &StreamState) -> u64 {
+    stream.garbage_count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 09 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day09_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(16869, solution);
+    }
+
+    /// Tests the Day 09 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day09_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(7284, solution);
+    }
+
+    /// Tests the Day 09 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day09_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(3, solve_part1(&input));
+        assert_eq!(17, solve_part2(&input));
+    }
+}
@@ -0,0 +1,134 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::AocError;
+use crate::utils::knot_hash::{calculate_knot_hash, twist_product};
+use crate::utils::parse::parse_split;
+
+const PROBLEM_NAME: &str = "Knot Hash";
+const PROBLEM_INPUT_FILE: &str = "./input/day10.txt";
+const PROBLEM_DAY: u64 = 10;
+/// Number of values in the strand twisted in Part 1.
+const STRAND_SIZE: usize = 256;
+
+/// Solver for AOC 2017 Day 10.
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = String;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 10 input file in the format required by the solver functions.
+///
+/// Returned value is string contained in the input file.
+fn process_input_file(filename: &str) -> String {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    raw_input.trim().to_string()
+}
+
+/// Solves AOC 2017 Day 10 Part 1.
+///
+/// Calculates the sparse hash of the numbers 0-255 inclusive using the comma-separated values in
+/// the input string, and returns the product of the first two values of the sparse hash.
+fn solve_part1(input_string: &str) -> u64 {
+    let lengths = parse_lengths(input_string).unwrap();
+    twist_product(&lengths, STRAND_SIZE)
+}
+
+/// Solves AOC 2017 Day 10 Part 2.
+///
+/// Calculates the knot hash of the given string.
+fn solve_part2(input_string: &str) -> String {
+    calculate_knot_hash(input_string)
+}
+
+/// Parses the comma-separated lengths used in Part 1. Whitespace around each length is ignored, as
+/// are empty elements (e.g. from a trailing comma).
+///
+/// Returns an [`AocError`] giving the element number of the first length that is not a number or
+/// that is longer than the strand.
+fn parse_lengths(input_string: &str) -> Result<Vec<usize>, AocError> {
+    let lengths = parse_split::<usize>(input_string, ",")?;
+    match lengths.iter().position(|&len| len > STRAND_SIZE) {
+        Some(i) => Err(AocError::ElementParse {
+            element: i + 1,
+            text: lengths[i].to_string(),
+        }),
+        None => Ok(lengths),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 10 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day10_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(38628, solution);
+    }
+
+    /// Tests the Day 10 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day10_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!("e1462100a34221a7f0906da15c1c979a", solution);
+    }
+
+    /// Tests parsing of lengths with extra whitespace, empty elements and invalid lengths.
+    #[test]
+    fn test_day10_parse_lengths() {
+        assert_eq!(Ok(vec![3, 4, 1, 5]), parse_lengths(" 3, 4,1 ,5,"));
+        assert_eq!(Ok(vec![]), parse_lengths(""));
+        assert_eq!(
+            Err(AocError::ElementParse {
+                element: 2,
+                text: String::from("x")
+            }),
+            parse_lengths("3,x,1")
+        );
+        assert_eq!(
+            Err(AocError::ElementParse {
+                element: 3,
+                text: String::from("257")
+            }),
+            parse_lengths("3,,4,257")
+        );
+    }
+
+    /// Tests both parts against an empty input, where the knot hash is that of the empty string.
+    #[test]
+    fn test_day10_empty_input() {
+        assert_eq!(0, solve_part1(""));
+        assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", solve_part2(""));
+    }
+
+    /// Tests the Day 10 Part 2 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day10_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!("3efbe78a8d82f29979031a4aa0b16a9d", solve_part2(&input));
+    }
+}
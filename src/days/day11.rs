@@ -0,0 +1,328 @@
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::cartography::Point3D;
+
+const PROBLEM_NAME: &str = "Hex Ed";
+const PROBLEM_INPUT_FILE: &str = "./input/day11.txt";
+const PROBLEM_DAY: u64 = 11;
+
+/// Custom error type indicating that the parsing of an HexGridDirection has failed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HexGridDirectionParseError {
+    /// Token that could not be parsed as a direction.
+    pub token: String,
+}
+
+impl fmt::Display for HexGridDirectionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid hex grid direction \"{}\"", self.token)
+    }
+}
+
+/// Custom error type indicating that an element of a comma-separated list of HexGridDirections
+/// could not be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HexPathParseError {
+    /// Position of the element in the list, starting at 1.
+    pub position: usize,
+    /// Token that could not be parsed as a direction.
+    pub token: String,
+}
+
+impl fmt::Display for HexPathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid hex grid direction \"{}\" at position {}",
+            self.token, self.position
+        )
+    }
+}
+
+/// Represents the six virtual directions from one hexagon tile to another adjoining tile.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HexGridDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FromStr for HexGridDirection {
+    type Err = HexGridDirectionParseError;
+
+    /// Converts the given string to the corresponding variant of [`HexGridDirection`]. Both the
+    /// short ("ne") and long ("northeast") names are accepted, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "n" | "north" => Ok(HexGridDirection::North),
+            "ne" | "northeast" => Ok(HexGridDirection::NorthEast),
+            "se" | "southeast" => Ok(HexGridDirection::SouthEast),
+            "s" | "south" => Ok(HexGridDirection::South),
+            "sw" | "southwest" => Ok(HexGridDirection::SouthWest),
+            "nw" | "northwest" => Ok(HexGridDirection::NorthWest),
+            _ => Err(HexGridDirectionParseError {
+                token: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Parses the comma-separated list of [`HexGridDirection`]s, with surrounding whitespace trimmed
+/// from each element. A single empty element at the end (after a trailing comma) is tolerated, but
+/// empty elements anywhere else are rejected.
+///
+/// Returns a [`HexPathParseError`] giving the position and text of the first element that could not
+/// be parsed.
+pub fn parse_directions(s: &str) -> Result<Vec<HexGridDirection>, HexPathParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    let s = s.strip_suffix(',').unwrap_or(s);
+    s.split(',')
+        .map(|token| token.trim())
+        .enumerate()
+        .map(|(i, token)| {
+            token
+                .parse::<HexGridDirection>()
+                .map_err(|err| HexPathParseError {
+                    position: i + 1,
+                    token: err.token,
+                })
+        })
+        .collect()
+}
+
+/// Solver for AOC 2017 Day 11.
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<HexGridDirection>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 11 input file in the format required by the solver functions.
+///
+/// Returned value is vector of [`HexGridDirection`] variants based on the comma-separated strings
+/// extracted from the input file.
+fn process_input_file(filename: &str) -> Vec<HexGridDirection> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_directions(&raw_input).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Solves AOC 2017 Day 11 Part 1.
+///
+/// Determine the number of steps required to reach the final location after processing all of the
+/// hexagon grid moves.
+fn solve_part1(input: &[HexGridDirection]) -> u64 {
+    HexWalk::new(input).final_step().distance()
+}
+
+/// Solves AOC 2017 Day 11 Part 2.
+///
+/// Determines the maximum number of steps from the origin that the child process reaches during its
+/// journey.
+fn solve_part2(input: &[HexGridDirection]) -> u64 {
+    HexWalk::new(input).max_excursion().distance()
+}
+
+/// Single step in a [`HexWalk`], holding the location reached and its distance (in steps) from the
+/// origin.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct HexStep {
+    loc: Point3D,
+    distance: u64,
+}
+
+impl HexStep {
+    /// Gets the location reached by the step.
+    pub fn loc(&self) -> Point3D {
+        self.loc
+    }
+
+    /// Gets the distance (in steps) of the location from the origin.
+    pub fn distance(&self) -> u64 {
+        self.distance
+    }
+}
+
+/// Trace of a walk across the hexagon grid, starting at the origin and recording the location and
+/// distance from the origin after every move.
+pub struct HexWalk {
+    trace: Vec<HexStep>,
+}
+
+impl HexWalk {
+    /// Creates a new [`HexWalk`] by following the given directions from the origin.
+    pub fn new(dirns: &[HexGridDirection]) -> HexWalk {
+        let mut loc = Point3D::new(0, 0, 0);
+        let mut trace = vec![HexStep { loc, distance: 0 }];
+        for dirn in dirns {
+            update_point3d_location(&mut loc, dirn);
+            trace.push(HexStep {
+                loc,
+                distance: get_steps_from_origin(&loc),
+            });
+        }
+        HexWalk { trace }
+    }
+
+    /// Gets the full trace of the walk. The first step is the origin, followed by one step for each
+    /// move.
+    pub fn trace(&self) -> &[HexStep] {
+        &self.trace
+    }
+
+    /// Gets the final step of the walk.
+    pub fn final_step(&self) -> &HexStep {
+        self.trace().last().unwrap()
+    }
+
+    /// Gets the first step of the walk at the maximum distance from the origin.
+    pub fn max_excursion(&self) -> &HexStep {
+        self.trace()
+            .iter()
+            .rev()
+            .max_by_key(|step| step.distance)
+            .unwrap()
+    }
+}
+
+/// Updates the Point3D location based on the next tile specified by the [`HexGridDirection`]
+/// variant.
+fn update_point3d_location(loc: &mut Point3D, dirn: &HexGridDirection) {
+    match dirn {
+        HexGridDirection::North => loc.shift(0, -1, 1),
+        HexGridDirection::NorthEast => loc.shift(1, -1, 0),
+        HexGridDirection::SouthEast => loc.shift(1, 0, -1),
+        HexGridDirection::South => loc.shift(0, 1, -1),
+        HexGridDirection::SouthWest => loc.shift(-1, 1, 0),
+        HexGridDirection::NorthWest => loc.shift(-1, 0, 1),
+    }
+}
+
+/// Gets the number of steps from the origin represented by the Point3D location. The location is
+/// taken to be a point on a two-dimensional hexagon grid.
+fn get_steps_from_origin(loc: &Point3D) -> u64 {
+    let coords = [loc.x().abs(), loc.y().abs(), loc.z().abs()];
+    coords.iter().max().unwrap().unsigned_abs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 11 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day11_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(877, solution);
+    }
+
+    /// Tests the Day 11 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day11_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(1622, solution);
+    }
+
+    /// Tests the walk trace against the examples given in the problem statement, including the
+    /// maximum excursion of a walk that returns to the origin.
+    #[test]
+    fn test_hex_walk_examples() {
+        let examples = [
+            ("ne,ne,ne", 3),
+            ("ne,ne,sw,sw", 0),
+            ("ne,ne,s,s", 2),
+            ("se,sw,se,sw,sw", 3),
+        ];
+        for (dirns, expected) in examples {
+            let walk = HexWalk::new(&parse_directions(dirns).unwrap());
+            assert_eq!(expected, walk.final_step().distance());
+        }
+        let walk = HexWalk::new(&parse_directions("ne,ne,sw,sw").unwrap());
+        let distances = walk
+            .trace()
+            .iter()
+            .map(|step| step.distance())
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![0, 1, 2, 1, 0], distances);
+        assert_eq!(Point3D::new(2, -2, 0), walk.max_excursion().loc());
+    }
+
+    /// Tests that directions are parsed from their short and long names in any case, and that the
+    /// first malformed element is reported with its position.
+    #[test]
+    fn test_day11_parse_directions() {
+        use HexGridDirection::*;
+        assert_eq!(
+            Ok(vec![
+                North, NorthEast, SouthEast, South, SouthWest, NorthWest
+            ]),
+            parse_directions("N,northEast, SE ,south,sw,NorthWest,\n")
+        );
+        assert_eq!(Ok(vec![North]), parse_directions("n\n"));
+        assert_eq!(Ok(vec![]), parse_directions(""));
+        assert_eq!(
+            Err(HexPathParseError {
+                position: 4,
+                token: String::from("up"),
+            }),
+            parse_directions("ne,n,s,up,nn")
+        );
+        // Empty elements are only tolerated at the end of the list
+        for (path, position) in [("ne,,n", 2), (",ne", 1), ("ne,n,,", 3)] {
+            assert_eq!(
+                Err(HexPathParseError {
+                    position,
+                    token: String::new(),
+                }),
+                parse_directions(path)
+            );
+        }
+        assert_eq!(
+            "Invalid hex grid direction \"north-east\" at position 1",
+            parse_directions("north-east").unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(HexGridDirectionParseError {
+                token: String::from("nne"),
+            }),
+            "nne".parse::<HexGridDirection>()
+        );
+    }
+
+    /// Tests the Day 11 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day11_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(2, solve_part1(&input));
+        assert_eq!(2, solve_part2(&input));
+    }
+}
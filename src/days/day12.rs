@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+
+use crate::scaffold::{Answer, ExportFormat, Solver};
+use crate::utils::error::{AocError, ParseError};
+use crate::utils::graph::WeightedGraph;
+use crate::utils::parse::{parse_integer, parse_lines_with, split_tuple};
+
+const PROBLEM_NAME: &str = "Digital Plumber";
+const PROBLEM_INPUT_FILE: &str = "./input/day12.txt";
+const PROBLEM_DAY: u64 = 12;
+
+/// Custom error type indicating that the parsing of a line from the input file has failed.
+#[derive(Debug)]
+struct InputLineParseError;
+
+impl From<ParseError> for InputLineParseError {
+    fn from(_: ParseError) -> Self {
+        InputLineParseError
+    }
+}
+
+/// Solver for AOC 2017 Day 12.
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = HashMap<u64, Vec<u64>>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn export(input: &Self::Input, format: ExportFormat) -> Option<String> {
+        match format {
+            ExportFormat::Dot => Some(export_pipe_graph_dot(input)),
+            ExportFormat::Json => None,
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 12 input file in the format required by the solver functions.
+///
+/// Returned value is HashMap mapping each program to the other it is directly connected to via
+/// pipes.
+fn process_input_file(filename: &str) -> HashMap<u64, Vec<u64>> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    let mut program_conns: HashMap<u64, Vec<u64>> = HashMap::new();
+    for line in raw_input.lines() {
+        if let Ok((left, right)) = parse_input_file_line(line) {
+            program_conns.insert(left, right);
+        }
+    }
+    program_conns
+}
+
+/// Solves AOC 2017 Day 12 Part 1.
+///
+/// Determines the number of programs in the group containing the program '0'.
+fn solve_part1(input: &HashMap<u64, Vec<u64>>) -> usize {
+    determine_program_group_members(0, input).len()
+}
+
+/// Solves AOC 2017 Day 12 Part 2.
+///
+/// Determines the total number of separate programs groups specified in the program connections.
+fn solve_part2(input: &HashMap<u64, Vec<u64>>) -> usize {
+    let mut visited: HashSet<u64> = HashSet::new();
+    let mut total_program_groups = 0;
+    for program in input.keys() {
+        // Skip any programs that have observed in a previously visited group
+        if !visited.contains(program) {
+            // Update the overall record of programs visited to prevent double-counting
+            let run_visited = determine_program_group_members(*program, input);
+            visited.extend(run_visited);
+            total_program_groups += 1;
+        }
+    }
+    total_program_groups
+}
+
+/// Exports the pipes connecting the programs as an undirected Graphviz DOT graph. Each pipe is
+/// given once, and programs only connected to themselves are given as standalone nodes.
+pub fn export_pipe_graph_dot(program_conns: &HashMap<u64, Vec<u64>>) -> String {
+    let mut programs = program_conns.keys().copied().collect::<Vec<u64>>();
+    programs.sort_unstable();
+    let mut dot = String::from("graph pipes {\n");
+    for program in programs {
+        let mut pipes = program_conns[&program]
+            .iter()
+            .copied()
+            .filter(|other| *other > program)
+            .collect::<Vec<u64>>();
+        pipes.sort_unstable();
+        pipes.dedup();
+        if pipes.is_empty()
+            && program_conns[&program]
+                .iter()
+                .all(|other| *other == program)
+        {
+            dot.push_str(&format!("    {program};\n"));
+        }
+        for other in pipes {
+            dot.push_str(&format!("    {program} -- {other};\n"));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Parses one line from the input file to extract the left program and its connected right
+/// programs.
+fn parse_input_file_line(s: &str) -> Result<(u64, Vec<u64>), InputLineParseError> {
+    let (left, right) = split_tuple(s, " <-> ")?;
+    let left = parse_integer::<u64>(left)?;
+    let right = right
+        .split(',')
+        .map(|v| parse_integer::<u64>(v.trim()))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    Ok((left, right))
+}
+
+/// Parses pipe connections in the extended format where each connected program can be given a
+/// weight (e.g. "0 <-> 2:3, 4"), returning the [`WeightedGraph`] of the pipes. Programs given
+/// without a weight are connected with a weight of 1, so input in the original format is also
+/// accepted.
+///
+/// Returns an [`AocError`] giving the line number of the first line that could not be parsed.
+pub fn parse_weighted_pipes(raw_input: &str) -> Result<WeightedGraph, AocError> {
+    let mut graph = WeightedGraph::new();
+    for (left, right) in parse_lines_with(raw_input, parse_weighted_input_file_line)? {
+        graph.add_node(left);
+        for (other, weight) in right {
+            graph.add_edge(left, other, weight);
+        }
+    }
+    Ok(graph)
+}
+
+/// Parses one line of pipe connections in the extended format to extract the left program and its
+/// connected right programs, each with the weight of its pipe.
+fn parse_weighted_input_file_line(s: &str) -> Result<(u64, Vec<(u64, u64)>), InputLineParseError> {
+    let (left, right) = split_tuple(s, " <-> ")?;
+    let left = parse_integer::<u64>(left)?;
+    let right = right
+        .split(',')
+        .map(|v| match v.trim().split_once(':') {
+            Some((program, weight)) => Ok((
+                parse_integer::<u64>(program.trim())?,
+                parse_integer::<u64>(weight.trim())?,
+            )),
+            None => Ok((parse_integer::<u64>(v.trim())?, 1)),
+        })
+        .collect::<Result<Vec<(u64, u64)>, ParseError>>()?;
+    Ok((left, right))
+}
+
+/// Determines the members of the program group containing the start program.
+fn determine_program_group_members(
+    start: u64,
+    program_conns: &HashMap<u64, Vec<u64>>,
+) -> HashSet<u64> {
+    let mut visited: HashSet<u64> = HashSet::new();
+    let mut visit_queue: VecDeque<u64> = VecDeque::from([start]);
+    while !visit_queue.is_empty() {
+        let program = visit_queue.pop_front().unwrap();
+        visited.insert(program);
+        if let Some(conns) = program_conns.get(&program) {
+            for next in conns {
+                if !visited.contains(next) {
+                    visit_queue.push_back(*next);
+                }
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+    use crate::utils::testgen::program_graph;
+
+    /// Tests that the program groups found in randomly generated villages split up the programs,
+    /// with every pipe connecting two programs in the same group.
+    #[test]
+    fn test_day12_groups_generated() {
+        let raw_input = program_graph(7, 200);
+        let input = raw_input
+            .lines()
+            .map(|line| parse_input_file_line(line).unwrap())
+            .collect::<HashMap<u64, Vec<u64>>>();
+        let mut groups: Vec<Vec<u64>> = vec![];
+        for program in input.keys() {
+            let mut group = determine_program_group_members(*program, &input)
+                .into_iter()
+                .collect::<Vec<u64>>();
+            group.sort_unstable();
+            assert!(group.contains(program));
+            for other in &input[program] {
+                assert!(group.contains(other));
+            }
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        assert_eq!(200, groups.iter().map(|group| group.len()).sum::<usize>());
+        assert_eq!(groups.len(), solve_part2(&input));
+        assert!(groups.len() > 1);
+    }
+
+    /// Tests the Day 12 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day12_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(288, solution);
+    }
+
+    /// Tests the Day 12 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day12_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(211, solution);
+    }
+
+    /// Tests the DOT export of the pipes given in the problem statement example.
+    #[test]
+    fn test_day12_export_pipe_graph_dot() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let expected = "graph pipes {\n    0 -- 2;\n    1;\n    2 -- 3;\n    2 -- 4;\n    \
+            3 -- 4;\n    4 -- 6;\n    5 -- 6;\n}\n";
+        assert_eq!(expected, export_pipe_graph_dot(&input));
+    }
+
+    /// Tests that the weighted pipe parser accepts the original input format, with each pipe given
+    /// a weight of 1.
+    #[test]
+    fn test_day12_parse_weighted_pipes_actual() {
+        let raw_input = fs::read_to_string(PROBLEM_INPUT_FILE).unwrap();
+        let graph = parse_weighted_pipes(&raw_input).unwrap();
+        let expected = WeightedGraph::from_unweighted(&process_input_file(PROBLEM_INPUT_FILE));
+        assert_eq!(expected.len(), graph.len());
+        for program in expected.nodes() {
+            let mut pipes = graph.neighbours(program).to_vec();
+            let mut expected_pipes = expected.neighbours(program).to_vec();
+            pipes.sort_unstable();
+            expected_pipes.sort_unstable();
+            assert_eq!(expected_pipes, pipes);
+        }
+        assert_eq!(288, graph.distances_from(0).len());
+    }
+
+    /// Tests shortest paths through the pipes given in the problem statement example, with some of
+    /// the pipes given weights.
+    #[test]
+    fn test_day12_weighted_pipes_shortest_path() {
+        let example = "0 <-> 2\n1 <-> 1\n2 <-> 0, 3:1, 4:5\n3 <-> 2:1, 4:1\n4 <-> 2:5, 3:1, 6\n\
+            5 <-> 6\n6 <-> 4, 5";
+        let graph = parse_weighted_pipes(example).unwrap();
+        let path = graph.shortest_path(0, 5).unwrap();
+        assert_eq!(5, path.cost);
+        assert_eq!(vec![0, 2, 3, 4, 6, 5], path.nodes);
+        assert_eq!(None, graph.shortest_path(0, 1));
+        assert_eq!(
+            Err(AocError::LineParse {
+                line: 2,
+                text: String::from("1 <-> 0:x")
+            }),
+            parse_weighted_pipes("0 <-> 1\n1 <-> 0:x")
+        );
+    }
+
+    /// Tests the Day 12 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day12_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(6, solve_part1(&input));
+        assert_eq!(2, solve_part2(&input));
+    }
+}
@@ -0,0 +1,237 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::ParseError;
+use crate::utils::parse::{parse_integer, split_tuple};
+
+const PROBLEM_NAME: &str = "Packet Scanners";
+const PROBLEM_INPUT_FILE: &str = "./input/day13.txt";
+const PROBLEM_DAY: u64 = 13;
+
+/// Number of delays checked in each pass of the sieve used by the "sieve" algorithm for Part 2.
+const SIEVE_WINDOW: u64 = 1 << 16;
+
+/// Custom error type indicating that the parsing of a line from the input file has failed.
+#[derive(Debug)]
+struct InputLineParseError;
+
+impl From<ParseError> for InputLineParseError {
+    fn from(_: ParseError) -> Self {
+        InputLineParseError
+    }
+}
+
+/// Firewall made up of layers at different depths, each with a security scanner moving back and
+/// forth over its range. A packet entering the firewall after a delay reaches the layer at each
+/// depth after (delay + depth) picoseconds, and is caught if the scanner is at the top of the
+/// range at that time.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Firewall {
+    layers: Vec<(u64, u64)>,
+}
+
+impl Firewall {
+    /// Creates a new [`Firewall`] from the depth and range of each layer.
+    pub fn new(layers: &[(u64, u64)]) -> Firewall {
+        let mut layers = layers.to_vec();
+        layers.sort();
+        Firewall { layers }
+    }
+
+    /// Gets the depth and range of each layer, in order of depth.
+    pub fn layers(&self) -> &[(u64, u64)] {
+        &self.layers
+    }
+
+    /// Gets the depths of the layers that catch a packet entering the firewall after the delay (in
+    /// picoseconds), in order of depth.
+    pub fn caught_layers(&self, delay: u64) -> Vec<u64> {
+        self.layers
+            .iter()
+            .filter(|(depth, range)| (depth + delay).is_multiple_of(scanner_period(*range)))
+            .map(|(depth, _)| *depth)
+            .collect()
+    }
+
+    /// Calculates the severity of the trip for a packet entering the firewall after the delay (in
+    /// picoseconds) - the sum of the depth multiplied by the range of each layer that catches it.
+    pub fn severity_with_delay(&self, delay: u64) -> u64 {
+        self.layers
+            .iter()
+            .filter(|(depth, range)| (depth + delay).is_multiple_of(scanner_period(*range)))
+            .map(|(depth, range)| depth * range)
+            .sum()
+    }
+
+    /// Checks if a packet entering the firewall after the delay (in picoseconds) is caught by any
+    /// layer.
+    pub fn is_caught(&self, delay: u64) -> bool {
+        self.layers
+            .iter()
+            .any(|(depth, range)| (depth + delay).is_multiple_of(scanner_period(*range)))
+    }
+}
+
+impl FromIterator<(u64, u64)> for Firewall {
+    fn from_iter<T: IntoIterator<Item = (u64, u64)>>(iter: T) -> Self {
+        Firewall::new(&iter.into_iter().collect::<Vec<(u64, u64)>>())
+    }
+}
+
+/// Gets the number of picoseconds taken by the scanner in a layer with the given range to return
+/// to the top of the range. Scanners in layers with a range of 1 never leave the top.
+fn scanner_period(range: u64) -> u64 {
+    (2 * range.saturating_sub(1)).max(1)
+}
+
+/// Solver for AOC 2017 Day 13.
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Firewall;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["brute", "sieve"];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_part2_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "sieve" => Some(solve_part2_sieve(input).into()),
+            _ => Self::solve_part2(input),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 13 input file in the format required by the solver functions.
+///
+/// Returned value is the [`Firewall`] made up of the layers given in the input file.
+fn process_input_file(filename: &str) -> Firewall {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    raw_input
+        .trim()
+        .lines()
+        .filter_map(|s| parse_input_file_line(s).ok())
+        .collect::<Firewall>()
+}
+
+/// Solves AOC 2017 Day 13 Part 1.
+///
+/// Determines the severity score for the trip if there is no delay before commencement of the
+/// firewall transit.
+fn solve_part1(firewall: &Firewall) -> u64 {
+    firewall.severity_with_delay(0)
+}
+
+/// Solves AOC 2017 Day 13 Part 2.
+///
+/// Determines the total delay (in picoseconds) prior to commencement required to complete the
+/// firewall transit without being caught.
+fn solve_part2(firewall: &Firewall) -> u64 {
+    (0..).find(|&delay| !firewall.is_caught(delay)).unwrap()
+}
+
+/// Solves AOC 2017 Day 13 Part 2 using a sieve over windows of delays, instead of checking each
+/// delay against every firewall in turn.
+///
+/// For each firewall, the delays within the current window that would be caught by its detector are
+/// marked off. The first delay in the window left unmarked is the minimum safe delay.
+fn solve_part2_sieve(firewall: &Firewall) -> u64 {
+    let mut window_start = 0;
+    loop {
+        let mut caught = vec![false; SIEVE_WINDOW as usize];
+        for (depth, range) in firewall.layers() {
+            let cycle = scanner_period(*range);
+            // First delay in the window for which the detector is at the top of its range
+            let mut delay = (cycle - (depth + window_start) % cycle) % cycle;
+            while delay < SIEVE_WINDOW {
+                caught[delay as usize] = true;
+                delay += cycle;
+            }
+        }
+        if let Some(offset) = caught.iter().position(|caught| !caught) {
+            return window_start + offset as u64;
+        }
+        window_start += SIEVE_WINDOW;
+    }
+}
+
+/// Parses a single line from the input file to extract required values.
+///
+/// If Ok() is returned, the wrapped value represents the depth and range of the firewall specified
+/// by the file line. Otherwise, an [`InputLineParseError`] is returned.
+fn parse_input_file_line(s: &str) -> Result<(u64, u64), InputLineParseError> {
+    let (depth, range) = split_tuple(s, ": ")?;
+    Ok((parse_integer::<u64>(depth)?, parse_integer::<u64>(range)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 13 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day13_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(2160, solution);
+    }
+
+    /// Tests the Day 13 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day13_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(3907470, solution);
+    }
+
+    /// Tests the caught layers and severity for different delays against the example given in the
+    /// problem statement.
+    #[test]
+    fn test_day13_firewall_delays() {
+        let example = Firewall::new(&[(6, 4), (0, 3), (4, 4), (1, 2)]);
+        assert_eq!(vec![0, 6], example.caught_layers(0));
+        assert_eq!(24, example.severity_with_delay(0));
+        assert_eq!(vec![1], example.caught_layers(1));
+        assert_eq!(2, example.severity_with_delay(1));
+        assert_eq!(Vec::<u64>::new(), example.caught_layers(10));
+        assert!(!example.is_caught(10));
+        // Layer with a range of 1 always catches the packet
+        let firewall = Firewall::new(&[(3, 1)]);
+        assert!((0..5).all(|delay| firewall.caught_layers(delay) == vec![3]));
+    }
+
+    /// Tests that the "brute" and "sieve" algorithms for Day 13 Part 2 agree, using both the
+    /// example given in the problem statement and the actual problem input.
+    #[test]
+    fn test_day13_part2_algorithms_agree() {
+        let example = Firewall::new(&[(0, 3), (1, 2), (4, 4), (6, 4)]);
+        assert_eq!(10, solve_part2(&example));
+        assert_eq!(10, solve_part2_sieve(&example));
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        assert_eq!(solve_part2(&input), solve_part2_sieve(&input));
+    }
+
+    /// Tests the Day 13 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day13_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(24, solve_part1(&input));
+        assert_eq!(10, solve_part2(&input));
+    }
+}
@@ -0,0 +1,328 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Cache, Solver};
+use crate::utils::fmt::{bits_msb_first, block_grid};
+use crate::utils::knot_hash::knot_hash_bytes;
+
+const PROBLEM_NAME: &str = "Disk Defragmentation";
+const PROBLEM_INPUT_FILE: &str = "./input/day14.txt";
+const PROBLEM_DAY: u64 = 14;
+
+const DISK_GRID_MIN_X: usize = 0;
+const DISK_GRID_MIN_Y: usize = 0;
+
+/// Custom type representing the input to the problem solver functions. The row hashes generated
+/// from the key string are used by both parts, so they are cached after they are first generated
+/// (along with the disk grid built from them).
+pub struct ProblemInput {
+    key: String,
+    row_hashes: Cache<Vec<[u8; 16]>>,
+    disk_grid: Cache<Vec<Vec<char>>>,
+}
+
+impl ProblemInput {
+    /// Returns the knot hash of each row of the disk grid, generating them if it has not already
+    /// been done.
+    fn row_hashes(&self) -> &[[u8; 16]] {
+        self.row_hashes
+            .get_or_compute(|| generate_row_hashes(&self.key))
+    }
+
+    /// Returns the disk grid (128x128 grid) generated from the key string, generating it if it has
+    /// not already been done. Each square is either used ('1') or free ('0').
+    pub fn disk_grid(&self) -> &[Vec<char>] {
+        self.disk_grid
+            .get_or_compute(|| generate_disk_grid(self.row_hashes()))
+    }
+}
+
+/// Solver for AOC 2017 Day 14.
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_both(input: &Self::Input) -> Option<(Answer, Answer)> {
+        // Both parts use the knot hashes of the rows, which are only generated once
+        Some((solve_part1(input).into(), solve_part2(input).into()))
+    }
+
+    fn visualise(input: &Self::Input) -> Option<String> {
+        Some(block_grid(
+            input.row_hashes().iter().map(|hash| bits_msb_first(hash)),
+        ))
+    }
+}
+
+/// Processes the AOC 2017 Day 14 input file in the format required by the solver functions.
+///
+/// Returned value is [`ProblemInput`] holding the key string given in the input file.
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let key = fs::read_to_string(filename).unwrap().trim().to_string();
+    ProblemInput {
+        key,
+        row_hashes: Cache::new(),
+        disk_grid: Cache::new(),
+    }
+}
+
+/// Solves AOC 2017 Day 14 Part 1.
+///
+/// Determines the number of squares used in the disk grid, with rows based on knot hash
+/// calculations. Each used square is a set bit in the hash of its row, so the bits are counted
+/// directly without building the disk grid.
+fn solve_part1(input: &ProblemInput) -> usize {
+    input
+        .row_hashes()
+        .iter()
+        .flatten()
+        .map(|byte| byte.count_ones() as usize)
+        .sum()
+}
+
+/// Solves AOC 2017 Day 14 Part 2.
+///
+/// Determines the number of regions present in the disk grid.
+fn solve_part2(input: &ProblemInput) -> usize {
+    let region_labels = label_disk_grid_regions(input.disk_grid());
+    region_labels
+        .iter()
+        .flatten()
+        .flatten()
+        .max()
+        .map_or(0, |label| label + 1)
+}
+
+/// Generates the knot hash of each row of the disk grid, given by the key string and row number.
+fn generate_row_hashes(key: &str) -> Vec<[u8; 16]> {
+    (0..=127)
+        .map(|v| knot_hash_bytes(&format!("{key}-{v}")))
+        .collect::<Vec<[u8; 16]>>()
+}
+
+/// Generates the disk grid (128x128 grid) from the row hashes, with each bit of a row hash (most
+/// significant bit first) giving a used ('1') or free ('0') square.
+fn generate_disk_grid(row_hashes: &[[u8; 16]]) -> Vec<Vec<char>> {
+    row_hashes
+        .iter()
+        .map(|hash| {
+            bits_msb_first(hash)
+                .map(|bit| if bit { '1' } else { '0' })
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>()
+}
+
+/// Labels the regions of used squares in the disk grid (such as [`ProblemInput::disk_grid`]), where
+/// a region is a group of used ('1') squares connected horizontally or vertically.
+///
+/// Returned grid has the same shape as the disk grid. Each used square holds the label of its
+/// region, and each free square holds None. Regions are labelled 0, 1, 2 etc. in the order their
+/// first square is found when reading the grid row by row from the top left, so the number of
+/// regions is one more than the largest label.
+///
+/// Labels are found with a two-pass connected-component algorithm. The first pass gives each used
+/// square a provisional label, merging the labels of its used neighbours above and to the left. The
+/// second pass replaces each provisional label with the label of its merged set.
+pub fn label_disk_grid_regions(disk_grid: &[Vec<char>]) -> Vec<Vec<Option<usize>>> {
+    let mut labels: Vec<Vec<Option<usize>>> =
+        disk_grid.iter().map(|row| vec![None; row.len()]).collect();
+    let mut label_sets = DisjointSet::new();
+    // First pass - assign provisional labels and record which labels are connected
+    for (y, row) in disk_grid.iter().enumerate() {
+        for (x, square) in row.iter().enumerate() {
+            if *square != '1' {
+                continue;
+            }
+            // Rows may differ in length, so a missing square above is treated as unlabelled
+            let above = if y > DISK_GRID_MIN_Y {
+                labels[y - 1].get(x).copied().flatten()
+            } else {
+                None
+            };
+            let left = if x > DISK_GRID_MIN_X {
+                labels[y][x - 1]
+            } else {
+                None
+            };
+            labels[y][x] = match (above, left) {
+                (Some(a), Some(l)) => {
+                    label_sets.union(a, l);
+                    Some(a)
+                }
+                (Some(a), None) => Some(a),
+                (None, Some(l)) => Some(l),
+                (None, None) => Some(label_sets.make_set()),
+            };
+        }
+    }
+    // Second pass - resolve provisional labels to sequential region labels
+    let mut region_labels: Vec<Option<usize>> = vec![None; label_sets.len()];
+    let mut region_count = 0;
+    for label in labels.iter_mut().flatten().flatten() {
+        let root = label_sets.find(*label);
+        *label = *region_labels[root].get_or_insert_with(|| {
+            region_count += 1;
+            region_count - 1
+        });
+    }
+    labels
+}
+
+/// Disjoint-set (union-find) structure over the labels 0..n, using path halving and union by size.
+struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSet {
+    /// Creates a new empty [`DisjointSet`].
+    fn new() -> DisjointSet {
+        DisjointSet {
+            parents: vec![],
+            sizes: vec![],
+        }
+    }
+
+    /// Gets the number of labels held in the disjoint set.
+    fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Adds a new label in its own set, returning the new label.
+    fn make_set(&mut self) -> usize {
+        let label = self.parents.len();
+        self.parents.push(label);
+        self.sizes.push(1);
+        label
+    }
+
+    /// Finds the root label of the set containing the given label.
+    fn find(&mut self, label: usize) -> usize {
+        let mut label = label;
+        while self.parents[label] != label {
+            self.parents[label] = self.parents[self.parents[label]];
+            label = self.parents[label];
+        }
+        label
+    }
+
+    /// Merges the sets containing the two labels.
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return;
+        }
+        let (big, small) = if self.sizes[root_a] >= self.sizes[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parents[small] = big;
+        self.sizes[big] += self.sizes[small];
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 14 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day14_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(8190, solution);
+    }
+
+    /// Tests the Day 14 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day14_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(1134, solution);
+    }
+
+    /// Tests the region labelling against a small grid, including a region whose provisional labels
+    /// are only merged when the bottom row is reached.
+    #[test]
+    fn test_label_disk_grid_regions() {
+        let disk_grid = ["1101", "0101", "1111", "0000", "1000"]
+            .iter()
+            .map(|row| row.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let labels = label_disk_grid_regions(&disk_grid);
+        let expected = [
+            [Some(0), Some(0), None, Some(0)],
+            [None, Some(0), None, Some(0)],
+            [Some(0), Some(0), Some(0), Some(0)],
+            [None, None, None, None],
+            [Some(1), None, None, None],
+        ];
+        assert_eq!(expected.map(|row| row.to_vec()).to_vec(), labels);
+        assert!(label_disk_grid_regions(&[]).is_empty());
+    }
+
+    /// Tests that rows of different lengths are labelled without panicking, with squares beyond
+    /// the end of the row above treated as unlabelled.
+    #[test]
+    fn test_label_disk_grid_regions_ragged() {
+        let disk_grid = ["11", "0111", "", "1", "11"]
+            .iter()
+            .map(|row| row.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let labels = label_disk_grid_regions(&disk_grid);
+        let expected = vec![
+            vec![Some(0), Some(0)],
+            vec![None, Some(0), Some(0), Some(0)],
+            vec![],
+            vec![Some(1)],
+            vec![Some(1), Some(1)],
+        ];
+        assert_eq!(expected, labels);
+    }
+
+    /// Tests the used squares and regions of the example key given in the problem statement.
+    #[test]
+    fn test_day14_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let top_left = input.disk_grid()[..2]
+            .iter()
+            .map(|row| row[..8].iter().collect::<String>())
+            .collect::<Vec<String>>();
+        assert_eq!(vec!["11010100", "01010101"], top_left);
+        let visualised = Day14::visualise(&input).unwrap();
+        assert_eq!(128, visualised.lines().count());
+        assert!(visualised.starts_with("██·█·█··"));
+        assert!(visualised.lines().nth(1).unwrap().starts_with("·█·█·█·█"));
+        assert_eq!(8108, solve_part1(&input));
+        assert_eq!(1242, solve_part2(&input));
+    }
+
+    /// Tests that solving both parts together gives the same solutions as the separate solvers.
+    #[test]
+    fn test_day14_solve_both() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let (p1_solution, p2_solution) = Day14::solve_both(&input).unwrap();
+        assert_eq!(Answer::from(8108usize), p1_solution);
+        assert_eq!(Answer::from(1242usize), p2_solution);
+    }
+}
@@ -0,0 +1,374 @@
+use std::fs;
+use std::num::NonZeroUsize;
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+use std::thread;
+
+#[cfg(feature = "regex")]
+use fancy_regex::Regex;
+
+use crate::scaffold::{Answer, Parameter, Parameters, Solver};
+use crate::utils::math::{mod_mul, mod_pow};
+#[cfg(not(feature = "regex"))]
+use crate::utils::parse::parse_integer;
+
+const PROBLEM_NAME: &str = "Dueling Generators";
+const PROBLEM_INPUT_FILE: &str = "./input/day15.txt";
+const PROBLEM_DAY: u64 = 15;
+
+/// Number of generator rounds conducted in problem part 1
+const PART1_ROUNDS: Parameter = Parameter {
+    name: "part1-rounds",
+    default: 40_000_000,
+    description: "pairs of values compared in part 1",
+};
+/// Number of generator rounds conducted in problem part 2
+const PART2_ROUNDS: Parameter = Parameter {
+    name: "part2-rounds",
+    default: 5_000_000,
+    description: "pairs of values compared in part 2",
+};
+/// Factor used by the A generator
+const GEN_A_FACTOR: u64 = 16_807;
+/// Factor used by the B generator
+const GEN_B_FACTOR: u64 = 48_271;
+/// Modulus value used by both generators
+const GEN_MODULUS: u64 = 2_147_483_647;
+
+/// Custom error type indicating that the parsing of the input file has failed.
+#[derive(Debug)]
+struct InputFileParseError;
+
+/// Custom type representing the input to the problem solver functions.
+#[derive(Clone, Debug)]
+pub struct ProblemInput {
+    /// Start value for the A generator.
+    gen_a_start: u64,
+    /// Start value for the B generator.
+    gen_b_start: u64,
+    /// Number of generator rounds conducted in part 1.
+    part1_rounds: u64,
+    /// Number of generator rounds conducted in part 2.
+    part2_rounds: u64,
+}
+
+/// Value generator used in the AOC 2017 Day 15 problem - a linear congruential generator (LCG)
+/// that multiplies its value by a factor on each step, modulo the modulus. Only values passing the
+/// check function are returned by the iterator.
+///
+/// Values are multiplied without overflowing for any factor and modulus, not just those used by the
+/// problem.
+pub struct LcgGenerator {
+    value: u64,
+    factor: u64,
+    modulus: u64,
+    check_fn: fn(u64) -> bool,
+}
+
+impl LcgGenerator {
+    /// Creates a new LcgGenerator.
+    pub fn new(value: u64, factor: u64, modulus: u64, check_fn: fn(u64) -> bool) -> LcgGenerator {
+        LcgGenerator {
+            value,
+            factor,
+            modulus,
+            check_fn,
+        }
+    }
+
+    /// Calculates the value held by the generator after the given number of steps, without
+    /// advancing the generator. Uses modular exponentiation, so takes O(log n) time.
+    ///
+    /// Every step is counted, including those producing values rejected by the check function.
+    pub fn value_after(&self, steps: u64) -> u64 {
+        mod_mul(
+            self.value,
+            mod_pow(self.factor, steps, self.modulus),
+            self.modulus,
+        )
+    }
+
+    /// Advances the generator by the given number of steps in O(log n) time (see
+    /// [`LcgGenerator::value_after`]). Named to avoid being shadowed by [`Iterator::skip`].
+    pub fn fast_forward(&mut self, steps: u64) {
+        self.value = self.value_after(steps);
+    }
+}
+
+impl Iterator for LcgGenerator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.value = mod_mul(self.value, self.factor, self.modulus);
+            if (self.check_fn)(self.value) {
+                return Some(self.value);
+            }
+        }
+    }
+}
+
+/// Solver for AOC 2017 Day 15.
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["sequential", "parallel"];
+    const PARAMETERS: &'static [Parameter] = &[PART1_ROUNDS, PART2_ROUNDS];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn apply_parameters(input: &mut Self::Input, params: &Parameters) {
+        input.part1_rounds = params.get(&PART1_ROUNDS);
+        input.part2_rounds = params.get(&PART2_ROUNDS);
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_part1_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "parallel" => Some(solve_part1_parallel(input).into()),
+            _ => Self::solve_part1(input),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 15 input file in the format required by the solver functions.
+///
+/// Returned value is a [`ProblemInput`] containing the start values for the A and B generators,
+/// along with the default number of rounds for each part.
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_string(&raw_input).unwrap()
+}
+
+/// Solves AOC 2017 Day 15 Part 1.
+///
+/// Determines the number of matching values from the A and B generators after 40 million pairs of
+/// values have been generated.
+fn solve_part1(input: &ProblemInput) -> u64 {
+    let mut gen_a = LcgGenerator::new(input.gen_a_start, GEN_A_FACTOR, GEN_MODULUS, |_| true);
+    let mut gen_b = LcgGenerator::new(input.gen_b_start, GEN_B_FACTOR, GEN_MODULUS, |_| true);
+    count_matching_value_pairs(&mut gen_a, &mut gen_b, input.part1_rounds)
+}
+
+/// Solves AOC 2017 Day 15 Part 1 by splitting the rounds into chunks that are matched in parallel.
+/// The generators for each chunk are fast-forwarded to the start of the chunk.
+fn solve_part1_parallel(input: &ProblemInput) -> u64 {
+    let (gen_a_start, gen_b_start) = (input.gen_a_start, input.gen_b_start);
+    let total_rounds = input.part1_rounds;
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get) as u64;
+    let chunk_len = total_rounds.div_ceil(threads).max(1);
+    thread::scope(|scope| {
+        let handles = (0..total_rounds)
+            .step_by(chunk_len as usize)
+            .map(|chunk_start| {
+                scope.spawn(move || {
+                    let mut gen_a =
+                        LcgGenerator::new(gen_a_start, GEN_A_FACTOR, GEN_MODULUS, |_| true);
+                    let mut gen_b =
+                        LcgGenerator::new(gen_b_start, GEN_B_FACTOR, GEN_MODULUS, |_| true);
+                    gen_a.fast_forward(chunk_start);
+                    gen_b.fast_forward(chunk_start);
+                    let rounds = chunk_len.min(total_rounds - chunk_start);
+                    count_matching_value_pairs(&mut gen_a, &mut gen_b, rounds)
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+/// Solves AOC 2017 Day 15 Part 2.
+///
+/// Determines the number of matching values from the A and B generators after 5 million pairs, with
+/// each generator using a non-trivial value-checking function.
+fn solve_part2(input: &ProblemInput) -> u64 {
+    let mut gen_a = LcgGenerator::new(input.gen_a_start, GEN_A_FACTOR, GEN_MODULUS, |v| v % 4 == 0);
+    let mut gen_b = LcgGenerator::new(input.gen_b_start, GEN_B_FACTOR, GEN_MODULUS, |v| v % 8 == 0);
+    count_matching_value_pairs(&mut gen_a, &mut gen_b, input.part2_rounds)
+}
+
+/// Parses the contents of the input file and returns the values needed by the solution functions.
+///
+/// If the input file string is correctly formatted, a [`ProblemInput`] containing the start values
+/// for the A and B generators (and the default number of rounds) is returned. Otherwise, an
+/// [`InputFileParseError`] is returned.
+fn parse_input_string(s: &str) -> Result<ProblemInput, InputFileParseError> {
+    let (gen_a_start, gen_b_start) = parse_start_values(s).ok_or(InputFileParseError)?;
+    Ok(ProblemInput {
+        gen_a_start,
+        gen_b_start,
+        part1_rounds: PART1_ROUNDS.default,
+        part2_rounds: PART2_ROUNDS.default,
+    })
+}
+
+/// Finds the start values of the A and B generators, given on the lines "Generator A starts with
+/// <N>" and "Generator B starts with <N>" (in that order). Returns None if either line is missing.
+#[cfg(feature = "regex")]
+fn parse_start_values(s: &str) -> Option<(u64, u64)> {
+    static INPUT_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = INPUT_REGEX.get_or_init(|| {
+        Regex::new(r"(?ms)^Generator A starts with (\d+)$.*^Generator B starts with (\d+)$")
+            .unwrap()
+    });
+    let caps = regex.captures(s).ok()??;
+    Some((caps[1].parse::<u64>().ok()?, caps[2].parse::<u64>().ok()?))
+}
+
+/// Finds the start values of the A and B generators, given on the lines "Generator A starts with
+/// <N>" and "Generator B starts with <N>" (in that order). Returns None if either line is missing.
+#[cfg(not(feature = "regex"))]
+fn parse_start_values(s: &str) -> Option<(u64, u64)> {
+    let mut lines = s.lines();
+    let mut find_start =
+        |prefix: &str| lines.find_map(|line| parse_integer::<u64>(line.strip_prefix(prefix)?).ok());
+    let gen_a_start = find_start("Generator A starts with ")?;
+    let gen_b_start = find_start("Generator B starts with ")?;
+    Some((gen_a_start, gen_b_start))
+}
+
+/// Counts the number of matching value pairs return by the two generators after the specified
+/// number of rounds have been conducted (see [`matching_rounds`]).
+///
+/// The count is held as a u64 (like the number of rounds), so it cannot overflow even where usize
+/// is narrower.
+fn count_matching_value_pairs(
+    gen_a: &mut LcgGenerator,
+    gen_b: &mut LcgGenerator,
+    total_rounds: u64,
+) -> u64 {
+    matching_rounds(gen_a, gen_b, total_rounds).fold(0, |matches, _| matches + 1)
+}
+
+/// Gets an iterator over the rounds (numbered from 1) in which the two generators return matching
+/// values, out of the specified number of rounds. Only the lowest 16 bits of the values returned
+/// by the generators need to be the same for a match to be recorded.
+///
+/// Rounds are generated lazily, so the matches can be streamed (or limited with
+/// [`Iterator::take`]) rather than collected, to analyse how they are distributed over the rounds.
+pub fn matching_rounds<'a>(
+    gen_a: &'a mut LcgGenerator,
+    gen_b: &'a mut LcgGenerator,
+    total_rounds: u64,
+) -> impl Iterator<Item = u64> + 'a {
+    (1..=total_rounds).filter(move |_| {
+        // Get the next value from both generators
+        let gen_a_value = gen_a.next().unwrap();
+        let gen_b_value = gen_b.next().unwrap();
+        // Compare the lowest 16 bits of the generator values
+        gen_a_value & 0xffff == gen_b_value & 0xffff
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example;
+
+    /// Tests the Day 15 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day15_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(594, solution);
+    }
+
+    /// Tests that the parallel Day 15 Part 1 solver method agrees with the actual problem solution.
+    #[test]
+    fn test_day15_part1_parallel() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        assert_eq!(594, solve_part1_parallel(&input));
+    }
+
+    /// Tests that skipping ahead gives the same values as stepping the generator, using the example
+    /// given in the problem statement.
+    #[test]
+    fn test_day15_generator_skip() {
+        let mut gen_a = LcgGenerator::new(65, GEN_A_FACTOR, GEN_MODULUS, |_| true);
+        assert_eq!(1352636452, gen_a.value_after(5));
+        let values = (0..5).map(|_| gen_a.next().unwrap()).collect::<Vec<u64>>();
+        assert_eq!(
+            vec![1092455, 1181022009, 245556042, 1744312007, 1352636452],
+            values
+        );
+        let mut gen_b = LcgGenerator::new(8921, GEN_B_FACTOR, GEN_MODULUS, |_| true);
+        gen_b.fast_forward(4);
+        assert_eq!(Some(285222916), gen_b.next());
+        let mut gen_c = LcgGenerator::new(8921, GEN_B_FACTOR, GEN_MODULUS, |_| true);
+        gen_c.fast_forward(1000);
+        assert_eq!(gen_b.nth(994), Some(gen_c.value));
+        // Products of values and factors exceeding u64 are reduced without overflowing
+        let mut gen_d = LcgGenerator::new(u64::MAX - 2, u64::MAX - 2, u64::MAX, |_| true);
+        assert_eq!(gen_d.value_after(3), gen_d.nth(2).unwrap());
+    }
+
+    /// Tests the Day 15 solver methods with the number of rounds overridden, using the example
+    /// given in the problem statement.
+    #[test]
+    fn test_day15_parameters_example() {
+        let mut input = parse_input_string(&example(PROBLEM_DAY, 1)).unwrap();
+        let mut params = Parameters::new();
+        params.set("part1-rounds", 5);
+        params.set("part2-rounds", 1056);
+        Day15::apply_parameters(&mut input, &params);
+        assert_eq!(1, solve_part1(&input));
+        assert_eq!(1, solve_part1_parallel(&input));
+        assert_eq!(1, solve_part2(&input));
+    }
+
+    /// Tests the rounds in which the generators match against the example given in the problem
+    /// statement - the third pair in part 1, and the 1056th pair in part 2.
+    #[test]
+    fn test_day15_matching_rounds_example() {
+        let input = parse_input_string(&example(PROBLEM_DAY, 1)).unwrap();
+        let gen = |start, factor, check_fn| LcgGenerator::new(start, factor, GEN_MODULUS, check_fn);
+        let mut gen_a = gen(input.gen_a_start, GEN_A_FACTOR, |_| true);
+        let mut gen_b = gen(input.gen_b_start, GEN_B_FACTOR, |_| true);
+        let rounds = matching_rounds(&mut gen_a, &mut gen_b, 5).collect::<Vec<u64>>();
+        assert_eq!(vec![3], rounds);
+        // Streaming stops once enough matches have been taken, leaving the generators part way
+        let mut gen_a = gen(input.gen_a_start, GEN_A_FACTOR, |_| true);
+        let mut gen_b = gen(input.gen_b_start, GEN_B_FACTOR, |_| true);
+        let first = matching_rounds(&mut gen_a, &mut gen_b, PART1_ROUNDS.default)
+            .take(2)
+            .collect::<Vec<u64>>();
+        assert_eq!(3, first[0]);
+        assert_eq!(
+            gen_a.value,
+            gen(input.gen_a_start, GEN_A_FACTOR, |_| true).value_after(first[1])
+        );
+        let mut gen_a = gen(input.gen_a_start, GEN_A_FACTOR, |v| v % 4 == 0);
+        let mut gen_b = gen(input.gen_b_start, GEN_B_FACTOR, |v| v % 8 == 0);
+        assert_eq!(
+            Some(1056),
+            matching_rounds(&mut gen_a, &mut gen_b, PART2_ROUNDS.default).next()
+        );
+    }
+
+    /// Tests the Day 15 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day15_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(328, solution);
+    }
+}
@@ -0,0 +1,477 @@
+use std::fmt;
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::error::ParseError;
+use crate::utils::parse::{expect_end, expect_literal, parse_integer, take_char, take_integer};
+
+const PROBLEM_NAME: &str = "Permutation Promenade";
+const PROBLEM_INPUT_FILE: &str = "./input/day16.txt";
+const PROBLEM_DAY: u64 = 16;
+/// Number of programs taking part in the dance (labelled 'a' to 'p').
+const PROGRAM_COUNT: usize = 16;
+/// Largest number of programs that can take part in a dance, with one lowercase letter each.
+const MAX_PROGRAM_COUNT: usize = 26;
+/// Total number of rounds needed for problem part 2.
+const PART2_ROUNDS: usize = 1_000_000_000;
+
+/// Custom error type indicating that the parsing of a line from the input file has failed.
+#[derive(Debug)]
+struct InputLineParseError;
+
+impl From<ParseError> for InputLineParseError {
+    fn from(_: ParseError) -> Self {
+        InputLineParseError
+    }
+}
+
+/// Custom error type indicating that a dance cannot be danced from the given start order.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DanceError {
+    /// No programs are taking part in the dance.
+    EmptyOrder,
+    /// The start order holds something other than a lowercase letter.
+    InvalidProgram { program: char },
+    /// The start order holds the same program more than once.
+    DuplicateProgram { program: char },
+    /// An exchange move refers to a position past the end of the line.
+    InvalidPosition { index: usize, position: usize },
+    /// A partner move refers to a program not taking part in the dance.
+    MissingProgram { index: usize, program: char },
+}
+
+impl fmt::Display for DanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DanceError::EmptyOrder => write!(f, "No programs in start order"),
+            DanceError::InvalidProgram { program } => {
+                write!(f, "Invalid program '{program}' in start order")
+            }
+            DanceError::DuplicateProgram { program } => {
+                write!(
+                    f,
+                    "Program '{program}' appears more than once in start order"
+                )
+            }
+            DanceError::InvalidPosition { index, position } => {
+                write!(
+                    f,
+                    "Dance move {index} refers to invalid position {position}"
+                )
+            }
+            DanceError::MissingProgram { index, program } => {
+                write!(
+                    f,
+                    "Dance move {index} refers to program '{program}' not in start order"
+                )
+            }
+        }
+    }
+}
+
+/// Enum representing the different dance moves that can reorder the programs.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DanceMove {
+    Spin { steps: usize },
+    Exchange { a: usize, b: usize },
+    Partner { a: char, b: char },
+}
+
+/// Dance made up of rounds of the same sequence of dance moves.
+#[derive(Copy, Clone)]
+pub struct Dance<'a> {
+    moves: &'a [DanceMove],
+}
+
+impl<'a> Dance<'a> {
+    /// Creates a new [`Dance`] in which each round is made up of the given dance moves.
+    pub fn new(moves: &'a [DanceMove]) -> Dance<'a> {
+        Dance { moves }
+    }
+
+    /// Returns an (endless) iterator yielding the program order after each full round of the dance,
+    /// starting from the given order. Rounds are only danced as the iterator is advanced.
+    ///
+    /// Returns a [`DanceError`] if the start order is not made up of distinct lowercase letters, or
+    /// if any of the dance moves refer to positions or programs not in the start order.
+    pub fn iter_rounds(&self, start_order: &str) -> Result<DanceRounds<'a>, DanceError> {
+        self.check_start_order(start_order)?;
+        Ok(DanceRounds {
+            moves: self.moves,
+            line: DanceLine::new(start_order),
+        })
+    }
+
+    /// Checks that the dance can be danced from the start order.
+    fn check_start_order(&self, start_order: &str) -> Result<(), DanceError> {
+        if start_order.is_empty() {
+            return Err(DanceError::EmptyOrder);
+        }
+        for (i, program) in start_order.chars().enumerate() {
+            if !program.is_ascii_lowercase() {
+                return Err(DanceError::InvalidProgram { program });
+            }
+            if start_order.chars().take(i).any(|p| p == program) {
+                return Err(DanceError::DuplicateProgram { program });
+            }
+        }
+        let len = start_order.len();
+        for (index, dance_move) in self.moves.iter().enumerate() {
+            match *dance_move {
+                DanceMove::Spin { .. } => (),
+                DanceMove::Exchange { a, b } => {
+                    if let Some(position) = [a, b].into_iter().find(|&p| p >= len) {
+                        return Err(DanceError::InvalidPosition { index, position });
+                    }
+                }
+                DanceMove::Partner { a, b } => {
+                    if let Some(program) = [a, b].into_iter().find(|&p| !start_order.contains(p)) {
+                        return Err(DanceError::MissingProgram { index, program });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the program orders after each round of a [`Dance`], created by
+/// [`Dance::iter_rounds`].
+pub struct DanceRounds<'a> {
+    moves: &'a [DanceMove],
+    line: DanceLine,
+}
+
+impl Iterator for DanceRounds<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.perform(self.moves);
+        Some(self.line.order())
+    }
+}
+
+/// Solver for AOC 2017 Day 16.
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Vec<DanceMove>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 16 input file in the format required by the solver functions.
+///
+/// Returned value is vector of [`DanceMove`] variants extracted from the comma-separated input
+/// file.
+fn process_input_file(filename: &str) -> Vec<DanceMove> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_file_content(&raw_input, PROGRAM_COUNT).unwrap()
+}
+
+/// Solves AOC 2017 Day 16 Part 1.
+///
+/// Determines the program order after all dance moves have been executed.
+fn solve_part1(dance_moves: &[DanceMove]) -> String {
+    execute_dance_moves(dance_moves, &starting_order(PROGRAM_COUNT))
+}
+
+/// Solves AOC 2017 Day 16 Part 2.
+///
+/// Determines the program order after one billion rounds of dance moves are executed.
+fn solve_part2(dance_moves: &[DanceMove]) -> String {
+    execute_dance_rounds(dance_moves, PROGRAM_COUNT, PART2_ROUNDS)
+}
+
+/// Gets the order of the given number of programs at the start of the dance ('a', 'b', 'c' etc.).
+fn starting_order(program_count: usize) -> String {
+    (b'a'..).take(program_count).map(char::from).collect()
+}
+
+/// Determines the program order after the given number of rounds of dance moves, starting from the
+/// starting order of the given number of programs.
+///
+/// Each round applies the same permutation, so the programs return to the starting order after some
+/// number of rounds (the cycle length). Only the rounds in the first cycle are danced.
+fn execute_dance_rounds(dance_moves: &[DanceMove], program_count: usize, rounds: usize) -> String {
+    let start = starting_order(program_count);
+    let mut orders = vec![start.clone()];
+    orders.extend(
+        Dance::new(dance_moves)
+            .iter_rounds(&start)
+            .expect("dance moves checked against program count when parsed")
+            .take_while(|order| *order != start),
+    );
+    orders.swap_remove(rounds % orders.len())
+}
+
+/// Parses the content of the input file to generate the data structure needed as input to the
+/// problem solver functions.
+///
+/// If the input file is correctly formatted, a vector of [`DanceMove`] variants is returned.
+/// Otherwise, an [`InputLineParseError`] is returned. Exchange positions and partner labels are
+/// checked against the number of programs taking part in the dance.
+fn parse_input_file_content(
+    s: &str,
+    program_count: usize,
+) -> Result<Vec<DanceMove>, InputLineParseError> {
+    if !(1..=MAX_PROGRAM_COUNT).contains(&program_count) {
+        return Err(InputLineParseError);
+    }
+    let mut dance_moves: Vec<DanceMove> = vec![];
+    for element in s.trim().split(',') {
+        dance_moves.push(parse_dance_move(element, program_count)?);
+    }
+    Ok(dance_moves)
+}
+
+/// Parses a single dance move (e.g. "s1", "x3/4" or "pe/b") from the input file, for a dance with
+/// the given number of programs.
+fn parse_dance_move(s: &str, program_count: usize) -> Result<DanceMove, InputLineParseError> {
+    let (kind, rest) = take_char(s)?;
+    match kind {
+        's' => Ok(DanceMove::Spin {
+            steps: parse_integer::<usize>(rest)?,
+        }),
+        'x' => {
+            let (a, rest) = take_integer::<usize>(rest)?;
+            let b = parse_integer::<usize>(expect_literal(rest, "/")?)?;
+            if a >= program_count || b >= program_count {
+                return Err(InputLineParseError);
+            }
+            Ok(DanceMove::Exchange { a, b })
+        }
+        'p' => {
+            let (a, rest) = take_char(rest)?;
+            let (b, rest) = take_char(expect_literal(rest, "/")?)?;
+            expect_end(rest)?;
+            let order = starting_order(program_count);
+            if !order.contains(a) || !order.contains(b) {
+                return Err(InputLineParseError);
+            }
+            Ok(DanceMove::Partner { a, b })
+        }
+        _ => Err(InputLineParseError),
+    }
+}
+
+/// Executes a single round of dance moves, reordering the programs as required.
+fn execute_dance_moves(dance_moves: &[DanceMove], program_starting_order: &str) -> String {
+    let mut dance_line = DanceLine::new(program_starting_order);
+    dance_line.perform(dance_moves);
+    dance_line.order()
+}
+
+/// Line of programs taking part in the dance.
+///
+/// Spins move the offset of the start of the line rather than rotating the programs, and the slot
+/// holding each program is recorded in a lookup table, so each dance move takes constant time.
+struct DanceLine {
+    /// Program held in each slot.
+    programs: Vec<char>,
+    /// Slot holding each program, indexed by the program's offset from 'a'.
+    slots: [usize; MAX_PROGRAM_COUNT],
+    /// Slot holding the program at the start of the line.
+    offset: usize,
+}
+
+impl DanceLine {
+    /// Creates a new [`DanceLine`] with the programs (lowercase letters) in the given order.
+    fn new(order: &str) -> DanceLine {
+        let programs = order.chars().collect::<Vec<char>>();
+        let mut slots = [0; MAX_PROGRAM_COUNT];
+        for (slot, p) in programs.iter().enumerate() {
+            slots[program_index(*p)] = slot;
+        }
+        DanceLine {
+            programs,
+            slots,
+            offset: 0,
+        }
+    }
+
+    /// Performs the dance moves in order.
+    fn perform(&mut self, dance_moves: &[DanceMove]) {
+        for dance in dance_moves {
+            match dance {
+                DanceMove::Spin { steps } => self.spin(*steps),
+                DanceMove::Exchange { a, b } => self.exchange(*a, *b),
+                DanceMove::Partner { a, b } => self.partner(*a, *b),
+            }
+        }
+    }
+
+    /// Moves the given number of programs from the end of the line to the front.
+    fn spin(&mut self, steps: usize) {
+        let len = self.programs.len();
+        self.offset = (self.offset + len - steps % len) % len;
+    }
+
+    /// Swaps the programs at the given positions in the line.
+    fn exchange(&mut self, a: usize, b: usize) {
+        let len = self.programs.len();
+        self.swap_slots((self.offset + a) % len, (self.offset + b) % len);
+    }
+
+    /// Swaps the positions of the given programs.
+    fn partner(&mut self, a: char, b: char) {
+        self.swap_slots(self.slots[program_index(a)], self.slots[program_index(b)]);
+    }
+
+    /// Returns the order of the programs, starting from the front of the line.
+    fn order(&self) -> String {
+        let len = self.programs.len();
+        (0..len)
+            .map(|i| self.programs[(self.offset + i) % len])
+            .collect::<String>()
+    }
+
+    /// Swaps the programs held in the slots, keeping the lookup table up to date.
+    fn swap_slots(&mut self, slot_a: usize, slot_b: usize) {
+        self.programs.swap(slot_a, slot_b);
+        self.slots[program_index(self.programs[slot_a])] = slot_a;
+        self.slots[program_index(self.programs[slot_b])] = slot_b;
+    }
+}
+
+/// Gets the index of the program in the [`DanceLine`] lookup table.
+fn program_index(p: char) -> usize {
+    (p as u8 - b'a') as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 16 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day16_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!("pkgnhomelfdibjac", solution);
+    }
+
+    /// Tests the Day 16 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day16_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!("pogbjfihclkemadn", solution);
+    }
+
+    /// Tests a round of dance moves against the example given in the problem statement.
+    #[test]
+    fn test_day16_execute_dance_moves_example() {
+        let dance_moves = parse_input_file_content("s1,x3/4,pe/b", 5).unwrap();
+        assert_eq!("baedc", execute_dance_moves(&dance_moves, "abcde"));
+        assert_eq!("ceadb", execute_dance_moves(&dance_moves, "baedc"));
+        assert_eq!("ceadb", execute_dance_rounds(&dance_moves, 5, 2));
+        assert_eq!("abcde", execute_dance_rounds(&dance_moves, 5, 0));
+        let rounds = Dance::new(&dance_moves)
+            .iter_rounds("abcde")
+            .unwrap()
+            .take(4)
+            .collect::<Vec<String>>();
+        assert_eq!(vec!["baedc", "ceadb", "ecbda", "abcde"], rounds);
+        assert_eq!("baedc", execute_dance_rounds(&dance_moves, 5, 5));
+    }
+
+    /// Tests that the cycle length of the actual dance, found from the orders after each round,
+    /// gives the order after one billion rounds from the remainder of the rounds in the last cycle.
+    #[test]
+    fn test_day16_cycle_length_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let start = starting_order(PROGRAM_COUNT);
+        let dance = Dance::new(&input);
+        let cycle_length = 1 + dance
+            .iter_rounds(&start)
+            .unwrap()
+            .position(|order| order == start)
+            .unwrap();
+        assert!(cycle_length < PART2_ROUNDS);
+        let remainder = PART2_ROUNDS % cycle_length;
+        assert_ne!(0, remainder);
+        let order = dance
+            .iter_rounds(&start)
+            .unwrap()
+            .nth(remainder - 1)
+            .unwrap();
+        assert_eq!("pogbjfihclkemadn", order);
+        assert_eq!(
+            order,
+            execute_dance_rounds(&input, PROGRAM_COUNT, PART2_ROUNDS)
+        );
+        assert_eq!(
+            order,
+            execute_dance_rounds(&input, PROGRAM_COUNT, remainder + 7 * cycle_length)
+        );
+    }
+
+    /// Tests that exchange positions and partner labels outside of the dance are rejected.
+    #[test]
+    fn test_day16_parse_program_count() {
+        assert!(parse_input_file_content("s1,x0/3,pd/b", 4).is_ok());
+        assert!(parse_input_file_content("s1,x3/4,pd/b", 4).is_err());
+        assert!(parse_input_file_content("s1,x0/3,pe/b", 4).is_err());
+        assert!(parse_input_file_content("s1", 0).is_err());
+        assert!(parse_input_file_content("s1", 27).is_err());
+    }
+
+    /// Tests that start orders which the dance moves cannot be danced from are rejected.
+    #[test]
+    fn test_day16_iter_rounds_invalid_order() {
+        let dance_moves = parse_input_file_content("s1,x3/4,pe/b", 5).unwrap();
+        let dance = Dance::new(&dance_moves);
+        let error = |order: &str| dance.iter_rounds(order).err();
+        assert_eq!(None, error("edcba"));
+        assert_eq!(None, Dance::new(&[]).iter_rounds("a").err());
+        assert_eq!(Some(DanceError::EmptyOrder), error(""));
+        assert_eq!(
+            Some(DanceError::InvalidProgram { program: 'E' }),
+            error("abcdE")
+        );
+        assert_eq!(
+            Some(DanceError::DuplicateProgram { program: 'a' }),
+            error("abcda")
+        );
+        assert_eq!(
+            Some(DanceError::InvalidPosition {
+                index: 1,
+                position: 4
+            }),
+            error("abcd")
+        );
+        assert_eq!(
+            Some(DanceError::MissingProgram {
+                index: 2,
+                program: 'e'
+            }),
+            error("abcdf")
+        );
+    }
+
+    /// Tests the Day 16 Part 1 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day16_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!("paedcbfghijklmno", solve_part1(&input));
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt;
+use std::fs;
+
+use crate::scaffold::{Answer, Parameter, Parameters, Solver};
+use crate::utils::error::OverflowError;
+
+const PROBLEM_NAME: &str = "Spinlock";
+const PROBLEM_INPUT_FILE: &str = "./input/day17.txt";
+const PROBLEM_DAY: u64 = 17;
+
+/// Number of values inserted into the spinlock beyond the start state in Day 17 Part 1.
+const PART1_CAP: Parameter = Parameter {
+    name: "part1-insertions",
+    default: 2_017,
+    description: "values inserted into the spinlock in part 1",
+};
+/// Number of values inserted into the spinlock beyond the start state in Day 17 Part 2.
+const PART2_CAP: Parameter = Parameter {
+    name: "part2-insertions",
+    default: 50_000_000,
+    description: "values inserted into the spinlock in part 2",
+};
+
+/// Custom type representing the input to the problem solver functions.
+#[derive(Clone, Debug)]
+pub struct ProblemInput {
+    /// Number of steps the spinlock moves forward before each insertion.
+    steps: usize,
+    /// Number of values inserted into the spinlock in part 1.
+    part1_cap: usize,
+    /// Number of values inserted into the spinlock in part 2.
+    part2_cap: u64,
+}
+
+/// Circular buffer filled by the spinlock, which steps forward through the buffer a fixed number of
+/// times before inserting each new value after its current position.
+///
+/// The buffer starts holding only the value 0. Values are never inserted before the start of the
+/// buffer, so 0 always remains at position 0.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Spinlock {
+    buffer: Vec<usize>,
+    cursor: usize,
+    steps: usize,
+}
+
+impl Spinlock {
+    /// Creates a new [`Spinlock`] holding only the value 0, which steps forward the given number of
+    /// times before each insertion.
+    pub fn new(steps: usize) -> Spinlock {
+        Spinlock {
+            buffer: vec![0],
+            cursor: 0,
+            steps,
+        }
+    }
+
+    /// Steps the spinlock forward and inserts the next value after the new current position. The
+    /// inserted value becomes the current position.
+    pub fn insert_next(&mut self) {
+        let value = self.buffer.len();
+        self.cursor = (self.cursor + self.steps) % self.buffer.len() + 1;
+        self.buffer.insert(self.cursor, value);
+    }
+
+    /// Gets the values held in the circular buffer, starting from position 0.
+    pub fn buffer(&self) -> &[usize] {
+        &self.buffer
+    }
+
+    /// Gets an iterator over the values held in the circular buffer, starting from position 0.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.buffer.iter().copied()
+    }
+
+    /// Gets the current position of the spinlock in the circular buffer.
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Gets the value at the current position of the spinlock (the last value inserted).
+    pub fn current(&self) -> usize {
+        self.buffer[self.cursor]
+    }
+
+    /// Gets the value following the given value in the circular buffer, or None if the value is
+    /// not held in the buffer.
+    pub fn value_after(&self, value: usize) -> Option<usize> {
+        let i = self.buffer.iter().position(|&v| v == value)?;
+        Some(self.buffer[(i + 1) % self.buffer.len()])
+    }
+}
+
+impl fmt::Display for Spinlock {
+    /// Formats the circular buffer starting from position 0, with the value at the current position
+    /// in brackets (as shown in the problem statement).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, value) in self.buffer.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            if i == self.cursor {
+                write!(f, "({value})")?;
+            } else {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solver for AOC 2017 Day 17.
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const PARAMETERS: &'static [Parameter] = &[PART1_CAP, PART2_CAP];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn apply_parameters(input: &mut Self::Input, params: &Parameters) {
+        input.part1_cap = params.get(&PART1_CAP) as usize;
+        input.part2_cap = params.get(&PART2_CAP);
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).unwrap_or_else(|e| panic!("{e}")).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 17 input file in the format required by the solver functions.
+///
+/// Returned value is a [`ProblemInput`] containing the positive integer value given in the input
+/// file, along with the default number of insertions for each part.
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    new_problem_input(raw_input.trim().parse::<usize>().unwrap())
+}
+
+/// Creates a new [`ProblemInput`] for the number of steps, with the default number of insertions
+/// for each part.
+fn new_problem_input(steps: usize) -> ProblemInput {
+    ProblemInput {
+        steps,
+        part1_cap: PART1_CAP.default as usize,
+        part2_cap: PART2_CAP.default,
+    }
+}
+
+/// Solves AOC 2017 Day 17 Part 1.
+///
+/// Identifies the value following 2017 in the spinlock circular buffer after 2017 values have been
+/// inserted.
+fn solve_part1(input: &ProblemInput) -> usize {
+    let mut spinlock = Spinlock::new(input.steps);
+    for _ in 0..input.part1_cap {
+        spinlock.insert_next();
+    }
+    // Find the value after the last value inserted into the completed spinlock circular buffer
+    spinlock.value_after(spinlock.current()).unwrap()
+}
+
+/// Solves AOC 2017 Day 17 Part 2.
+///
+/// Identifies the value following 0 in the spinlock circular buffer after 50 million values have
+/// been inserted.
+///
+/// The simulation is conducted with u64 values regardless of the size of usize, and an
+/// [`OverflowError`] is returned if the cursor position cannot be held in a u64.
+fn solve_part2(input: &ProblemInput) -> Result<u64, OverflowError> {
+    // Spinlock length matters, but not all specific values (we are locking at fixed index for 0)
+    let steps = input.steps as u64;
+    let mut cursor: u64 = 0;
+    let mut code_after_zero: u64 = 0;
+    for code in 1..=input.part2_cap {
+        // Check if the current code will be inserted after the zero value (remains at index 0)
+        if cursor == 0 {
+            code_after_zero = code;
+        }
+        // Simulate inserting the current code into the spinlock, which then holds code + 1 values
+        cursor = cursor
+            .checked_add(steps)
+            .and_then(|cursor| cursor.checked_add(1))
+            .zip(code.checked_add(1))
+            .map(|(cursor, spinlock_len)| cursor % spinlock_len)
+            .ok_or_else(|| OverflowError {
+                message: format!("spinlock cursor after inserting {code} values exceeds u64"),
+            })?;
+    }
+    Ok(code_after_zero)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 17 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day17_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(1642, solution);
+        // Check that the completed buffer holds each inserted value once, with 0 at the start
+        let mut spinlock = Spinlock::new(input.steps);
+        for _ in 0..input.part1_cap {
+            spinlock.insert_next();
+        }
+        assert_eq!(input.part1_cap + 1, spinlock.buffer().len());
+        assert_eq!(Some(0), spinlock.iter().next());
+        assert_eq!(input.part1_cap, spinlock.current());
+        let mut values = spinlock.buffer().to_vec();
+        values.sort_unstable();
+        assert!(values.into_iter().eq(0..=input.part1_cap));
+    }
+
+    /// Tests the intermediate states of the spinlock against the example given in the problem
+    /// statement.
+    #[test]
+    fn test_day17_spinlock_example_states() {
+        let states = [
+            "(0)",
+            "0 (1)",
+            "0 (2) 1",
+            "0 2 (3) 1",
+            "0 2 (4) 3 1",
+            "0 (5) 2 4 3 1",
+            "0 5 2 4 3 (6) 1",
+            "0 5 (7) 2 4 3 6 1",
+            "0 5 7 2 4 3 (8) 6 1",
+            "0 (9) 5 7 2 4 3 8 6 1",
+        ];
+        let mut spinlock = Spinlock::new(3);
+        for (i, state) in states.into_iter().enumerate() {
+            if i > 0 {
+                spinlock.insert_next();
+            }
+            assert_eq!(state, spinlock.to_string());
+            assert_eq!(i, spinlock.current());
+        }
+        assert_eq!(1, spinlock.position());
+        assert_eq!(
+            vec![0, 9, 5, 7, 2, 4, 3, 8, 6, 1],
+            spinlock.iter().collect::<Vec<usize>>()
+        );
+        assert_eq!(Some(5), spinlock.value_after(9));
+        assert_eq!(Some(0), spinlock.value_after(1));
+        assert_eq!(None, spinlock.value_after(10));
+    }
+
+    /// Tests the Day 17 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day17_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(Ok(33601318), solution);
+    }
+
+    /// Tests the Day 17 solver methods with the number of insertions overridden, using the example
+    /// given in the problem statement.
+    #[test]
+    fn test_day17_parameters_example() {
+        let mut input = new_problem_input(3);
+        assert_eq!(638, solve_part1(&input));
+        let mut params = Parameters::new();
+        params.set("part1-insertions", 9);
+        params.set("part2-insertions", 9);
+        Day17::apply_parameters(&mut input, &params);
+        assert_eq!(5, solve_part1(&input));
+        assert_eq!(Ok(9), solve_part2(&input));
+    }
+
+    /// Tests the Day 17 Part 1 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day17_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(638, solve_part1(&input));
+    }
+}
@@ -0,0 +1,95 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::machines::duet::Duet;
+use crate::utils::machines::soundcomputer::{Instruction, IsaProfile, SoundComputer};
+
+const PROBLEM_NAME: &str = "Duet";
+const PROBLEM_INPUT_FILE: &str = "./input/day18.txt";
+const PROBLEM_DAY: u64 = 18;
+
+/// Solver for AOC 2017 Day 18.
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Vec<Instruction>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 18 input file in the format required by the solver functions.
+///
+/// Returned value is vector of [`Instruction`] given by the lines of the input file.
+fn process_input_file(filename: &str) -> Vec<Instruction> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    Instruction::parse_program(&raw_input).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Solves AOC 2017 Day 18 Part 1.
+///
+/// Determines the value of the recovered frequency the first time a "rcv" instruction is executed
+/// with a non-zero value.
+fn solve_part1(instructions: &[Instruction]) -> i64 {
+    let mut sound_computer = SoundComputer::new(instructions, IsaProfile::Sound).unwrap();
+    sound_computer
+        .run_solo()
+        .unwrap()
+        .recovered_frequency
+        .unwrap()
+}
+
+/// Solves AOC 2017 Day 18 Part 2.
+///
+/// Determines the total number of sounds sent by program 1, when the sound computer is operated as
+/// two machines (0 and 1) running in duet mode.
+fn solve_part2(instructions: &[Instruction]) -> u64 {
+    let mut duet = Duet::new(instructions).unwrap();
+    duet.run().unwrap().total_sounds_sent[1]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 18 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day18_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(3188, solution);
+    }
+
+    /// Tests the Day 18 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day18_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(7112, solution);
+    }
+
+    /// Tests the Day 18 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day18_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(4, solve_part1(&input));
+        let input = process_input_file(&example_path(PROBLEM_DAY, 2));
+        assert_eq!(3, solve_part2(&input));
+    }
+}
@@ -0,0 +1,632 @@
+use core::fmt;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::cartography::{CardinalDirection, Point2D};
+
+const PROBLEM_NAME: &str = "A Series of Tubes";
+const PROBLEM_INPUT_FILE: &str = "./input/day19.txt";
+const PROBLEM_DAY: u64 = 19;
+
+/// Represents the unique variants of track segments in the Day 19 problem input file.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum TrackSegment {
+    Vertical,
+    Horizontal,
+    Corner,
+    Letter { letter: char },
+}
+
+/// Custom error type indicating that the operation parsing the input file has failed.
+#[derive(Debug)]
+struct InputFileParseError {
+    message: String,
+}
+
+impl fmt::Display for InputFileParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parsing of input file failed: {}", self.message)
+    }
+}
+
+/// Custom error type indicating an unrecoverable error has been encountered in navigating the track
+/// segments.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NavigationError {
+    /// No track segment was found on the edges of the map for the packet to start from.
+    NoStart,
+    /// The selected track does not exist, since there are fewer entry points on the edges.
+    NoSuchTrack { track: usize, tracks: usize },
+    /// The packet reached a turn at the location where more than one new direction could be taken.
+    AmbiguousTurn { location: Point2D },
+}
+
+impl fmt::Display for NavigationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavigationError::NoStart => {
+                write!(
+                    f,
+                    "Navigation error encountered: no start on the edges of the map"
+                )
+            }
+            NavigationError::NoSuchTrack { track, tracks } => {
+                write!(
+                    f,
+                    "Navigation error encountered: no track {track} (found {tracks} tracks)"
+                )
+            }
+            NavigationError::AmbiguousTurn { location } => {
+                write!(
+                    f,
+                    "Navigation error encountered: ambiguous turn at {location}"
+                )
+            }
+        }
+    }
+}
+
+/// Solver for AOC 2017 Day 19.
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = HashMap<Point2D, TrackSegment>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_both(input: &Self::Input) -> Option<(Answer, Answer)> {
+        // Both parts are answered by a single navigation of the track
+        let (letters, steps) = navigate_track(input);
+        Some((letters.into(), steps.into()))
+    }
+}
+
+/// Processes the AOC 2017 Day 19 input file in the format required by the solver functions.
+///
+/// Returned value is [`HashMap`] mapping a two-dimensional location ([`Point2D`]) to a track
+/// segment type ([`TrackSegment`]).
+fn process_input_file(filename: &str) -> HashMap<Point2D, TrackSegment> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    parse_input_file_contents(&raw_input).unwrap()
+}
+
+/// Parses the content of the input file to generate the data structure needed as input to the
+/// problem solver functions.
+///
+/// If the input file is correctly formatted, a [`HashMap`] is returned. Otherwise, an
+/// [`InputFileParseError`] is returned to indicate the parsing operation has failed.
+fn parse_input_file_contents(
+    s: &str,
+) -> Result<HashMap<Point2D, TrackSegment>, InputFileParseError> {
+    let mut track_map: HashMap<Point2D, TrackSegment> = HashMap::new();
+    let mut max_col: Option<usize> = None;
+    for (r, row) in s.lines().enumerate() {
+        for (c, tile) in row.chars().enumerate() {
+            let (x, y) = (c as i64, r as i64);
+            match tile {
+                '|' => {
+                    track_map.insert(Point2D::new(x, y), TrackSegment::Vertical);
+                }
+                '-' => {
+                    track_map.insert(Point2D::new(x, y), TrackSegment::Horizontal);
+                }
+                '+' => {
+                    track_map.insert(Point2D::new(x, y), TrackSegment::Corner);
+                }
+                'A'..='Z' => {
+                    track_map.insert(Point2D::new(x, y), TrackSegment::Letter { letter: tile });
+                }
+                _ => (),
+            }
+            // Check that the current row is not too long!
+            match r {
+                0 => max_col = Some(c),
+                _ => {
+                    if c > max_col.unwrap() {
+                        return Err(InputFileParseError {
+                            message: format!("Row {r} is too long!"),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    Ok(track_map)
+}
+
+/// Solves AOC 2017 Day 19 Part 1.
+///
+/// Determines the sequence of letters encountered by the packet as it navigates the track segments
+/// given in the input [`HashMap`].
+fn solve_part1(track_map: &HashMap<Point2D, TrackSegment>) -> String {
+    let (letters, _) = navigate_track(track_map);
+    letters
+}
+
+/// Solves AOC 2017 Day 19 Part 2.
+///
+/// Determines the number of steps required for the packet to complete its navigation through the
+/// track segments.
+fn solve_part2(track_map: &HashMap<Point2D, TrackSegment>) -> usize {
+    let (_, steps) = navigate_track(track_map);
+    steps
+}
+
+/// Navigates the packet through the track, collecting letters and counting steps along the way.
+///
+/// Returned tuple contains the letters (in order) collected along the way and the total number of
+/// steps undertaken.
+fn navigate_track(track_map: &HashMap<Point2D, TrackSegment>) -> (String, usize) {
+    TrackNavigator::new(track_map).navigate().unwrap()
+}
+
+/// Guides the packet along the track segments of a map, from the start on the top row to the end of
+/// the path.
+///
+/// The packet moves straight through vertical, horizontal and letter segments, so paths can cross
+/// themselves (with the crossing shown by either segment or by a letter). The packet turns at
+/// corner segments, and at letter segments where the path does not continue straight ahead.
+pub struct TrackNavigator<'a> {
+    track_map: &'a HashMap<Point2D, TrackSegment>,
+}
+
+impl<'a> TrackNavigator<'a> {
+    /// Creates a new [`TrackNavigator`] for the track segments.
+    pub fn new(track_map: &'a HashMap<Point2D, TrackSegment>) -> TrackNavigator<'a> {
+        TrackNavigator { track_map }
+    }
+
+    /// Gets the entry points of the tracks on the map, along with the direction in which the packet
+    /// enters the map at each of them.
+    ///
+    /// The packet can enter from any edge of the map (the bounding box of the track segments), at a
+    /// straight segment running into the map - a vertical segment on the top or bottom edge, or a
+    /// horizontal segment on the left or right edge. Entry points are ordered by edge (top, right,
+    /// bottom then left), then from left to right or top to bottom along the edge. A map may hold
+    /// several disconnected tracks, each with its own entry point.
+    ///
+    /// If both ends of a track lie on the edges, only the end found first in this order is given as
+    /// the entry point of the track, so the packet starts from the top edge where it can (as in the
+    /// problem statement). The other end is taken to be the end of the path.
+    pub fn entry_points(&self) -> Vec<(Point2D, CardinalDirection)> {
+        let candidates = self.edge_points();
+        let mut entry_points: Vec<(Point2D, CardinalDirection)> = vec![];
+        let mut exits: Vec<(Point2D, CardinalDirection)> = vec![];
+        for (start, dirn) in candidates.iter().copied() {
+            if exits.contains(&(start, dirn)) {
+                continue;
+            }
+            entry_points.push((start, dirn));
+            // Tracks that cannot be navigated keep all of their entry points
+            if let Ok(trace) = self.trace_from(start, dirn) {
+                let exit = (trace.end, trace.end_dirn.reverse());
+                if exit != (start, dirn) && candidates.contains(&exit) {
+                    exits.push(exit);
+                }
+            }
+        }
+        entry_points
+    }
+
+    /// Gets every location on the edges of the map where the packet could enter, along with the
+    /// direction in which it would enter (see [`TrackNavigator::entry_points`]).
+    fn edge_points(&self) -> Vec<(Point2D, CardinalDirection)> {
+        let Some(bounds) = self.bounds() else {
+            return vec![];
+        };
+        let (min_x, max_x, min_y, max_y) = bounds;
+        let mut entry_points = vec![];
+        for edge in CardinalDirection::ALL {
+            let dirn = edge.reverse();
+            let mut edge_points = self
+                .track_map
+                .iter()
+                .filter(|(loc, segment)| {
+                    let on_edge = match edge {
+                        CardinalDirection::North => loc.y() == min_y,
+                        CardinalDirection::East => loc.x() == max_x,
+                        CardinalDirection::South => loc.y() == max_y,
+                        CardinalDirection::West => loc.x() == min_x,
+                    };
+                    on_edge && runs_along(**segment, dirn)
+                })
+                .map(|(loc, _)| *loc)
+                .collect::<Vec<Point2D>>();
+            edge_points.sort_by_key(|loc| (loc.x(), loc.y()));
+            entry_points.extend(edge_points.into_iter().map(|loc| (loc, dirn)));
+        }
+        entry_points
+    }
+
+    /// Gets the bounding box of the track segments, as the minimum and maximum x- and
+    /// y-coordinates. Returns None if there are no track segments.
+    fn bounds(&self) -> Option<(i64, i64, i64, i64)> {
+        let xs = self.track_map.keys().map(|loc| loc.x());
+        let ys = self.track_map.keys().map(|loc| loc.y());
+        Some((xs.clone().min()?, xs.max()?, ys.clone().min()?, ys.max()?))
+    }
+
+    /// Navigates the packet through the track, collecting letters and counting steps along the way.
+    /// If the map holds more than one track, the track with the first entry point is navigated (the
+    /// leftmost entry on the top edge, if there is one). A track with both ends on the edges is
+    /// navigated from the end that comes first in the order of [`TrackNavigator::entry_points`].
+    ///
+    /// Returned tuple contains the letters (in order) collected along the way and the total number
+    /// of steps undertaken. Returns an error if there is no start, or if the packet reaches a turn
+    /// that could be taken in either direction.
+    pub fn navigate(&self) -> Result<(String, usize), NavigationError> {
+        self.navigate_track(0)
+    }
+
+    /// Navigates the packet through the selected track, given by the index of its entry point (see
+    /// [`TrackNavigator::entry_points`] for the ordering).
+    ///
+    /// Returns an error if the map has no tracks or the selected track does not exist, along with
+    /// any error encountered while navigating the track.
+    pub fn navigate_track(&self, track: usize) -> Result<(String, usize), NavigationError> {
+        let entry_points = self.entry_points();
+        match entry_points.get(track) {
+            Some((start, dirn)) => self.navigate_from(*start, *dirn),
+            None if entry_points.is_empty() => Err(NavigationError::NoStart),
+            None => Err(NavigationError::NoSuchTrack {
+                track,
+                tracks: entry_points.len(),
+            }),
+        }
+    }
+
+    /// Navigates the packet through every track on the map, ordered by their entry points.
+    ///
+    /// Returns an error if the map has no tracks, or the first error encountered while navigating
+    /// any of the tracks.
+    pub fn navigate_all(&self) -> Result<Vec<(String, usize)>, NavigationError> {
+        let entry_points = self.entry_points();
+        if entry_points.is_empty() {
+            return Err(NavigationError::NoStart);
+        }
+        entry_points
+            .into_iter()
+            .map(|(start, dirn)| self.navigate_from(start, dirn))
+            .collect()
+    }
+
+    /// Navigates the packet through the track entered at the start location, moving in the given
+    /// direction.
+    fn navigate_from(
+        &self,
+        start: Point2D,
+        dirn: CardinalDirection,
+    ) -> Result<(String, usize), NavigationError> {
+        let trace = self.trace_from(start, dirn)?;
+        Ok((trace.letters, trace.steps))
+    }
+
+    /// Traces the path of the packet entering at the start location and moving in the given
+    /// direction, until there is no valid move next.
+    fn trace_from(
+        &self,
+        start: Point2D,
+        mut dirn: CardinalDirection,
+    ) -> Result<TrackTrace, NavigationError> {
+        let mut loc = start;
+        let mut letters = String::new();
+        // Packet takes a step to enter the starting location
+        let mut steps = 1;
+        // Continue moving until there is no valid move next
+        loop {
+            let track_segment = self.track_map[&loc];
+            if let TrackSegment::Letter { letter } = track_segment {
+                letters.push(letter);
+            }
+            // Pass straight through all segments other than corners, if the path continues
+            let ahead = loc + Point2D::from(dirn);
+            if track_segment == TrackSegment::Corner || !self.track_map.contains_key(&ahead) {
+                match self.find_turn(loc, dirn, track_segment == TrackSegment::Corner)? {
+                    Some(new_dirn) => dirn = new_dirn,
+                    // Check if the final location was reached
+                    None => break,
+                }
+            }
+            loc += Point2D::from(dirn);
+            steps += 1;
+        }
+        Ok(TrackTrace {
+            letters,
+            steps,
+            end: loc,
+            end_dirn: dirn,
+        })
+    }
+
+    /// Finds the new direction for the packet turning at the location while moving in the given
+    /// direction.
+    ///
+    /// Track segments either side of the location lead away from it, unless they run across the
+    /// new direction (e.g. a vertical segment beside a packet turning east or west). At corners, a
+    /// single track segment either side is taken as the way to turn even if it runs across the new
+    /// direction, since the path must be crossing another path there. Returns None if there is no
+    /// way to turn, or an error if there is more than one.
+    fn find_turn(
+        &self,
+        loc: Point2D,
+        dirn: CardinalDirection,
+        is_corner: bool,
+    ) -> Result<Option<CardinalDirection>, NavigationError> {
+        let candidates = [dirn.turn_left(), dirn.turn_right()]
+            .into_iter()
+            .filter(|new_dirn| {
+                self.track_map
+                    .contains_key(&(loc + Point2D::from(*new_dirn)))
+            })
+            .collect::<Vec<CardinalDirection>>();
+        if is_corner && candidates.len() == 1 {
+            return Ok(Some(candidates[0]));
+        }
+        let leading_away = candidates
+            .into_iter()
+            .filter(|new_dirn| {
+                let segment = self.track_map[&(loc + Point2D::from(*new_dirn))];
+                !runs_across(segment, *new_dirn)
+            })
+            .collect::<Vec<CardinalDirection>>();
+        match leading_away.as_slice() {
+            [] => Ok(None),
+            [new_dirn] => Ok(Some(*new_dirn)),
+            _ => Err(NavigationError::AmbiguousTurn { location: loc }),
+        }
+    }
+}
+
+/// Path taken by the packet through a single track.
+struct TrackTrace {
+    /// Letters collected along the way, in order.
+    letters: String,
+    /// Total number of steps undertaken.
+    steps: usize,
+    /// Location at the end of the path.
+    end: Point2D,
+    /// Direction the packet was moving in when it reached the end of the path.
+    end_dirn: CardinalDirection,
+}
+
+/// Checks if the track segment is a straight segment running along the given direction of travel.
+fn runs_along(segment: TrackSegment, dirn: CardinalDirection) -> bool {
+    match segment {
+        TrackSegment::Vertical | TrackSegment::Horizontal => !runs_across(segment, dirn),
+        TrackSegment::Corner | TrackSegment::Letter { .. } => false,
+    }
+}
+
+/// Checks if the track segment runs across the given direction of travel.
+fn runs_across(segment: TrackSegment, dirn: CardinalDirection) -> bool {
+    match segment {
+        TrackSegment::Vertical => matches!(dirn, CardinalDirection::East | CardinalDirection::West),
+        TrackSegment::Horizontal => {
+            matches!(dirn, CardinalDirection::North | CardinalDirection::South)
+        }
+        TrackSegment::Corner | TrackSegment::Letter { .. } => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::{example, example_path};
+
+    /// Tests the Day 19 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day19_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!("QPRYCIOLU", solution);
+    }
+
+    /// Tests the Day 19 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day19_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(16162, solution);
+    }
+
+    /// Tests that solving both parts together gives the same solutions as the separate solvers.
+    #[test]
+    fn test_day19_solve_both() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let (p1_solution, p2_solution) = Day19::solve_both(&input).unwrap();
+        assert_eq!(Answer::from("QPRYCIOLU"), p1_solution);
+        assert_eq!(Answer::from(16162usize), p2_solution);
+    }
+
+    /// Tests navigation of the example given in the problem statement, along with maps with
+    /// letters on corners, a letter at a crossing and a corner that could be turned either way.
+    #[test]
+    fn test_day19_track_navigator() {
+        let navigate = |map: &str| {
+            let track_map = parse_input_file_contents(map).unwrap();
+            TrackNavigator::new(&track_map).navigate()
+        };
+        assert_eq!(
+            Ok((String::from("ABCDEF"), 38)),
+            navigate(&example(PROBLEM_DAY, 1))
+        );
+        let letter_corners = " |  \n A-B\n   |\n D-C\n";
+        assert_eq!(Ok((String::from("ABCD"), 8)), navigate(letter_corners));
+        let letter_crossing = " |    \n | +-+\n +-X-+\n   | \n   E \n";
+        assert_eq!(Ok((String::from("XXE"), 13)), navigate(letter_crossing));
+        let ambiguous = " | \n-+-\n";
+        assert_eq!(
+            Err(NavigationError::AmbiguousTurn {
+                location: Point2D::new(1, 1)
+            }),
+            navigate(ambiguous)
+        );
+    }
+
+    /// Tests that each of several disconnected tracks can be selected and navigated, and that maps
+    /// without tracks or without the selected track are reported as errors.
+    #[test]
+    fn test_day19_multiple_tracks() {
+        let map = " |  |   | \n A  +-+ B \n |    | | \n +-C  D + \n";
+        let track_map = parse_input_file_contents(map).unwrap();
+        let navigator = TrackNavigator::new(&track_map);
+        let south = CardinalDirection::South;
+        assert_eq!(
+            vec![
+                (Point2D::new(1, 0), south),
+                (Point2D::new(4, 0), south),
+                (Point2D::new(8, 0), south)
+            ],
+            navigator.entry_points()
+        );
+        let tracks = vec![
+            (String::from("AC"), 6),
+            (String::from("D"), 6),
+            (String::from("B"), 4),
+        ];
+        assert_eq!(Ok(tracks.clone()), navigator.navigate_all());
+        assert_eq!(Ok(tracks[0].clone()), navigator.navigate());
+        for (i, track) in tracks.iter().enumerate() {
+            assert_eq!(Ok(track.clone()), navigator.navigate_track(i));
+        }
+        assert_eq!(
+            Err(NavigationError::NoSuchTrack {
+                track: 3,
+                tracks: 3
+            }),
+            navigator.navigate_track(3)
+        );
+        let track_map = parse_input_file_contents("    \n +A \n").unwrap();
+        let navigator = TrackNavigator::new(&track_map);
+        assert_eq!(Err(NavigationError::NoStart), navigator.navigate());
+        assert_eq!(Err(NavigationError::NoStart), navigator.navigate_all());
+        assert_eq!(Err(NavigationError::NoStart), navigator.navigate_track(1));
+    }
+
+    /// Tests that the start location and direction are inferred for tracks entered from each edge
+    /// of the map, including the example from the problem statement rotated and transposed.
+    #[test]
+    fn test_day19_entry_from_any_edge() {
+        let entries = |map: &str| {
+            let track_map = parse_input_file_contents(map).unwrap();
+            let navigator = TrackNavigator::new(&track_map);
+            (navigator.entry_points(), navigator.navigate_all())
+        };
+        // Only straight segments running into the map can be entered
+        let (entry_points, tracks) = entries(" A+ \n  | \n");
+        assert_eq!(
+            vec![(Point2D::new(2, 1), CardinalDirection::North)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("A"), 3)]), tracks);
+        let (entry_points, tracks) = entries("      \n--A-+ \n    | \n  B-+ \n");
+        assert_eq!(
+            vec![(Point2D::new(0, 1), CardinalDirection::East)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("AB"), 9)]), tracks);
+        let (entry_points, tracks) = entries(" +-C-- \n |     \n D     \n");
+        assert_eq!(
+            vec![(Point2D::new(5, 0), CardinalDirection::West)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("CD"), 7)]), tracks);
+        // Rotating or transposing the example map gives the same letters and steps
+        let example_map = example(PROBLEM_DAY, 1);
+        let rows = example_map
+            .lines()
+            .map(|row| row.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        let width = rows.iter().map(|row| row.len()).max().unwrap();
+        let tile = |r: usize, c: usize| *rows[r].get(c).unwrap_or(&' ');
+        let swap = |t: char| match t {
+            '|' => '-',
+            '-' => '|',
+            _ => t,
+        };
+        let transposed = (0..width)
+            .map(|c| {
+                (0..rows.len())
+                    .map(|r| swap(tile(r, c)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let rotated = (0..rows.len())
+            .rev()
+            .map(|r| (0..width).rev().map(|c| tile(r, c)).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+        for (map, dirn) in [
+            (transposed, CardinalDirection::East),
+            (rotated, CardinalDirection::North),
+        ] {
+            let (entry_points, tracks) = entries(&map);
+            assert_eq!(1, entry_points.len());
+            assert_eq!(dirn, entry_points[0].1);
+            assert_eq!(Ok(vec![(String::from("ABCDEF"), 38)]), tracks);
+        }
+    }
+
+    /// Tests that a track with both ends on the edges of the map is only navigated once, starting
+    /// from the end that comes first in edge order (top, right, bottom then left).
+    #[test]
+    fn test_day19_track_ends_on_edges() {
+        let navigate_all = |map: &str| {
+            let track_map = parse_input_file_contents(map).unwrap();
+            let navigator = TrackNavigator::new(&track_map);
+            (navigator.entry_points(), navigator.navigate_all())
+        };
+        let (entry_points, tracks) = navigate_all(" |\n A\n |\n");
+        assert_eq!(
+            vec![(Point2D::new(1, 0), CardinalDirection::South)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("A"), 3)]), tracks);
+        // Track from the left edge to the bottom edge starts from the bottom edge
+        let (entry_points, tracks) = navigate_all("--A-+\n    B\n    |\n");
+        assert_eq!(
+            vec![(Point2D::new(4, 2), CardinalDirection::North)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("BA"), 7)]), tracks);
+        // Rotating the map by a half turn puts that end on the top edge, reversing the letters
+        let (entry_points, tracks) = navigate_all("|    \nB    \n+-A--\n");
+        assert_eq!(
+            vec![(Point2D::new(0, 0), CardinalDirection::South)],
+            entry_points
+        );
+        assert_eq!(Ok(vec![(String::from("BA"), 7)]), tracks);
+        // Separate tracks sharing the edges are still navigated separately
+        let (_, tracks) = navigate_all(" | | \n A | \n | C \n");
+        assert_eq!(
+            Ok(vec![(String::from("A"), 3), (String::from("C"), 3)]),
+            tracks
+        );
+    }
+
+    /// Tests the Day 19 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day19_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!("ABCDEF", solve_part1(&input));
+        assert_eq!(38, solve_part2(&input));
+    }
+}
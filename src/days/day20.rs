@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fs;
+
+use itertools::Itertools;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::cartography::Point3D;
+use crate::utils::day20::{rank_long_term, Particle3D, SwarmSimulation};
+use crate::utils::error::{InputFileParseError, ParseError};
+use crate::utils::parse::{expect_end, expect_literal, take_integer};
+
+const PROBLEM_NAME: &str = "Particle Swarm";
+const PROBLEM_INPUT_FILE: &str = "./input/day20.txt";
+const PROBLEM_DAY: u64 = 20;
+
+/// Solver for AOC 2017 Day 20.
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Vec<Particle3D>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 20 input file in the format required by the solver functions.
+///
+/// Returned value is vector of [`Particle3D`] structs created using the values given in the lines
+/// of the input file.
+fn process_input_file(filename: &str) -> Vec<Particle3D> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    raw_input
+        .trim()
+        .lines()
+        .map(|line| parse_input_file_line(line).unwrap())
+        .collect::<Vec<Particle3D>>()
+}
+
+/// Parses a line from the input file, returning a [`Particle3D`] if the line is correctly
+/// formatted. Otherwise, a error ([`InputFileParseError`]) is returned.
+fn parse_input_file_line(s: &str) -> Result<Particle3D, InputFileParseError> {
+    parse_particle(s).map_err(|err| InputFileParseError {
+        message: format!("Input file line not correctly formatted [{s}] - {err}"),
+    })
+}
+
+/// Parses the location, velocity and acceleration of a particle from a line in the format
+/// "p=<x,y,z>, v=<x,y,z>, a=<x,y,z>". Whitespace is allowed around each of the coordinates and
+/// separators, so padded lines such as "p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>" are also accepted.
+///
+/// If the line is not correctly formatted, the returned error names the vector that could not be
+/// parsed along with the column (counted from 1) at which it starts.
+fn parse_particle(s: &str) -> Result<Particle3D, ParseError> {
+    let (loc, rest) = take_vector(s, s, "p", "location")?;
+    let (vel, rest) = take_vector(s, expect_separator(rest)?, "v", "velocity")?;
+    let (acc, rest) = take_vector(s, expect_separator(rest)?, "a", "acceleration")?;
+    expect_end(rest.trim_end())?;
+    Ok(Particle3D::new(&loc, &vel, &acc))
+}
+
+/// Checks that the input starts with the comma separating two vectors (with optional whitespace
+/// either side), returning the input remaining after it.
+fn expect_separator(s: &str) -> Result<&str, ParseError> {
+    Ok(expect_literal(s.trim_start(), ",")?.trim_start())
+}
+
+/// Takes a named vector in the format "name=<x,y,z>" from the start of the input, which is the
+/// remainder of the full line being parsed.
+fn take_vector<'a>(
+    line: &str,
+    s: &'a str,
+    name: &str,
+    description: &str,
+) -> Result<(Point3D, &'a str), ParseError> {
+    let take = || -> Result<(Point3D, &'a str), ParseError> {
+        let rest = expect_literal(s.trim_start(), name)?;
+        let rest = expect_literal(rest.trim_start(), "=")?;
+        take_point3d(expect_literal(rest.trim_start(), "<")?)
+    };
+    take().map_err(|err| ParseError {
+        message: format!(
+            "invalid {description} at column {}: {}",
+            line.len() - s.len() + 1,
+            err.message
+        ),
+    })
+}
+
+/// Takes the comma-separated coordinates of a [`Point3D`] from the start of the input, followed by
+/// the closing ">". Whitespace is allowed around each of the coordinates.
+fn take_point3d(s: &str) -> Result<(Point3D, &str), ParseError> {
+    let (x, rest) = take_integer::<i64>(s.trim_start())?;
+    let (y, rest) = take_integer::<i64>(expect_literal(rest.trim_start(), ",")?.trim_start())?;
+    let (z, rest) = take_integer::<i64>(expect_literal(rest.trim_start(), ",")?.trim_start())?;
+    Ok((
+        Point3D::new(x, y, z),
+        expect_literal(rest.trim_start(), ">")?,
+    ))
+}
+
+/// Solves AOC 2017 Day 20 Part 1.
+///
+/// Returns the number of the particle that will remain closest to the origin in the long-term.
+///
+/// Panics if more than one particle remains closest to the origin, since the answer is ambiguous.
+fn solve_part1(particles: &[Particle3D]) -> usize {
+    let ranking = rank_long_term(particles);
+    match ranking.first().map(|group| group.as_slice()) {
+        Some(&[index]) => index,
+        Some(tied) => panic!("Ambiguous answer - particles {tied:?} remain closest to the origin!"),
+        None => panic!("No particles given!"),
+    }
+}
+
+/// Solves AOC 2017 Day 20 Part 2.
+///
+/// Determines the number of particles remaining after no more collisions are possible.
+fn solve_part2(particles: &[Particle3D]) -> usize {
+    let mut simulation = SwarmSimulation::new(particles);
+    // Calculate Manhattan distance between each pair of particles
+    let mut old_pair_dist =
+        calculate_pair_manhattan_distances(&simulation.next().unwrap().particles);
+    // Keep simulating until no more collisions are possible
+    loop {
+        let state = simulation.next().unwrap();
+        let new_pair_dist = calculate_pair_manhattan_distances(&state.particles);
+        // Check for stopping condition: all pair distances have increased
+        if new_pair_dist
+            .iter()
+            .all(|(i_pair, dist)| dist >= old_pair_dist.get(i_pair).unwrap())
+        {
+            // Return the number of particles remaining
+            return state.particles.len();
+        }
+        // New pair distances becomes old
+        old_pair_dist = new_pair_dist;
+    }
+}
+
+/// Calculates the Manhattan distance between each pair of particles
+fn calculate_pair_manhattan_distances(
+    particles: &HashMap<usize, Particle3D>,
+) -> HashMap<(usize, usize), u64> {
+    let mut pair_distances_manh: HashMap<(usize, usize), u64> = HashMap::new();
+    for (i, j) in particles.keys().sorted().tuple_combinations() {
+        let left = particles.get(i).unwrap();
+        let right = particles.get(j).unwrap();
+        let dist_manh = left.loc().manhattan(right.loc());
+        pair_distances_manh.insert((*i, *j), dist_manh);
+    }
+    pair_distances_manh
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+    use crate::utils::testgen::particle_swarm;
+
+    /// Tests the Day 20 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day20_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(376, solution);
+    }
+
+    /// Tests the Day 20 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day20_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(574, solution);
+    }
+
+    /// Tests the Day 20 solver methods against the example input given in the problem statement.
+    #[test]
+    fn test_day20_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(0, solve_part1(&input));
+        let input = process_input_file(&example_path(PROBLEM_DAY, 2));
+        assert_eq!(1, solve_part2(&input));
+    }
+
+    /// Tests that the particle without any acceleration is found to stay closest to the origin in
+    /// randomly generated swarms.
+    #[test]
+    fn test_day20_part1_generated() {
+        for seed in 0..10 {
+            let raw_input = particle_swarm(seed, 100);
+            let still = raw_input
+                .lines()
+                .position(|line| line.ends_with("a=<0,0,0>"))
+                .unwrap();
+            let particles = raw_input
+                .lines()
+                .map(|line| parse_input_file_line(line).unwrap())
+                .collect::<Vec<Particle3D>>();
+            assert_eq!(still, solve_part1(&particles));
+        }
+    }
+
+    /// Tests that particles are parsed with or without whitespace padding, and that the errors for
+    /// invalid lines point to the vector that could not be parsed.
+    #[test]
+    fn test_day20_parse_whitespace() {
+        let expected = Particle3D::new(
+            &Point3D::new(3, 0, -10),
+            &Point3D::new(2, 0, 0),
+            &Point3D::new(-1, 0, 7),
+        );
+        for line in [
+            "p=<3,0,-10>, v=<2,0,0>, a=<-1,0,7>",
+            "p=< 3,0,-10>, v=< 2,0,0>, a=<-1,0, 7>",
+            "p=< 3, 0, -10 >,v=<  2 ,0 ,0>  ,  a = <-1,0,7> ",
+        ] {
+            assert_eq!(Ok(expected), parse_particle(line), "{line}");
+        }
+        for (line, message) in [
+            (
+                "p=< 3,0,0>, v=< 2,0>, a=<-1,0,0>",
+                "invalid velocity at column 13: expected \",\" at \">, a=<-1,0,0>\"",
+            ),
+            (
+                "p=< 3,0,0>, v=< 2,0,0>, a=<- 1,0,0>",
+                "invalid acceleration at column 25: expected integer at \"- 1,0,0>\"",
+            ),
+            (
+                "p=<3,x,0>, v=<2,0,0>, a=<-1,0,0>",
+                "invalid location at column 1: expected integer at \"x,0>, v=<2,0,0>, a=<-1,0,0>\"",
+            ),
+            (
+                "p=<3,0,0> v=<2,0,0>, a=<-1,0,0>",
+                "expected \",\" at \"v=<2,0,0>, a=<-1,0,0>\"",
+            ),
+            (
+                "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>, b=<0,0,0>",
+                "unexpected trailing input \", b=<0,0,0>\"",
+            ),
+        ] {
+            assert_eq!(message, parse_particle(line).unwrap_err().message);
+        }
+    }
+}
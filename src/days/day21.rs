@@ -0,0 +1,260 @@
+use std::fs;
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
+use fancy_regex::Regex;
+
+use crate::scaffold::{Answer, Parameter, Parameters, Solver};
+use crate::utils::error::{InputFileParseError, OverflowError};
+use crate::utils::fractal::{count_on_after, ArtGrid, Pattern, RuleBook};
+#[cfg(not(feature = "regex"))]
+use crate::utils::parse::split_tuple;
+
+const PROBLEM_NAME: &str = "Fractal Art";
+const PROBLEM_INPUT_FILE: &str = "./input/day21.txt";
+const PROBLEM_DAY: u64 = 21;
+
+/// Number of iterations of the enhancement rules applied in Day 21 Part 1.
+const PART1_ITERATIONS: Parameter = Parameter {
+    name: "part1-iterations",
+    default: 5,
+    description: "iterations of the enhancement rules in part 1",
+};
+/// Number of iterations of the enhancement rules applied in Day 21 Part 2.
+const PART2_ITERATIONS: Parameter = Parameter {
+    name: "part2-iterations",
+    default: 18,
+    description: "iterations of the enhancement rules in part 2",
+};
+
+/// Custom type representing the input to the problem solver functions.
+#[derive(Clone, Debug)]
+pub struct ProblemInput {
+    /// Enhancement rules given in the input file.
+    rulebook: RuleBook,
+    /// Number of iterations of the enhancement rules applied in part 1.
+    part1_iterations: usize,
+    /// Number of iterations of the enhancement rules applied in part 2.
+    part2_iterations: usize,
+}
+
+/// Solver for AOC 2017 Day 21.
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["blocks", "grid"];
+    const PARAMETERS: &'static [Parameter] = &[PART1_ITERATIONS, PART2_ITERATIONS];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn apply_parameters(input: &mut Self::Input, params: &Parameters) {
+        input.part1_iterations = params.get(&PART1_ITERATIONS) as usize;
+        input.part2_iterations = params.get(&PART2_ITERATIONS) as usize;
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).unwrap_or_else(|e| panic!("{e}")).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).unwrap_or_else(|e| panic!("{e}")).into())
+    }
+
+    fn solve_part1_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "grid" => Some(count_on_grid(&input.rulebook, input.part1_iterations).into()),
+            _ => Self::solve_part1(input),
+        }
+    }
+
+    fn solve_part2_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "grid" => Some(count_on_grid(&input.rulebook, input.part2_iterations).into()),
+            _ => Self::solve_part2(input),
+        }
+    }
+}
+
+/// Processes the AOC 2017 Day 21 input file in the format required by the solver functions.
+///
+/// Returned value is a [`ProblemInput`] holding a [`RuleBook`] containing each of the enhancement
+/// rules given in the input file, along with the default number of iterations for each part. The
+/// rules are checked to cover every possible 2x2 and 3x3 pattern.
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    let mut rulebook = RuleBook::new();
+    for line in raw_input.trim().lines() {
+        let (left, right) = parse_input_file_line(line).unwrap();
+        rulebook.add_rule(left, right).unwrap();
+    }
+    if let Err(e) = rulebook.validate() {
+        panic!("{e}");
+    }
+    ProblemInput {
+        rulebook,
+        part1_iterations: PART1_ITERATIONS.default as usize,
+        part2_iterations: PART2_ITERATIONS.default as usize,
+    }
+}
+
+/// Parses a single line from the input file to extract the input and output patterns of the rule.
+/// If line is not a valid format, an [`InputFileParseError`] is returned.
+fn parse_input_file_line(s: &str) -> Result<(Pattern, Pattern), InputFileParseError> {
+    let invalid = || InputFileParseError {
+        message: format!("Invalid input line format: {}", s),
+    };
+    let (left, right) = split_rule(s).ok_or_else(invalid)?;
+    match (left.parse::<Pattern>(), right.parse::<Pattern>()) {
+        (Ok(left), Ok(right)) => Ok((left, right)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Splits a rule into its left and right sides, checking that the rule converts a 2x2 grid section
+/// into a 3x3 grid section or a 3x3 grid section into a 4x4 grid section.
+#[cfg(feature = "regex")]
+fn split_rule(s: &str) -> Option<(&str, &str)> {
+    static REGEX_RULE_FOUR: OnceLock<Regex> = OnceLock::new();
+    static REGEX_RULE_NINE: OnceLock<Regex> = OnceLock::new();
+    let regex_rule_four = REGEX_RULE_FOUR
+        .get_or_init(|| Regex::new(r"^([.#]{2}/[.#]{2}) => ([.#]{3}/[.#]{3}/[.#]{3})$").unwrap());
+    let regex_rule_nine = REGEX_RULE_NINE.get_or_init(|| {
+        Regex::new(r"^([.#]{3}/[.#]{3}/[.#]{3}) => ([.#]{4}/[.#]{4}/[.#]{4}/[.#]{4})$").unwrap()
+    });
+    for regex in [regex_rule_four, regex_rule_nine] {
+        if let Ok(Some(caps)) = regex.captures(s) {
+            return Some((caps.get(1)?.as_str(), caps.get(2)?.as_str()));
+        }
+    }
+    None
+}
+
+/// Splits a rule into its left and right sides, checking that the rule converts a 2x2 grid section
+/// into a 3x3 grid section or a 3x3 grid section into a 4x4 grid section.
+#[cfg(not(feature = "regex"))]
+fn split_rule(s: &str) -> Option<(&str, &str)> {
+    let (left, right) = split_tuple(s, " => ").ok()?;
+    let size = |pattern: &str| pattern.parse::<Pattern>().ok().map(|p| p.size());
+    match (size(left)?, size(right)?) {
+        (2, 3) | (3, 4) => Some((left, right)),
+        _ => None,
+    }
+}
+
+/// Solves AOC 2017 Day 21 Part 1.
+///
+/// Determines how many pixels are left on after applying 5 iterations of the enhancement rules.
+fn solve_part1(input: &ProblemInput) -> Result<u128, OverflowError> {
+    count_on_after(&input.rulebook, input.part1_iterations)
+}
+
+/// Solves AOC 2017 Day 21 Part 2.
+///
+/// Determines how many pixels are left on after applying 18 iterations of the enhancement rules.
+fn solve_part2(input: &ProblemInput) -> Result<u128, OverflowError> {
+    count_on_after(&input.rulebook, input.part2_iterations)
+}
+
+/// Counts the pixels left on after applying n iterations of the enhancement rules, by generating
+/// the full art grid. Only feasible for a small number of iterations, as the grid grows
+/// exponentially.
+fn count_on_grid(rulebook: &RuleBook, iterations: usize) -> usize {
+    let mut artgrid = ArtGrid::start();
+    for _ in 0..iterations {
+        // Rule book has been validated, so every block has a matching rule
+        artgrid = artgrid.enhance(rulebook).unwrap();
+    }
+    artgrid.count_on()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example;
+
+    /// Tests the Day 21 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day21_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(Ok(203), solution);
+        assert_eq!(203, count_on_grid(&input.rulebook, input.part1_iterations));
+    }
+
+    /// Tests the Day 21 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day21_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(Ok(3342470), solution);
+        assert_eq!(
+            3342470,
+            count_on_grid(&input.rulebook, input.part2_iterations)
+        );
+    }
+
+    /// Tests the Day 21 Part 1 solver method with the number of iterations overridden, using the
+    /// example given in the problem statement. The example rules do not cover every pattern, so
+    /// they are added to the rule book without validation.
+    #[test]
+    fn test_day21_parameters_example() {
+        let mut rulebook = RuleBook::new();
+        for line in example(PROBLEM_DAY, 1).trim().lines() {
+            let (left, right) = parse_input_file_line(line).unwrap();
+            rulebook.add_rule(left, right).unwrap();
+        }
+        let mut input = ProblemInput {
+            rulebook,
+            part1_iterations: PART1_ITERATIONS.default as usize,
+            part2_iterations: PART2_ITERATIONS.default as usize,
+        };
+        let mut params = Parameters::new();
+        params.set("part1-iterations", 2);
+        Day21::apply_parameters(&mut input, &params);
+        assert_eq!(2, input.part1_iterations);
+        assert_eq!(18, input.part2_iterations);
+        assert_eq!(Ok(12), solve_part1(&input));
+        assert_eq!(12, count_on_grid(&input.rulebook, input.part1_iterations));
+    }
+
+    /// Tests that only rules converting 2x2 to 3x3 or 3x3 to 4x4 grid sections are accepted.
+    #[test]
+    fn test_day21_parse_rules() {
+        let (left, right) = parse_input_file_line("../.# => ##./#../...").unwrap();
+        assert_eq!(
+            ("../.#", "##./#../..."),
+            (&*left.to_string(), &*right.to_string())
+        );
+        for invalid in [
+            "../.# => ##./#..",
+            "../.# => #..#/..../..../#..#",
+            "../.x => ##./#../...",
+            "../.#=>##./#../...",
+            ".#./..#/### => ##./#../...",
+        ] {
+            assert!(parse_input_file_line(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    /// Tests that the pixel count is not limited by the size of usize when the number of
+    /// iterations is overridden well beyond the problem statement.
+    #[test]
+    fn test_day21_large_iterations() {
+        let mut input = process_input_file(PROBLEM_INPUT_FILE);
+        let mut params = Parameters::new();
+        params.set("part2-iterations", 120);
+        Day21::apply_parameters(&mut input, &params);
+        assert!(solve_part1(&input).is_ok());
+        assert!(solve_part2(&input).is_err());
+    }
+}
@@ -0,0 +1,691 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+};
+
+use crate::scaffold::{Answer, Parameter, Parameters, Solver};
+use crate::utils::cartography::{CardinalDirection, Point2D};
+
+const PROBLEM_NAME: &str = "Sporifica Virus";
+const PROBLEM_INPUT_FILE: &str = "./input/day22.txt";
+const PROBLEM_DAY: u64 = 22;
+
+/// Number of bursts of activity conducted in Day 22 Part 1.
+const PART1_BURSTS: Parameter = Parameter {
+    name: "part1-bursts",
+    default: 10_000,
+    description: "bursts of activity in part 1",
+};
+/// Number of bursts of activity conducted in Day 22 Part 2.
+const PART2_BURSTS: Parameter = Parameter {
+    name: "part2-bursts",
+    default: 10_000_000,
+    description: "bursts of activity in part 2",
+};
+/// Largest width (and height) of the [`FlatGrid`], in nodes. Nodes beyond it are held sparsely.
+const MAX_FLAT_GRID_SIZE: i64 = 1 << 12;
+
+/// Used to represent the possible states of individual grid tile.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NodeState {
+    Clean,
+    Infected,
+    Weakened,
+    Flagged,
+}
+
+/// Turn made by the virus carrier before it acts on the current node.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+    Straight,
+    Reverse,
+}
+
+impl Turn {
+    /// Gets the direction faced after making the turn from the given direction.
+    pub fn apply(&self, dirn: CardinalDirection) -> CardinalDirection {
+        match self {
+            Turn::Left => dirn.turn_left(),
+            Turn::Right => dirn.turn_right(),
+            Turn::Straight => dirn,
+            Turn::Reverse => dirn.reverse(),
+        }
+    }
+}
+
+/// Behaviour of a virus, giving the turn made by the carrier and the new state of the node for each
+/// state the current node can be in.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VirusRules {
+    pub clean: (Turn, NodeState),
+    pub infected: (Turn, NodeState),
+    pub weakened: (Turn, NodeState),
+    pub flagged: (Turn, NodeState),
+}
+
+impl VirusRules {
+    /// Rules followed by the virus in Part 1, which toggles nodes between clean and infected.
+    pub const ORIGINAL: VirusRules = VirusRules {
+        clean: (Turn::Left, NodeState::Infected),
+        infected: (Turn::Right, NodeState::Clean),
+        weakened: (Turn::Straight, NodeState::Infected),
+        flagged: (Turn::Reverse, NodeState::Clean),
+    };
+
+    /// Rules followed by the evolved virus in Part 2, which weakens and flags nodes in between
+    /// cleaning and infecting them.
+    pub const EVOLVED: VirusRules = VirusRules {
+        clean: (Turn::Left, NodeState::Weakened),
+        infected: (Turn::Right, NodeState::Flagged),
+        weakened: (Turn::Straight, NodeState::Infected),
+        flagged: (Turn::Reverse, NodeState::Clean),
+    };
+
+    /// Gets the turn made by the carrier and the new state of a node in the given state.
+    pub fn rule(&self, state: NodeState) -> (Turn, NodeState) {
+        match state {
+            NodeState::Clean => self.clean,
+            NodeState::Infected => self.infected,
+            NodeState::Weakened => self.weakened,
+            NodeState::Flagged => self.flagged,
+        }
+    }
+}
+
+/// Custom type representing the input to the problem solver functions.
+#[derive(Clone, Debug)]
+pub struct ProblemInput {
+    /// Starting state of the computer grid.
+    grid: HashMap<Point2D, NodeState>,
+    /// Maximum x-coordinate for the tiles in the grid.
+    max_x: i64,
+    /// Maximum y-coordinate for the tiles in the grid.
+    max_y: i64,
+    /// Number of bursts of activity conducted in part 1.
+    part1_bursts: usize,
+    /// Number of bursts of activity conducted in part 2.
+    part2_bursts: usize,
+}
+
+/// Record of the spread of the infection over a run of bursts, as returned by
+/// [`conduct_bursts_with_history`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InfectionHistory {
+    /// Number of bursts that caused a node to become infected, recorded after each checkpoint
+    /// (given as the number of bursts conducted so far).
+    pub checkpoints: Vec<(usize, usize)>,
+    /// Total number of bursts that caused a node to become infected.
+    pub infection_bursts: usize,
+    /// Locations of the nodes left infected once all bursts have been conducted.
+    pub infected: HashSet<Point2D>,
+}
+
+impl ProblemInput {
+    /// Gets the location of the node at the centre of the starting grid, where the carrier starts.
+    pub fn centre(&self) -> Point2D {
+        grid_centre(self.max_x, self.max_y)
+    }
+}
+
+/// Virus carrier moving around the grid in a [`VirusSim`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Carrier {
+    /// Location of the node the carrier is on.
+    pub loc: Point2D,
+    /// Direction the carrier is facing.
+    pub dirn: CardinalDirection,
+    /// Number of bursts by this carrier that caused a node to become infected.
+    pub infection_bursts: usize,
+}
+
+/// Simulation of one or more virus carriers spreading a virus that follows the given rules, over a
+/// grid of nodes shared by the carriers.
+///
+/// The carriers conduct bursts of activity in turn (round-robin, in the order they were added),
+/// each starting from its own location and direction. With a single carrier started at the centre
+/// of the grid facing north, this is the simulation from the problem.
+pub struct VirusSim {
+    grid: FlatGrid,
+    rules: VirusRules,
+    carriers: Vec<Carrier>,
+    next: usize,
+}
+
+impl VirusSim {
+    /// Creates a new [`VirusSim`] for the starting grid given in the problem input, without any
+    /// carriers.
+    pub fn new(input: &ProblemInput, rules: VirusRules) -> VirusSim {
+        VirusSim {
+            grid: FlatGrid::new(&input.grid, input.max_x, input.max_y),
+            rules,
+            carriers: vec![],
+            next: 0,
+        }
+    }
+
+    /// Adds a carrier starting at the given location and direction, which takes its first turn
+    /// after the carriers already added. Returned value is the index of the carrier.
+    pub fn add_carrier(&mut self, loc: Point2D, dirn: CardinalDirection) -> usize {
+        self.carriers.push(Carrier {
+            loc,
+            dirn,
+            infection_bursts: 0,
+        });
+        self.carriers.len() - 1
+    }
+
+    /// Conducts a single burst of activity by the carrier whose turn is next. Does nothing if there
+    /// are no carriers.
+    pub fn burst(&mut self) {
+        let Some(carrier) = self.carriers.get_mut(self.next) else {
+            return;
+        };
+        let node_state = self.grid.get_mut(&carrier.loc);
+        // Update carrier direction and node state, checking new state to count infection bursts
+        let (turn, new_state) = self.rules.rule(*node_state);
+        carrier.dirn = turn.apply(carrier.dirn);
+        *node_state = new_state;
+        if new_state == NodeState::Infected {
+            carrier.infection_bursts += 1;
+        }
+        // Update carrier location
+        carrier.loc += Point2D::from(carrier.dirn);
+        self.next = (self.next + 1) % self.carriers.len();
+    }
+
+    /// Conducts the given number of rounds, in which each carrier conducts one burst of activity.
+    pub fn run(&mut self, rounds: usize) {
+        for _ in 0..rounds * self.carriers.len() {
+            self.burst();
+        }
+    }
+
+    /// Gets the carriers, in the order they were added.
+    pub fn carriers(&self) -> &[Carrier] {
+        &self.carriers
+    }
+
+    /// Gets the total number of bursts by all carriers that caused a node to become infected.
+    pub fn infection_bursts(&self) -> usize {
+        self.carriers.iter().map(|c| c.infection_bursts).sum()
+    }
+
+    /// Gets the state of the node at the given location.
+    pub fn node(&self, loc: &Point2D) -> NodeState {
+        self.grid.get(loc)
+    }
+}
+
+/// Solver for AOC 2017 Day 22.
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = ProblemInput;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+    const ALGORITHMS: &'static [&'static str] = &["hashmap", "flat"];
+    const PARAMETERS: &'static [Parameter] = &[PART1_BURSTS, PART2_BURSTS];
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn apply_parameters(input: &mut Self::Input, params: &Parameters) {
+        input.part1_bursts = params.get(&PART1_BURSTS) as usize;
+        input.part2_bursts = params.get(&PART2_BURSTS) as usize;
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+
+    fn solve_part1_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "flat" => {
+                Some(conduct_bursts_flat(input, input.part1_bursts, &VirusRules::ORIGINAL).into())
+            }
+            _ => Self::solve_part1(input),
+        }
+    }
+
+    fn solve_part2_with(input: &Self::Input, algorithm: &str) -> Option<Answer> {
+        match algorithm {
+            "flat" => {
+                Some(conduct_bursts_flat(input, input.part2_bursts, &VirusRules::EVOLVED).into())
+            }
+            _ => Self::solve_part2(input),
+        }
+    }
+}
+
+/// Square grid of node states held in a flat vector, used by the "flat" algorithm in place of a
+/// HashMap. Nodes outside of the grid are clean, and the grid is doubled in size (keeping the
+/// existing nodes centred) when the carrier moves beyond its edge.
+///
+/// The grid is only grown while it holds at most [`MAX_FLAT_GRID_SIZE`] nodes along each side, and
+/// only to reach nodes within half its size of its edge. Other nodes (such as those around a
+/// carrier started far away from the starting grid) are held in a HashMap instead.
+struct FlatGrid {
+    size: i64,
+    offset: i64,
+    nodes: Vec<NodeState>,
+    distant: HashMap<Point2D, NodeState>,
+}
+
+impl FlatGrid {
+    /// Creates a new [`FlatGrid`] holding the nodes of the starting grid.
+    fn new(grid: &HashMap<Point2D, NodeState>, max_x: i64, max_y: i64) -> FlatGrid {
+        let size = 2 * (max_x.max(max_y) + 1);
+        let mut flat = FlatGrid {
+            size,
+            offset: size / 4,
+            nodes: vec![NodeState::Clean; (size * size) as usize],
+            distant: HashMap::new(),
+        };
+        for (loc, state) in grid.iter() {
+            *flat.get_mut(loc) = *state;
+        }
+        flat
+    }
+
+    /// Gets the state of the node at the given location. Nodes outside of the grid are clean.
+    fn get(&self, loc: &Point2D) -> NodeState {
+        match self.index(loc) {
+            Some(index) => self.nodes[index],
+            None => self.distant.get(loc).copied().unwrap_or(NodeState::Clean),
+        }
+    }
+
+    /// Gets a mutable reference to the state of the node at the given location, growing the grid
+    /// if the location is just outside of it (or holding the node sparsely if it is further away).
+    fn get_mut(&mut self, loc: &Point2D) -> &mut NodeState {
+        if self.index(loc).is_none() && self.size < MAX_FLAT_GRID_SIZE {
+            let margin = self.size / 2;
+            let (x, y) = (loc.x() + self.offset, loc.y() + self.offset);
+            if (-margin..self.size + margin).contains(&x)
+                && (-margin..self.size + margin).contains(&y)
+            {
+                self.grow();
+            }
+        }
+        match self.index(loc) {
+            Some(index) => &mut self.nodes[index],
+            None => self.distant.entry(*loc).or_insert(NodeState::Clean),
+        }
+    }
+
+    /// Gets the index of the node at the given location in the flat vector, or None if the location
+    /// is outside of the grid.
+    fn index(&self, loc: &Point2D) -> Option<usize> {
+        let (x, y) = (loc.x() + self.offset, loc.y() + self.offset);
+        if x < 0 || y < 0 || x >= self.size || y >= self.size {
+            return None;
+        }
+        Some((y * self.size + x) as usize)
+    }
+
+    /// Doubles the size of the grid, keeping the existing nodes centred.
+    fn grow(&mut self) {
+        let new_size = 2 * self.size;
+        let shift = self.size / 2;
+        let mut nodes = vec![NodeState::Clean; (new_size * new_size) as usize];
+        for (y, row) in self.nodes.chunks(self.size as usize).enumerate() {
+            let start = (y as i64 + shift) * new_size + shift;
+            nodes[start as usize..(start + self.size) as usize].copy_from_slice(row);
+        }
+        self.size = new_size;
+        self.offset += shift;
+        self.nodes = nodes;
+    }
+}
+
+/// Processes the AOC 2017 Day 22 input file in the format required by the solver functions.
+///
+/// Returned value is a [`ProblemInput`] containing the initial grid state given in the input file,
+/// the maximum x- and y-coordinates of grid locations and the default number of bursts for each
+/// part. The top left tile given in the input file is taken to have the location (x,y):(0,0).
+fn process_input_file(filename: &str) -> ProblemInput {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    let mut grid_state: HashMap<Point2D, NodeState> = HashMap::new();
+    let mut max_x: Option<usize> = None;
+    let mut max_y: Option<usize> = None;
+    for (y, row) in raw_input.lines().enumerate() {
+        // Track length of the current row
+        let mut row_max_x: Option<usize> = None;
+        for (x, tile) in row.trim().chars().enumerate() {
+            row_max_x = Some(x);
+            let loc = Point2D::new(i64::try_from(x).unwrap(), i64::try_from(y).unwrap());
+            match tile {
+                '.' => {
+                    grid_state.insert(loc, NodeState::Clean);
+                }
+                '#' => {
+                    grid_state.insert(loc, NodeState::Infected);
+                }
+                _c => panic!("Invalid character in input file at ({x},{y}): {_c}"),
+            }
+        }
+        // Check if the row was empty
+        if row_max_x.is_none() {
+            panic!("Empty row at row {y}!");
+        }
+        // Update expected row length and check that current row was not too long or short
+        if max_x.is_none() {
+            max_x = row_max_x;
+        } else if row_max_x != max_x {
+            panic!("Row {y} is not the same length as preceding rows in input file!");
+        }
+        // Update maximum observed y-coordinate
+        max_y = Some(y);
+    }
+    // Check that a maximum x- and y-coordinate have been found
+    if max_x.is_none() || max_y.is_none() {
+        panic!("Malformed input file - empty rows!");
+    }
+    ProblemInput {
+        grid: grid_state,
+        max_x: i64::try_from(max_x.unwrap()).unwrap(),
+        max_y: i64::try_from(max_y.unwrap()).unwrap(),
+        part1_bursts: PART1_BURSTS.default as usize,
+        part2_bursts: PART2_BURSTS.default as usize,
+    }
+}
+
+/// Solves AOC 2017 Day 22 Part 1.
+///
+/// Determines how many bursts of activity cause a node to become infected after 10,000 bursts of
+/// activity.
+fn solve_part1(input: &ProblemInput) -> usize {
+    conduct_bursts(input, input.part1_bursts, &VirusRules::ORIGINAL)
+}
+
+/// Solves AOC 2017 Day 22 Part 2.
+///
+/// Determines how many bursts of activity cause a node to become infect after 10,000,000 bursts of
+/// activity using an evolved virus.
+fn solve_part2(input: &ProblemInput) -> usize {
+    conduct_bursts(input, input.part2_bursts, &VirusRules::EVOLVED)
+}
+
+/// Determines the number of bursts of activity that cause a node to become infected.
+fn conduct_bursts(input: &ProblemInput, num_bursts: usize, rules: &VirusRules) -> usize {
+    conduct_bursts_with_history(input, num_bursts, rules, &[]).infection_bursts
+}
+
+/// Conducts the bursts of activity for a virus following the rules, starting from the grid given in
+/// the problem input with the carrier at its centre. The number of bursts that have caused a node
+/// to become infected is recorded at each of the checkpoints (given as a number of bursts, in any
+/// order), along with the nodes left infected at the end. Checkpoints beyond the number of bursts
+/// are ignored.
+///
+/// For example, checkpoints collected from `(100_000..=num_bursts).step_by(100_000)` record the
+/// growth of the infection every 100,000 bursts.
+pub fn conduct_bursts_with_history(
+    input: &ProblemInput,
+    num_bursts: usize,
+    rules: &VirusRules,
+    checkpoints: &[usize],
+) -> InfectionHistory {
+    let mut grid = input.grid.clone();
+    let mut pending = checkpoints
+        .iter()
+        .copied()
+        .filter(|&burst| burst <= num_bursts)
+        .collect::<Vec<usize>>();
+    pending.sort_unstable_by(|a, b| b.cmp(a));
+    pending.dedup();
+    let mut recorded: Vec<(usize, usize)> = Vec::with_capacity(pending.len());
+    // Initialise carrier location and direction
+    let mut loc_carrier = input.centre();
+    let mut dirn_carrier: CardinalDirection = CardinalDirection::North;
+    let mut infection_bursts: usize = 0;
+    for burst in 0..=num_bursts {
+        // Record the infection count at checkpoints before conducting the next burst
+        while pending.last() == Some(&burst) {
+            pending.pop();
+            recorded.push((burst, infection_bursts));
+        }
+        if burst == num_bursts {
+            break;
+        }
+        // Add surrounding locations to grid as clean nodes (if not already present)
+        add_surrounding_nodes_to_grid(&mut grid, &loc_carrier);
+        // Update carrier direction and node state, checking new state to count infection bursts
+        let (turn, new_state) = rules.rule(*grid.get(&loc_carrier).unwrap());
+        dirn_carrier = turn.apply(dirn_carrier);
+        grid.insert(loc_carrier, new_state);
+        if new_state == NodeState::Infected {
+            infection_bursts += 1;
+        }
+        // Update carrier location
+        loc_carrier += Point2D::from(dirn_carrier);
+    }
+    let infected = grid
+        .into_iter()
+        .filter(|(_, state)| *state == NodeState::Infected)
+        .map(|(loc, _)| loc)
+        .collect::<HashSet<Point2D>>();
+    InfectionHistory {
+        checkpoints: recorded,
+        infection_bursts,
+        infected,
+    }
+}
+
+/// Determines the number of bursts of activity that cause a node to become infected, with the
+/// nodes held in a [`FlatGrid`] instead of a HashMap (using a [`VirusSim`] with a single carrier).
+fn conduct_bursts_flat(input: &ProblemInput, num_bursts: usize, rules: &VirusRules) -> usize {
+    let mut sim = VirusSim::new(input, *rules);
+    sim.add_carrier(input.centre(), CardinalDirection::North);
+    sim.run(num_bursts);
+    sim.infection_bursts()
+}
+
+/// Gets the location of the node at the centre of a grid with the given maximum x- and
+/// y-coordinates.
+fn grid_centre(max_x: i64, max_y: i64) -> Point2D {
+    Point2D::new(max_x / 2 + max_x % 2, max_y / 2 + max_y % 2)
+}
+
+/// Adds clean nodes to the grid around the given location if they are not already recorded in the
+/// grid.
+fn add_surrounding_nodes_to_grid(grid: &mut HashMap<Point2D, NodeState>, loc: &Point2D) {
+    for loc_surround in loc.surrounding() {
+        grid.entry(loc_surround).or_insert(NodeState::Clean);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 22 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day22_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(5570, solution);
+    }
+
+    /// Tests the Day 22 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day22_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(2512022, solution);
+    }
+
+    /// Tests that the "hashmap" and "flat" algorithms for Day 22 agree, including after the flat
+    /// grid has been grown to hold the nodes visited by the carrier.
+    #[test]
+    fn test_day22_algorithms_agree() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        for (num_bursts, rules) in [
+            (PART1_BURSTS.default as usize, VirusRules::ORIGINAL),
+            (100_000, VirusRules::EVOLVED),
+        ] {
+            assert_eq!(
+                conduct_bursts(&input, num_bursts, &rules),
+                conduct_bursts_flat(&input, num_bursts, &rules)
+            );
+        }
+    }
+
+    /// Tests the infection counts recorded at checkpoints against the intermediate counts given in
+    /// the problem statement example.
+    #[test]
+    fn test_day22_conduct_bursts_with_history() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let history =
+            conduct_bursts_with_history(&input, 10_000, &VirusRules::ORIGINAL, &[10_000, 7, 70]);
+        assert_eq!(vec![(7, 5), (70, 41), (10_000, 5587)], history.checkpoints);
+        assert_eq!(5587, history.infection_bursts);
+        let history = conduct_bursts_with_history(&input, 100, &VirusRules::EVOLVED, &[100, 1_000]);
+        assert_eq!(vec![(100, 26)], history.checkpoints);
+        // Five nodes are left infected after seven bursts, as shown in the example
+        let history = conduct_bursts_with_history(&input, 7, &VirusRules::ORIGINAL, &[]);
+        assert_eq!(5, history.infected.len());
+    }
+
+    /// Tests the Day 22 solver methods with the number of bursts overridden, using the example given
+    /// in the problem statement.
+    #[test]
+    fn test_day22_parameters_example() {
+        let mut grid = (0..9)
+            .map(|i| (Point2D::new(i % 3, i / 3), NodeState::Clean))
+            .collect::<HashMap<Point2D, NodeState>>();
+        grid.insert(Point2D::new(2, 0), NodeState::Infected);
+        grid.insert(Point2D::new(0, 1), NodeState::Infected);
+        let mut input = ProblemInput {
+            grid,
+            max_x: 2,
+            max_y: 2,
+            part1_bursts: PART1_BURSTS.default as usize,
+            part2_bursts: PART2_BURSTS.default as usize,
+        };
+        let mut params = Parameters::new();
+        params.set("part1-bursts", 70);
+        params.set("part2-bursts", 100);
+        Day22::apply_parameters(&mut input, &params);
+        assert_eq!(41, solve_part1(&input));
+        assert_eq!(26, solve_part2(&input));
+        assert_eq!(
+            Some(Answer::from(26usize)),
+            Day22::solve_part2_with(&input, "flat")
+        );
+    }
+
+    /// Tests a user-defined virus, which moves in a straight line infecting every node it visits.
+    #[test]
+    fn test_day22_custom_virus_rules() {
+        let rules = VirusRules {
+            clean: (Turn::Straight, NodeState::Infected),
+            infected: (Turn::Straight, NodeState::Infected),
+            ..VirusRules::ORIGINAL
+        };
+        let input = ProblemInput {
+            grid: HashMap::from([(Point2D::new(0, 0), NodeState::Clean)]),
+            max_x: 0,
+            max_y: 0,
+            part1_bursts: PART1_BURSTS.default as usize,
+            part2_bursts: PART2_BURSTS.default as usize,
+        };
+        let history = conduct_bursts_with_history(&input, 50, &rules, &[]);
+        assert_eq!(50, history.infection_bursts);
+        assert!((0..50).all(|y| history.infected.contains(&Point2D::new(0, -y))));
+    }
+
+    /// Tests that a [`VirusSim`] with a single carrier started at the centre of the grid reproduces
+    /// the results of the example given in the problem statement.
+    #[test]
+    fn test_day22_virus_sim_single_carrier() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        for (rules, num_bursts, expected) in [
+            (VirusRules::ORIGINAL, 10_000, 5587),
+            (VirusRules::EVOLVED, 100, 26),
+        ] {
+            let mut sim = VirusSim::new(&input, rules);
+            assert_eq!(0, sim.add_carrier(input.centre(), CardinalDirection::North));
+            sim.run(num_bursts);
+            assert_eq!(expected, sim.infection_bursts());
+            assert_eq!(expected, sim.carriers()[0].infection_bursts);
+        }
+    }
+
+    /// Tests that carriers far enough apart not to meet each spread the infection as they would
+    /// alone, and that carriers sharing the grid take turns acting on the same nodes.
+    #[test]
+    fn test_day22_virus_sim_multiple_carriers() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let mut sim = VirusSim::new(&input, VirusRules::ORIGINAL);
+        sim.add_carrier(input.centre(), CardinalDirection::North);
+        let far = Point2D::new(200, -200);
+        assert_eq!(1, sim.add_carrier(far, CardinalDirection::East));
+        sim.run(70);
+        let mut alone = VirusSim::new(&input, VirusRules::ORIGINAL);
+        alone.add_carrier(far, CardinalDirection::East);
+        alone.run(70);
+        assert_eq!(41, sim.carriers()[0].infection_bursts);
+        assert_eq!(alone.carriers()[0], sim.carriers()[1]);
+        assert_eq!(41 + alone.infection_bursts(), sim.infection_bursts());
+        // Second carrier cleans the node infected by the first, then both turn right and move on
+        let mut sim = VirusSim::new(&input, VirusRules::ORIGINAL);
+        sim.add_carrier(input.centre(), CardinalDirection::North);
+        sim.add_carrier(input.centre(), CardinalDirection::South);
+        sim.burst();
+        assert_eq!(NodeState::Infected, sim.node(&input.centre()));
+        sim.burst();
+        assert_eq!(NodeState::Clean, sim.node(&input.centre()));
+        assert_eq!(
+            vec![(1, CardinalDirection::West), (0, CardinalDirection::West)],
+            sim.carriers()
+                .iter()
+                .map(|c| (c.infection_bursts, c.dirn))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// Tests that a carrier started far away from the starting grid does not grow the flat grid to
+    /// reach it, and spreads the infection as it would from the centre of a clean grid.
+    #[test]
+    fn test_day22_virus_sim_distant_carrier() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        let far = Point2D::new(1 << 20, 0);
+        let mut sim = VirusSim::new(&input, VirusRules::EVOLVED);
+        sim.add_carrier(far, CardinalDirection::North);
+        sim.run(10_000);
+        assert!(sim.grid.size <= MAX_FLAT_GRID_SIZE);
+        assert!(!sim.grid.distant.is_empty());
+        let mut clean_input = input.clone();
+        clean_input
+            .grid
+            .values_mut()
+            .for_each(|state| *state = NodeState::Clean);
+        let mut near = VirusSim::new(&clean_input, VirusRules::EVOLVED);
+        near.add_carrier(clean_input.centre(), CardinalDirection::North);
+        near.run(10_000);
+        assert_eq!(near.infection_bursts(), sim.infection_bursts());
+        assert_eq!(
+            near.carriers()[0].loc - clean_input.centre(),
+            sim.carriers()[0].loc - far
+        );
+    }
+
+    /// Tests the Day 22 Part 1 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day22_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(5587, solve_part1(&input));
+    }
+}
@@ -0,0 +1,131 @@
+use std::fs;
+
+use crate::scaffold::{Answer, Solver};
+use crate::utils::machines::soundcomputer::{Instruction, IsaProfile, SoundComputer};
+#[cfg(test)]
+use crate::utils::math::is_prime;
+
+const PROBLEM_NAME: &str = "Coprocessor Conflagration";
+const PROBLEM_INPUT_FILE: &str = "./input/day23.txt";
+const PROBLEM_DAY: u64 = 23;
+
+/// Solver for AOC 2017 Day 23.
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Vec<Instruction>;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part1(input).into())
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        Some(solve_part2(input).into())
+    }
+}
+
+/// Processes the AOC 2017 Day 23 input file in the format required by the solver functions.
+///
+/// Returned value is a vector of [`Instruction`] instances given by the lines of the input file.
+fn process_input_file(filename: &str) -> Vec<Instruction> {
+    // Read contents of problem input file
+    let raw_input = fs::read_to_string(filename).unwrap();
+    // Process input file contents into data structure
+    Instruction::parse_program(&raw_input).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Solves AOC 2017 Day 23 Part 1.
+///
+/// Determines the number of times the MUL (multiply) instruction is executed by the
+/// [`SoundComputer`] running the given program (vector of instructions), which is compiled to
+/// speed up execution.
+fn solve_part1(instructions: &[Instruction]) -> usize {
+    let mut sound_computer = SoundComputer::new(instructions, IsaProfile::Coprocessor).unwrap();
+    sound_computer.compile();
+    sound_computer.execute().unwrap();
+    sound_computer.get_mul_executions_count()
+}
+
+/// Solves AOC 2017 Day 23 Part 2.
+///
+/// Returns the value held in register "h" of SoundComputer after program execution halts, with
+/// debug switch toggled off (register "a" set to 1).
+///
+/// The program is run on an optimised [`SoundComputer`], which replaces the nested loop checking
+/// for composite numbers with a native operation.
+fn solve_part2(instructions: &[Instruction]) -> i64 {
+    let mut sound_comp = SoundComputer::new(instructions, IsaProfile::Coprocessor).unwrap();
+    sound_comp.update_register(&'a', 1).unwrap();
+    sound_comp.optimise();
+    sound_comp.execute().unwrap();
+    sound_comp.read_register(&'h').unwrap()
+}
+
+/// Calculates the Day 23 Part 2 solution directly from the constants coded into the program.
+///
+/// Program counts the number of composite numbers (increasing by the step coded into the
+/// second-last instruction) between a lower and upper limit. The lower and upper limits are
+/// calculated based on the seed value coded into the first instruction of the program.
+#[cfg(test)]
+fn count_composites_closed_form(instructions: &[Instruction]) -> usize {
+    // Extract seed and step values from the sound computer program
+    let sound_comp = SoundComputer::new(instructions, IsaProfile::Coprocessor).unwrap();
+    let seed = sound_comp.extract_last_arg_value(0).unwrap();
+    let step = sound_comp
+        .extract_last_arg_value(instructions.len() - 2)
+        .unwrap()
+        .unsigned_abs();
+    // Calculate lower and upper bounds for composite number check
+    let lower = seed * 100 + 100000;
+    let upper = lower + 17000;
+    // Count composite numbers between upper and lower bound
+    (lower..=upper)
+        .step_by(step as usize)
+        .filter(|&n| !is_prime(n as u64))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fixtures::example_path;
+
+    /// Tests the Day 23 Part 1 solver method against the actual problem solution.
+    #[test]
+    fn test_day23_part1_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part1(&input);
+        assert_eq!(6241, solution);
+    }
+
+    /// Tests the Day 23 Part 2 solver method against the actual problem solution.
+    #[test]
+    fn test_day23_part2_actual() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(909, solution);
+    }
+
+    /// Tests that the Day 23 Part 2 solver method agrees with the closed-form calculation.
+    #[test]
+    fn test_day23_part2_closed_form() {
+        let input = process_input_file(PROBLEM_INPUT_FILE);
+        let solution = solve_part2(&input);
+        assert_eq!(count_composites_closed_form(&input) as i64, solution);
+    }
+
+    /// Tests the Day 23 Part 1 solver method against the example input given in the problem statement.
+    #[test]
+    fn test_day23_example() {
+        let input = process_input_file(&example_path(PROBLEM_DAY, 1));
+        assert_eq!(3, solve_part1(&input));
+    }
+}
@@ -1 +1,4 @@
+pub mod days;
+pub mod prelude;
+pub mod scaffold;
 pub mod utils;
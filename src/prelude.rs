@@ -0,0 +1,4 @@
+pub use crate::scaffold::{Answer, Solver};
+pub use crate::utils::cartography::{CardinalDirection, Point2D, Point3D};
+pub use crate::utils::error::{AocError, InputFileParseError, ParseError};
+pub use crate::utils::machines;
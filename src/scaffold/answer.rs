@@ -0,0 +1,64 @@
+use std::fmt;
+
+/// Solution to one part of an AOC problem.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Answer {
+    Int(i128),
+    Uint(u128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Uint(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_answer_from!(Int, i128, i32, i64, isize, i128);
+impl_answer_from!(Uint, u128, u32, u64, usize, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that answers are displayed the same as the values they were created from.
+    #[test]
+    fn test_answer_display() {
+        assert_eq!("-42", Answer::from(-42i64).to_string());
+        assert_eq!("909", Answer::from(909usize).to_string());
+        assert_eq!(
+            "fgmobeaijhdpkcln",
+            Answer::from("fgmobeaijhdpkcln").to_string()
+        );
+        assert_eq!(Answer::Uint(7), Answer::from(7u32));
+    }
+}
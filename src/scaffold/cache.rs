@@ -0,0 +1,53 @@
+use std::cell::OnceCell;
+
+/// Intermediate product that is computed at most once and shared between the parts of a problem.
+///
+/// A [`Cache`] is stored in the problem input so that part 2 can reuse the work done by part 1 (or
+/// vice versa). Only values that are fully determined by the rest of the input should be cached.
+#[derive(Clone, Debug, Default)]
+pub struct Cache<T> {
+    cell: OnceCell<T>,
+}
+
+impl<T> Cache<T> {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Cache<T> {
+        Cache {
+            cell: OnceCell::new(),
+        }
+    }
+
+    /// Returns the cached value, computing it with the given function if it has not already been
+    /// computed.
+    pub fn get_or_compute(&self, compute: impl FnOnce() -> T) -> &T {
+        self.cell.get_or_init(compute)
+    }
+
+    /// Returns the cached value, or None if it has not been computed yet.
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the cached value is only computed on first use.
+    #[test]
+    fn test_cache_computes_once() {
+        let cache: Cache<u64> = Cache::new();
+        assert_eq!(None, cache.get());
+        let mut calls = 0;
+        assert_eq!(
+            42,
+            *cache.get_or_compute(|| {
+                calls += 1;
+                42
+            })
+        );
+        assert_eq!(42, *cache.get_or_compute(|| unreachable!()));
+        assert_eq!(Some(&42), cache.get());
+        assert_eq!(1, calls);
+    }
+}
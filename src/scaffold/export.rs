@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// Format of the problem input exported by the runner.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ExportFormat {
+    /// Graphviz DOT graph.
+    Dot,
+    /// JSON document.
+    Json,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Dot => write!(f, "DOT"),
+            ExportFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub mod answer;
+pub mod cache;
+pub mod export;
+pub mod parameters;
+pub mod solver;
+pub mod timings;
+
+pub use answer::Answer;
+pub use cache::Cache;
+pub use export::ExportFormat;
+pub use parameters::{Parameter, ParameterError, Parameters};
+pub use solver::Solver;
+pub use timings::{Phase, SolveOptions, TimedSolution, Timings, TimingsReport};
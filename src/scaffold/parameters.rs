@@ -0,0 +1,113 @@
+use std::fmt;
+
+/// Parameter of a problem that can be overridden when solving it (e.g. the number of rounds
+/// conducted), with the value given in the problem statement used by default.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Parameter {
+    /// Name used to select the parameter (e.g. "part1-rounds").
+    pub name: &'static str,
+    /// Value given in the problem statement.
+    pub default: u64,
+    /// Short description of the parameter, listed when an unknown parameter is given.
+    pub description: &'static str,
+}
+
+/// Custom error type indicating that a parameter override is not valid for a problem.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParameterError {
+    pub message: String,
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid parameter: {}", self.message)
+    }
+}
+
+/// Values given to override the default parameters of a problem, in the order they were given.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Parameters {
+    overrides: Vec<(String, u64)>,
+}
+
+impl Parameters {
+    /// Creates a new [`Parameters`] with no overrides.
+    pub fn new() -> Parameters {
+        Parameters::default()
+    }
+
+    /// Overrides the named parameter with the value, replacing any earlier override.
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.overrides.retain(|(existing, _)| existing != name);
+        self.overrides.push((name.to_string(), value));
+    }
+
+    /// Checks if no parameters have been overridden.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// Gets the overridden parameters, in the order they were given.
+    pub fn overrides(&self) -> &[(String, u64)] {
+        &self.overrides
+    }
+
+    /// Gets the value of the parameter, using its default if it has not been overridden.
+    pub fn get(&self, parameter: &Parameter) -> u64 {
+        self.overrides
+            .iter()
+            .find(|(name, _)| name == parameter.name)
+            .map_or(parameter.default, |(_, value)| *value)
+    }
+
+    /// Checks that every overridden parameter is one of the parameters supported by the problem.
+    pub fn validate(&self, supported: &[Parameter]) -> Result<(), ParameterError> {
+        for (name, _) in self.overrides.iter() {
+            if supported.iter().any(|parameter| parameter.name == name) {
+                continue;
+            }
+            let message = match supported.is_empty() {
+                true => format!("\"{name}\" (problem has no parameters)"),
+                false => format!(
+                    "\"{name}\" (expected one of: {})",
+                    supported
+                        .iter()
+                        .map(|parameter| format!(
+                            "{} [{}, default {}]",
+                            parameter.name, parameter.description, parameter.default
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            };
+            return Err(ParameterError { message });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that overridden parameters replace the defaults, and that unsupported parameters are
+    /// rejected.
+    #[test]
+    fn test_parameters_overrides() {
+        const ROUNDS: Parameter = Parameter {
+            name: "rounds",
+            default: 40,
+            description: "number of rounds",
+        };
+        let mut params = Parameters::new();
+        assert_eq!(40, params.get(&ROUNDS));
+        params.set("rounds", 5);
+        params.set("rounds", 10);
+        assert_eq!(10, params.get(&ROUNDS));
+        assert_eq!(&[(String::from("rounds"), 10)], params.overrides());
+        assert!(params.validate(&[ROUNDS]).is_ok());
+        assert!(params.validate(&[]).is_err());
+        params.set("steps", 3);
+        assert!(params.validate(&[ROUNDS]).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Instant;
+
+use crate::scaffold::{
+    Answer, ExportFormat, Parameter, Parameters, Phase, SolveOptions, TimedSolution, Timings,
+};
+
+/// Common interface implemented by the solver for each AOC 2017 problem.
+pub trait Solver {
+    /// Problem input, in the format required by the solver functions.
+    type Input;
+
+    /// Day number of the problem.
+    const DAY: u64;
+    /// Name of the problem.
+    const NAME: &'static str;
+    /// Location of the problem input file.
+    const INPUT_FILE: &'static str;
+    /// Names of the alternative algorithms that can be selected to solve the problem, with the
+    /// first being used by default. Empty if the problem only has a single implementation.
+    const ALGORITHMS: &'static [&'static str] = &[];
+    /// Parameters of the problem that can be overridden with the --param option, such as the number
+    /// of rounds conducted. Empty if the problem has no parameters.
+    const PARAMETERS: &'static [Parameter] = &[];
+
+    /// Processes the problem input file in the format required by the solver functions.
+    fn process_input_file(filename: &str) -> Self::Input;
+
+    /// Applies the parameter values (overridden or default) to the processed problem input. Called
+    /// by the runner after processing the input file, so the input holds the defaults beforehand.
+    ///
+    /// Does nothing unless overridden by a problem with parameters (default behaviour).
+    fn apply_parameters(_input: &mut Self::Input, _params: &Parameters) {}
+
+    /// Solves Part 1 of the problem.
+    ///
+    /// Returns None if the part has not been implemented yet.
+    fn solve_part1(input: &Self::Input) -> Option<Answer>;
+
+    /// Solves Part 2 of the problem.
+    ///
+    /// Returns None if the part has not been implemented yet.
+    fn solve_part2(input: &Self::Input) -> Option<Answer>;
+
+    /// Solves both parts of the problem together, for problems where both parts share most of the
+    /// work. The runner uses this in preference to solving each part separately.
+    ///
+    /// Returns None if the problem does not implement a combined solver (default behaviour).
+    fn solve_both(_input: &Self::Input) -> Option<(Answer, Answer)> {
+        None
+    }
+
+    /// Renders a visualisation of the problem input, printed by the runner when the --visualize
+    /// flag is given.
+    ///
+    /// Returns None if the problem does not support visualisation (default behaviour).
+    fn visualise(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Exports the structure of the problem input in the given format, written by the runner to
+    /// the file given with the --export (or --export-dot) option.
+    ///
+    /// Returns None if the problem does not support export in the format (default behaviour).
+    fn export(_input: &Self::Input, _format: ExportFormat) -> Option<String> {
+        None
+    }
+
+    /// Solves Part 1 of the problem using the named algorithm (one of [`Solver::ALGORITHMS`]).
+    ///
+    /// Uses the default implementation unless overridden by a problem with alternative algorithms.
+    fn solve_part1_with(input: &Self::Input, _algorithm: &str) -> Option<Answer> {
+        Self::solve_part1(input)
+    }
+
+    /// Solves Part 2 of the problem using the named algorithm (one of [`Solver::ALGORITHMS`]).
+    ///
+    /// Uses the default implementation unless overridden by a problem with alternative algorithms.
+    fn solve_part2_with(input: &Self::Input, _algorithm: &str) -> Option<Answer> {
+        Self::solve_part2(input)
+    }
+
+    /// Processes the input file, applies the parameters and solves each part of the problem in
+    /// turn, returning the solutions along with the time taken by each phase.
+    ///
+    /// Each part is always solved on its own (never with [`Solver::solve_both`]), so that the time
+    /// taken by each part is known.
+    fn solve_timed(filename: &str, params: &Parameters) -> TimedSolution {
+        Self::solve_timed_with(filename, params, &SolveOptions::default(), |_| ()).0
+    }
+
+    /// Processes the input file, applies the parameters and solves the parts of the problem
+    /// selected by the options, returning the solutions and the time taken by each phase along with
+    /// the processed input.
+    ///
+    /// The hook is called at the end of each phase, outside of the time taken by the phase, so that
+    /// the caller can measure anything else about it (such as the memory allocated).
+    fn solve_timed_with(
+        filename: &str,
+        params: &Parameters,
+        options: &SolveOptions,
+        mut on_phase_end: impl FnMut(Phase),
+    ) -> (TimedSolution, Self::Input) {
+        let mut timings = Timings::default();
+        let mut phase_start = Instant::now();
+        let mut end_phase = |timings: &mut Timings, phase| {
+            timings.push(phase, phase_start.elapsed());
+            on_phase_end(phase);
+            phase_start = Instant::now();
+        };
+        // Input processing
+        let mut input = Self::process_input_file(filename);
+        Self::apply_parameters(&mut input, params);
+        end_phase(&mut timings, Phase::Input);
+        // Solve both parts together if selected and supported, otherwise solve each part separately
+        let both = match options.combined && options.part1 && options.part2 {
+            true if options.algorithm.is_none() => Self::solve_both(&input),
+            _ => None,
+        };
+        let (part1, part2) = if let Some((part1, part2)) = both {
+            end_phase(&mut timings, Phase::Both);
+            (Some(part1), Some(part2))
+        } else {
+            let mut part1 = None;
+            if options.part1 {
+                part1 = match options.algorithm {
+                    Some(algorithm) => Self::solve_part1_with(&input, algorithm),
+                    None => Self::solve_part1(&input),
+                };
+                end_phase(&mut timings, Phase::Part1);
+            }
+            let mut part2 = None;
+            if options.part2 {
+                part2 = match options.algorithm {
+                    Some(algorithm) => Self::solve_part2_with(&input, algorithm),
+                    None => Self::solve_part2(&input),
+                };
+                end_phase(&mut timings, Phase::Part2);
+            }
+            (part1, part2)
+        };
+        let solution = TimedSolution {
+            part1,
+            part2,
+            timings,
+        };
+        (solution, input)
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::scaffold::Answer;
+
+/// Phase of solving a problem, timed separately by [`crate::scaffold::Solver::solve_timed_with`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /// Processing the input file (including applying any parameters).
+    Input,
+    /// Solving part 1 on its own.
+    Part1,
+    /// Solving part 2 on its own.
+    Part2,
+    /// Solving both parts together (see [`crate::scaffold::Solver::solve_both`]).
+    Both,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Input => write!(f, "Input"),
+            Phase::Part1 => write!(f, "Part 1"),
+            Phase::Part2 => write!(f, "Part 2"),
+            Phase::Both => write!(f, "Both"),
+        }
+    }
+}
+
+/// Parts of a problem solved by [`crate::scaffold::Solver::solve_timed_with`], and how they are
+/// solved. By default, both parts are solved on their own with the default algorithm.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SolveOptions<'a> {
+    /// Whether part 1 is solved.
+    pub part1: bool,
+    /// Whether part 2 is solved.
+    pub part2: bool,
+    /// Alternative algorithm used to solve each part (one of
+    /// [`crate::scaffold::Solver::ALGORITHMS`]), or None to use the default algorithm.
+    pub algorithm: Option<&'a str>,
+    /// Whether both parts are solved together with [`crate::scaffold::Solver::solve_both`] where
+    /// supported. Only applies when both parts are selected and no algorithm is.
+    pub combined: bool,
+}
+
+impl Default for SolveOptions<'_> {
+    fn default() -> Self {
+        SolveOptions {
+            part1: true,
+            part2: true,
+            algorithm: None,
+            combined: false,
+        }
+    }
+}
+
+/// Execution times for each phase of solving a problem, in the order the phases were run.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Timings {
+    phases: Vec<(Phase, Duration)>,
+}
+
+impl Timings {
+    /// Creates a new [`Timings`] with the execution time of each phase, in the order they were run.
+    pub fn new(phases: Vec<(Phase, Duration)>) -> Timings {
+        Timings { phases }
+    }
+
+    /// Adds the execution time of the next phase run.
+    pub fn push(&mut self, phase: Phase, duration: Duration) {
+        self.phases.push((phase, duration));
+    }
+
+    /// Gets the execution time of each phase, in the order the phases were run.
+    pub fn phases(&self) -> &[(Phase, Duration)] {
+        &self.phases
+    }
+
+    /// Gets the execution time of the phase, or zero if the phase was not run.
+    pub fn get(&self, phase: Phase) -> Duration {
+        self.phases
+            .iter()
+            .filter(|(p, _)| *p == phase)
+            .map(|(_, duration)| *duration)
+            .sum()
+    }
+
+    /// Calculates the total execution time across all phases.
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+/// Solutions to the parts of a problem, along with the time taken by each phase of solving it (see
+/// [`crate::scaffold::Solver::solve_timed`]).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TimedSolution {
+    /// Solution to part 1, or None if the part is not implemented (or was not selected).
+    pub part1: Option<Answer>,
+    /// Solution to part 2, or None if the part is not implemented (or was not selected).
+    pub part2: Option<Answer>,
+    /// Execution time of each phase run to solve the problem, starting with processing the input.
+    pub timings: Timings,
+}
+
+/// Accumulates the [`Timings`] of several problems, to calculate totals and percentiles across
+/// them.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct TimingsReport {
+    days: Vec<(u64, Timings)>,
+}
+
+impl TimingsReport {
+    /// Creates a new empty [`TimingsReport`].
+    pub fn new() -> TimingsReport {
+        TimingsReport::default()
+    }
+
+    /// Adds the timings for the given day to the report.
+    pub fn add(&mut self, day: u64, timings: Timings) {
+        self.days.push((day, timings));
+    }
+
+    /// Gets the timings added for each day, in the order they were added.
+    pub fn days(&self) -> &[(u64, Timings)] {
+        &self.days
+    }
+
+    /// Checks if no timings have been added to the report.
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    /// Calculates the sum of each phase (processing the input, then solving each part on its own)
+    /// across all days.
+    pub fn total(&self) -> Timings {
+        let phase_total = |phase| {
+            self.days
+                .iter()
+                .map(|(_, timings)| timings.get(phase))
+                .sum::<Duration>()
+        };
+        Timings::new(
+            [Phase::Input, Phase::Part1, Phase::Part2]
+                .map(|phase| (phase, phase_total(phase)))
+                .to_vec(),
+        )
+    }
+
+    /// Gets the pth percentile (0 to 100) of the total execution time of each day, using the
+    /// nearest-rank method. Returns None if the report is empty.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        let mut totals = self
+            .days
+            .iter()
+            .map(|(_, timings)| timings.total())
+            .collect::<Vec<Duration>>();
+        totals.sort_unstable();
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * totals.len() as f64).ceil() as usize;
+        totals.get(rank.max(1) - 1).copied()
+    }
+
+    /// Gets the day with the longest total execution time, along with that time. Returns None if
+    /// the report is empty.
+    pub fn slowest(&self) -> Option<(u64, Duration)> {
+        self.days
+            .iter()
+            .map(|(day, timings)| (*day, timings.total()))
+            .max_by_key(|(_, total)| *total)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::days::day01::Day01;
+    use crate::scaffold::{Parameters, Solver};
+    use crate::utils::fixtures::example_path;
+
+    /// Tests that the solutions are returned along with the timings, through the library API.
+    #[test]
+    fn test_solve_timed() {
+        let solution = Day01::solve_timed(&example_path(1, 1), &Parameters::new());
+        assert_eq!(Some(Answer::from(3u32)), solution.part1);
+        assert_eq!(Some(Answer::from(0u32)), solution.part2);
+        let phases = solution
+            .timings
+            .phases()
+            .iter()
+            .map(|(phase, _)| *phase)
+            .collect::<Vec<Phase>>();
+        assert_eq!(vec![Phase::Input, Phase::Part1, Phase::Part2], phases);
+        assert!(solution.timings.total() >= solution.timings.get(Phase::Input));
+    }
+
+    /// Tests the totals and percentiles calculated across several days.
+    #[test]
+    fn test_timings_report() {
+        let mut report = TimingsReport::new();
+        assert_eq!(None, report.percentile(50.0));
+        assert_eq!(None, report.slowest());
+        let ms = Duration::from_millis;
+        for (day, part1) in [(1, 4), (2, 1), (3, 9), (4, 2)] {
+            let timings = Timings::new(vec![
+                (Phase::Input, ms(1)),
+                (Phase::Part1, ms(part1)),
+                (Phase::Part2, ms(10)),
+            ]);
+            report.add(day, timings);
+        }
+        let total = report.total();
+        assert_eq!(
+            (ms(4), ms(16), ms(40)),
+            (
+                total.get(Phase::Input),
+                total.get(Phase::Part1),
+                total.get(Phase::Part2)
+            )
+        );
+        assert_eq!(ms(60), total.total());
+        assert_eq!(Some(ms(12)), report.percentile(0.0));
+        assert_eq!(Some(ms(13)), report.percentile(50.0));
+        assert_eq!(Some(ms(15)), report.percentile(75.0));
+        assert_eq!(Some(ms(20)), report.percentile(90.0));
+        assert_eq!(Some(ms(20)), report.percentile(100.0));
+        assert_eq!(Some((3, ms(20))), report.slowest());
+    }
+}
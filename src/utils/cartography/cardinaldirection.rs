@@ -0,0 +1,91 @@
+/// The four cardinal directions.
+///
+/// Unit vectors use screen coordinates, with North pointing in the negative y-direction.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardinalDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl CardinalDirection {
+    /// All four directions, in clockwise order starting from North.
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
+    /// Returns the (dx, dy) unit vector for the direction.
+    pub fn unit_vector(&self) -> (i64, i64) {
+        match self {
+            CardinalDirection::North => (0, -1),
+            CardinalDirection::East => (1, 0),
+            CardinalDirection::South => (0, 1),
+            CardinalDirection::West => (-1, 0),
+        }
+    }
+
+    /// Returns the direction matching the (dx, dy) unit vector, or None if the vector is not a unit
+    /// vector along the x- or y-axis.
+    pub fn from_delta(dx: i64, dy: i64) -> Option<CardinalDirection> {
+        match (dx, dy) {
+            (0, -1) => Some(CardinalDirection::North),
+            (1, 0) => Some(CardinalDirection::East),
+            (0, 1) => Some(CardinalDirection::South),
+            (-1, 0) => Some(CardinalDirection::West),
+            _ => None,
+        }
+    }
+
+    /// Returns the direction resulting from a 90-degree turn to the left (counterclockwise).
+    pub fn turn_left(&self) -> CardinalDirection {
+        match self {
+            CardinalDirection::North => CardinalDirection::West,
+            CardinalDirection::East => CardinalDirection::North,
+            CardinalDirection::South => CardinalDirection::East,
+            CardinalDirection::West => CardinalDirection::South,
+        }
+    }
+
+    /// Returns the direction resulting from a 90-degree turn to the right (clockwise).
+    pub fn turn_right(&self) -> CardinalDirection {
+        match self {
+            CardinalDirection::North => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::North,
+        }
+    }
+
+    /// Returns the opposite direction.
+    pub fn reverse(&self) -> CardinalDirection {
+        self.turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that turning and converting to and from unit vectors are consistent for all
+    /// directions.
+    #[test]
+    fn test_direction_helpers_round_trip() {
+        for dirn in CardinalDirection::ALL {
+            let (dx, dy) = dirn.unit_vector();
+            assert_eq!(Some(dirn), CardinalDirection::from_delta(dx, dy));
+            assert_eq!(dirn, dirn.turn_left().turn_right());
+            assert_eq!((-dx, -dy), dirn.reverse().unit_vector());
+            // Turning right rotates the unit vector clockwise in screen coordinates
+            assert_eq!((-dy, dx), dirn.turn_right().unit_vector());
+        }
+        for pair in CardinalDirection::ALL.windows(2) {
+            assert_eq!(pair[1], pair[0].turn_right());
+        }
+        assert_eq!(None, CardinalDirection::from_delta(1, 1));
+    }
+}
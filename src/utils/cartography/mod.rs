@@ -0,0 +1,7 @@
+pub mod cardinaldirection;
+pub mod point2d;
+pub mod point3d;
+
+pub use cardinaldirection::CardinalDirection;
+pub use point2d::Point2D;
+pub use point3d::Point3D;
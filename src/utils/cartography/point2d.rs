@@ -0,0 +1,163 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+use crate::utils::cartography::CardinalDirection;
+
+/// Offsets to the four orthogonally adjacent points, in the order North, East, South, West.
+const ORTHOGONAL_OFFSETS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight surrounding points, in reading order.
+const SURROUNDING_OFFSETS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A point in two-dimensional space with integer coordinates. The y-axis points down (screen
+/// coordinates), so North is in the negative y-direction.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2D {
+    x: i64,
+    y: i64,
+}
+
+impl Point2D {
+    /// Creates a new [`Point2D`] with the given coordinates.
+    pub fn new(x: i64, y: i64) -> Point2D {
+        Point2D { x, y }
+    }
+
+    /// Returns the value of the "x" field.
+    pub fn x(&self) -> i64 {
+        self.x
+    }
+
+    /// Returns the value of the "y" field.
+    pub fn y(&self) -> i64 {
+        self.y
+    }
+
+    /// Moves the point by the given deltas.
+    pub fn shift(&mut self, dx: i64, dy: i64) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    /// Calculates the Manhattan distance between the point and the other point.
+    pub fn manhattan(&self, other: &Point2D) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns an iterator over the four orthogonally adjacent points, in the order North, East,
+    /// South, West.
+    pub fn neighbours(&self) -> impl Iterator<Item = Point2D> {
+        let origin = *self;
+        ORTHOGONAL_OFFSETS
+            .into_iter()
+            .map(move |(dx, dy)| Point2D::new(origin.x + dx, origin.y + dy))
+    }
+
+    /// Returns an iterator over the eight surrounding points (including diagonals), in reading
+    /// order.
+    pub fn surrounding(&self) -> impl Iterator<Item = Point2D> {
+        let origin = *self;
+        SURROUNDING_OFFSETS
+            .into_iter()
+            .map(move |(dx, dy)| Point2D::new(origin.x + dx, origin.y + dy))
+    }
+}
+
+impl fmt::Display for Point2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<CardinalDirection> for Point2D {
+    /// Converts the direction into its unit vector.
+    fn from(dirn: CardinalDirection) -> Point2D {
+        let (dx, dy) = dirn.unit_vector();
+        Point2D::new(dx, dy)
+    }
+}
+
+impl Add for Point2D {
+    type Output = Point2D;
+
+    fn add(self, rhs: Point2D) -> Point2D {
+        Point2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point2D {
+    fn add_assign(&mut self, rhs: Point2D) {
+        self.shift(rhs.x, rhs.y);
+    }
+}
+
+impl Sub for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, rhs: Point2D) -> Point2D {
+        Point2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point2D {
+    fn sub_assign(&mut self, rhs: Point2D) {
+        self.shift(-rhs.x, -rhs.y);
+    }
+}
+
+impl Mul<i64> for Point2D {
+    type Output = Point2D;
+
+    fn mul(self, rhs: i64) -> Point2D {
+        Point2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the neighbour iterators, Manhattan distance and display format.
+    #[test]
+    fn test_point2d_neighbours() {
+        let loc = Point2D::new(2, -3);
+        assert_eq!(
+            vec![
+                Point2D::new(2, -4),
+                Point2D::new(3, -3),
+                Point2D::new(2, -2),
+                Point2D::new(1, -3)
+            ],
+            loc.neighbours().collect::<Vec<Point2D>>()
+        );
+        assert_eq!(8, loc.surrounding().count());
+        assert!(loc
+            .surrounding()
+            .all(|other| other != loc && other.manhattan(&loc) <= 2));
+        assert_eq!("(2, -3)", loc.to_string());
+    }
+
+    /// Tests the arithmetic operators and conversion from a direction.
+    #[test]
+    fn test_point2d_arithmetic() {
+        let a = Point2D::new(2, -3);
+        let b = Point2D::new(-1, 4);
+        assert_eq!(Point2D::new(1, 1), a + b);
+        assert_eq!(Point2D::new(3, -7), a - b);
+        assert_eq!(Point2D::new(-6, 9), a * -3);
+        assert_eq!(10, a.manhattan(&b));
+        let mut c = a;
+        c += Point2D::from(CardinalDirection::West) * 2;
+        assert_eq!(Point2D::new(0, -3), c);
+    }
+}
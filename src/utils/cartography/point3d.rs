@@ -0,0 +1,110 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// Offsets to the six orthogonally adjacent points.
+const ORTHOGONAL_OFFSETS: [(i64, i64, i64); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// A point in three-dimensional space with integer coordinates.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3D {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point3D {
+    /// Creates a new [`Point3D`] with the given coordinates.
+    pub fn new(x: i64, y: i64, z: i64) -> Point3D {
+        Point3D { x, y, z }
+    }
+
+    /// Returns the value of the "x" field.
+    pub fn x(&self) -> i64 {
+        self.x
+    }
+
+    /// Returns the value of the "y" field.
+    pub fn y(&self) -> i64 {
+        self.y
+    }
+
+    /// Returns the value of the "z" field.
+    pub fn z(&self) -> i64 {
+        self.z
+    }
+
+    /// Moves the point by the given deltas.
+    pub fn shift(&mut self, dx: i64, dy: i64, dz: i64) {
+        self.x += dx;
+        self.y += dy;
+        self.z += dz;
+    }
+
+    /// Calculates the Manhattan distance between the point and the other point.
+    pub fn manhattan(&self, other: &Point3D) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Calculates the Manhattan distance between the point and the origin.
+    pub fn manhattan_origin(&self) -> u64 {
+        self.manhattan(&Point3D::default())
+    }
+
+    /// Returns an iterator over the six orthogonally adjacent points.
+    pub fn neighbours(&self) -> impl Iterator<Item = Point3D> {
+        let origin = *self;
+        ORTHOGONAL_OFFSETS
+            .into_iter()
+            .map(move |(dx, dy, dz)| Point3D::new(origin.x + dx, origin.y + dy, origin.z + dz))
+    }
+}
+
+impl fmt::Display for Point3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Point3D {
+    type Output = Point3D;
+
+    fn add(self, rhs: Point3D) -> Point3D {
+        Point3D::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3D {
+    fn add_assign(&mut self, rhs: Point3D) {
+        self.shift(rhs.x, rhs.y, rhs.z);
+    }
+}
+
+impl Sub for Point3D {
+    type Output = Point3D;
+
+    fn sub(self, rhs: Point3D) -> Point3D {
+        Point3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3D {
+    fn sub_assign(&mut self, rhs: Point3D) {
+        self.shift(-rhs.x, -rhs.y, -rhs.z);
+    }
+}
+
+impl Mul<i64> for Point3D {
+    type Output = Point3D;
+
+    fn mul(self, rhs: i64) -> Point3D {
+        Point3D::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
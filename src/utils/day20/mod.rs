@@ -1,3 +1,5 @@
 pub mod particle3d;
+pub mod swarm;
 
-pub use particle3d::Particle3D;
+pub use particle3d::{rank_long_term, Particle3D};
+pub use swarm::{SwarmSimulation, SwarmState};
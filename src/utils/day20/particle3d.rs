@@ -1,15 +1,13 @@
-use aoc_utils::cartography::Point3D;
+use crate::utils::cartography::Point3D;
 
 /// A particle existing in three-dimensional space. Each particle has a three-dimensional location,
 /// velocity and acceleration.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Particle3D {
     loc: Point3D,
     vel: Point3D,
     acc: Point3D,
-    loc_manh: u64,
-    vel_manh: u64,
-    acc_manh: u64,
 }
 
 impl Particle3D {
@@ -18,39 +16,101 @@ impl Particle3D {
             loc: *loc,
             vel: *vel,
             acc: *acc,
-            loc_manh: loc.get_manhattan_distance_origin(),
-            vel_manh: vel.get_manhattan_distance_origin(),
-            acc_manh: acc.get_manhattan_distance_origin(),
         }
     }
 
     /// Updates the velocity and location of the particle.
     pub fn tick(&mut self) {
         // Update velocity by acceleration
-        self.vel.shift(self.acc.x(), self.acc.y(), self.acc.z());
+        self.vel += self.acc;
         // Update location by velocity
-        self.loc.shift(self.vel.x(), self.vel.y(), self.vel.z());
+        self.loc += self.vel;
     }
 
     /// Returns the value of the "loc" field.
     pub fn loc(&self) -> &Point3D {
         &self.loc
     }
+
+    /// Calculates the long-term Manhattan distance of the particle from the origin.
+    ///
+    /// After t more ticks, each coordinate is at 2p(t) = a.t^2 + (2v + a).t + 2p. Once the sign of
+    /// every coordinate has settled, twice the Manhattan distance from the origin is therefore a
+    /// quadratic in t. Returned value is the coefficients of that quadratic, highest order first,
+    /// so comparing the returned tuples orders particles by their long-term distance.
+    pub fn long_term_distance(&self) -> (i64, i64, i64) {
+        let axes = [
+            (self.loc.x(), self.vel.x(), self.acc.x()),
+            (self.loc.y(), self.vel.y(), self.acc.y()),
+            (self.loc.z(), self.vel.z(), self.acc.z()),
+        ];
+        axes.iter().fold((0, 0, 0), |(a2, a1, a0), &(p, v, a)| {
+            // Sign the coordinate takes on in the long-term
+            let sign = match (a.signum(), v.signum()) {
+                (0, 0) => p.signum(),
+                (0, v_sign) => v_sign,
+                (a_sign, _) => a_sign,
+            };
+            (a2 + sign * a, a1 + sign * (2 * v + a), a0 + sign * 2 * p)
+        })
+    }
 }
 
-impl PartialOrd for Particle3D {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // Compare absolute acceleration
-        match self.acc_manh.partial_cmp(&other.acc_manh) {
-            Some(std::cmp::Ordering::Equal) => {}
-            ord => return ord,
-        }
-        // Compare absolute velocity
-        match self.vel_manh.partial_cmp(&other.vel_manh) {
-            Some(core::cmp::Ordering::Equal) => {}
-            ord => return ord,
+/// Ranks the particles by their long-term distance from the origin, closest first.
+///
+/// Returned value is groups of particle indices. Particles in the same group remain at the same
+/// Manhattan distance from the origin as each other forever, so are genuinely tied.
+pub fn rank_long_term(particles: &[Particle3D]) -> Vec<Vec<usize>> {
+    let mut ranked = particles
+        .iter()
+        .map(|p| p.long_term_distance())
+        .enumerate()
+        .collect::<Vec<(usize, (i64, i64, i64))>>();
+    ranked.sort_by_key(|&(index, dist)| (dist, index));
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut last_dist = None;
+    for (index, dist) in ranked {
+        match groups.last_mut() {
+            Some(group) if last_dist == Some(dist) => group.push(index),
+            _ => groups.push(vec![index]),
         }
-        // Compare absolute location
-        self.loc_manh.partial_cmp(&other.loc_manh)
+        last_dist = Some(dist);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates a particle moving only along the x-axis.
+    fn particle_x(p: i64, v: i64, a: i64) -> Particle3D {
+        Particle3D::new(
+            &Point3D::new(p, 0, 0),
+            &Point3D::new(v, 0, 0),
+            &Point3D::new(a, 0, 0),
+        )
+    }
+
+    /// Tests the ranking against the example given in the problem statement, and for particles with
+    /// equal acceleration that are decided by velocity heading away from or towards the origin.
+    #[test]
+    fn test_rank_long_term() {
+        let particles = [particle_x(3, 2, -1), particle_x(4, 0, -2)];
+        assert_eq!(vec![vec![0], vec![1]], rank_long_term(&particles));
+        // Equal absolute velocity, but particle 1 starts by heading towards the origin
+        let particles = [
+            particle_x(0, 5, 1),
+            particle_x(0, -5, 1),
+            particle_x(-3, 0, 1),
+        ];
+        assert_eq!(vec![vec![1], vec![2], vec![0]], rank_long_term(&particles));
+        // Mirror images remain at the same distance forever
+        let particles = [
+            particle_x(2, 1, 0),
+            particle_x(-2, -1, 0),
+            particle_x(9, 0, 0),
+        ];
+        assert_eq!(vec![vec![2], vec![0, 1]], rank_long_term(&particles));
     }
 }
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::utils::cartography::Point3D;
+
+use crate::utils::day20::Particle3D;
+
+/// State of the particle swarm at a single tick of the simulation.
+#[derive(Clone)]
+pub struct SwarmState {
+    /// Number of ticks that have elapsed since the start of the simulation.
+    pub tick: u64,
+    /// Particles remaining in the swarm, keyed by their original position in the swarm.
+    pub particles: HashMap<usize, Particle3D>,
+}
+
+impl SwarmState {
+    /// Returns the ids of the particles remaining in the swarm, in ascending order.
+    pub fn surviving_ids(&self) -> Vec<usize> {
+        let mut ids = self.particles.keys().copied().collect::<Vec<usize>>();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the location of each particle remaining in the swarm, in ascending order of id.
+    pub fn positions(&self) -> Vec<(usize, Point3D)> {
+        self.surviving_ids()
+            .into_iter()
+            .map(|id| (id, *self.particles[&id].loc()))
+            .collect()
+    }
+}
+
+/// Iterator that simulates the particle swarm, yielding the state of the swarm at each tick.
+///
+/// Particles that occupy the same location at the end of a tick collide and are removed from the
+/// swarm. The first state yielded is for tick 0, after the removal of any particles starting at
+/// the same location. The simulation never ends, so the caller decides when to stop.
+pub struct SwarmSimulation {
+    state: SwarmState,
+    started: bool,
+}
+
+impl SwarmSimulation {
+    /// Creates a new [`SwarmSimulation`] for the given particles. Each particle is identified by its
+    /// index in the slice.
+    pub fn new(particles: &[Particle3D]) -> SwarmSimulation {
+        SwarmSimulation {
+            state: SwarmState {
+                tick: 0,
+                particles: particles.iter().copied().enumerate().collect(),
+            },
+            started: false,
+        }
+    }
+}
+
+impl Iterator for SwarmSimulation {
+    type Item = SwarmState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started {
+            // Move all particles
+            for p in self.state.particles.values_mut() {
+                p.tick();
+            }
+            self.state.tick += 1;
+        }
+        self.started = true;
+        remove_collided_particles(&mut self.state.particles);
+        Some(self.state.clone())
+    }
+}
+
+/// Removes particles that have collided.
+fn remove_collided_particles(particles: &mut HashMap<usize, Particle3D>) {
+    // Determine number of particles at each unique location occupied
+    let mut locations: HashMap<Point3D, usize> = HashMap::new();
+    for p in particles.values() {
+        *locations.entry(*p.loc()).or_insert(0) += 1;
+    }
+    // Remove collided particles
+    particles.retain(|_, p| locations[p.loc()] == 1);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the simulation against the collision example given in the problem statement.
+    #[test]
+    fn test_swarm_simulation_collisions() {
+        let particles = [(-6, 3), (-4, 2), (-2, 1), (3, -1)]
+            .iter()
+            .map(|&(x, vx)| {
+                Particle3D::new(
+                    &Point3D::new(x, 0, 0),
+                    &Point3D::new(vx, 0, 0),
+                    &Point3D::new(0, 0, 0),
+                )
+            })
+            .collect::<Vec<Particle3D>>();
+        let states = SwarmSimulation::new(&particles)
+            .take(4)
+            .collect::<Vec<SwarmState>>();
+        assert_eq!(vec![0, 1, 2, 3], states[1].surviving_ids());
+        assert_eq!(1, states[1].tick);
+        assert_eq!(vec![(3, Point3D::new(1, 0, 0))], states[2].positions());
+        assert_eq!(vec![3], states[3].surviving_ids());
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// Custom error type indicating that the problem input could not be processed, with context
+/// locating the cause of the failure within the input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AocError {
+    /// A line of the input could not be parsed. Line numbers start at 1.
+    LineParse { line: usize, text: String },
+    /// An element of a separated list in the input could not be parsed. Element numbers start at 1.
+    ElementParse { element: usize, text: String },
+    /// A value within a line of the input could not be parsed. Line and column numbers (counted in
+    /// characters) start at 1, with the column giving the start of the value.
+    ValueParse {
+        line: usize,
+        column: usize,
+        text: String,
+    },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::LineParse { line, text } => {
+                write!(f, "Parsing of input failed at line {line} [{text}]")
+            }
+            AocError::ElementParse { element, text } => {
+                write!(f, "Parsing of input failed at element {element} [{text}]")
+            }
+            AocError::ValueParse { line, column, text } => write!(
+                f,
+                "Parsing of input failed at line {line}, column {column} [{text}]"
+            ),
+        }
+    }
+}
@@ -1,3 +1,9 @@
+pub mod aocerror;
 pub mod inputfileparseerror;
+pub mod overflowerror;
+pub mod parseerror;
 
+pub use aocerror::AocError;
 pub use inputfileparseerror::InputFileParseError;
+pub use overflowerror::OverflowError;
+pub use parseerror::ParseError;
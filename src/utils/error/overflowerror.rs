@@ -0,0 +1,14 @@
+use std::fmt;
+
+/// Custom error type indicating that a counter overflowed while solving a problem, typically when
+/// the problem parameters have been overridden with much larger values than the problem statement.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    pub message: String,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Counter overflowed: {}", self.message)
+    }
+}
@@ -0,0 +1,14 @@
+use std::fmt;
+
+/// Custom error type indicating that one of the parser helpers in [`crate::utils::parse`] failed.
+/// The message describes what was expected and the input remaining at the point of failure.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parsing failed: {}", self.message)
+    }
+}
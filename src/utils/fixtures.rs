@@ -0,0 +1,50 @@
+use std::fs;
+
+/// Directory holding the example input fixtures, so that they are found from any working directory.
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// Gets the path of the nth example input fixture for the day (numbered from 1), in the format
+/// "examples/dayNN_example{n}.txt".
+pub fn example_path(day: u64, n: u64) -> String {
+    format!("{EXAMPLES_DIR}/day{day:02}_example{n}.txt")
+}
+
+/// Loads the nth example input fixture for the day (numbered from 1). Example inputs are taken
+/// from the problem statements, in the same format as the problem input files.
+///
+/// Panics if the fixture does not exist, since a missing fixture is a mistake in the test itself.
+pub fn example(day: u64, n: u64) -> String {
+    let path = example_path(day, n);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to load example {path}: {e}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+
+    /// Tests that every implemented day (each "dayNN.rs" solver module) has at least one example
+    /// input fixture.
+    #[test]
+    fn test_every_day_has_example() {
+        let mut days = fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/src/days"))
+            .unwrap()
+            .filter_map(|entry| {
+                let name = entry.unwrap().file_name().into_string().unwrap();
+                name.strip_prefix("day")?
+                    .strip_suffix(".rs")?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .collect::<Vec<u64>>();
+        days.sort_unstable();
+        assert!(!days.is_empty());
+        let missing = days
+            .into_iter()
+            .filter(|&day| !Path::new(&example_path(day, 1)).is_file())
+            .collect::<Vec<u64>>();
+        assert!(missing.is_empty(), "days missing examples: {missing:?}");
+        assert_eq!("1122", example(1, 1).trim());
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::{Display, Write};
+use std::time::Duration;
+
+/// Character used for set bits (or on cells) by [`block_grid`].
+pub const BLOCK_ON: char = '█';
+/// Character used for clear bits (or off cells) by [`block_grid`].
+pub const BLOCK_OFF: char = '·';
+
+/// Formats the bytes as a lowercase hexadecimal string, with two digits per byte.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut output, byte| {
+        write!(output, "{byte:02x}").unwrap();
+        output
+    })
+}
+
+/// Formats the bytes as a hex dump, with the given number of bytes (at least 1) per line. Each line
+/// gives the offset of its first byte, the bytes in hexadecimal and the printable ASCII characters
+/// of the bytes (with '.' in place of other bytes).
+pub fn hex_dump(bytes: &[u8], width: usize) -> String {
+    let width = width.max(1);
+    let mut output = String::new();
+    for (i, line) in bytes.chunks(width).enumerate() {
+        let hex = line
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let ascii = line
+            .iter()
+            .map(|&byte| match byte {
+                0x20..=0x7e => byte as char,
+                _ => '.',
+            })
+            .collect::<String>();
+        writeln!(
+            output,
+            "{:08x}  {hex:<hex_width$}  |{ascii}|",
+            i * width,
+            hex_width = width * 3 - 1
+        )
+        .unwrap();
+    }
+    output
+}
+
+/// Gets the bits of the bytes in order, starting from the most significant bit of the first byte.
+pub fn bits_msb_first(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+}
+
+/// Renders a grid of binary cells as block characters, with one line per row using '█' for on
+/// cells and '·' for off cells.
+pub fn block_grid<R, C>(rows: R) -> String
+where
+    R: IntoIterator<Item = C>,
+    C: IntoIterator<Item = bool>,
+{
+    let mut output = String::new();
+    for row in rows {
+        output.extend(
+            row.into_iter()
+                .map(|on| if on { BLOCK_ON } else { BLOCK_OFF }),
+        );
+        output.push('\n');
+    }
+    output
+}
+
+/// Formats the duration to two decimal places in the most suitable unit (as with `{:.2?}`), with
+/// the digits of long durations grouped in thousands (e.g. "1,234.50s").
+pub fn format_duration(duration: Duration) -> String {
+    group_thousands(format!("{duration:.2?}"))
+}
+
+/// Formats the value with a comma between each group of three digits in its integer part (e.g.
+/// 1234567 as "1,234,567"). Any sign before the digits and any text after them (such as a fraction
+/// or unit) is kept as it is, so formatted values like "-1234.50ms" become "-1,234.50ms".
+pub fn group_thousands(value: impl Display) -> String {
+    let text = value.to_string();
+    let sign_len = usize::from(text.starts_with(['-', '+']));
+    let digits_len = text[sign_len..]
+        .bytes()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    let (sign, rest) = text.split_at(sign_len);
+    let (digits, suffix) = rest.split_at(digits_len);
+    let mut output = String::from(sign);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits_len - i) % 3 == 0 {
+            output.push(',');
+        }
+        output.push(digit);
+    }
+    output.push_str(suffix);
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the hexadecimal formatting of bytes, as a string and as a hex dump.
+    #[test]
+    fn test_hex() {
+        assert_eq!("", hex(&[]));
+        assert_eq!("00ff0a7b", hex(&[0, 255, 10, 123]));
+        assert_eq!(
+            "00000000  41 42 43 00  |ABC.|\n00000004  7e 0a        |~.|\n",
+            hex_dump(b"ABC\0~\n", 4)
+        );
+        assert_eq!("", hex_dump(&[], 16));
+    }
+
+    /// Tests the rendering of bits as a grid of block characters.
+    #[test]
+    fn test_block_grid() {
+        let rows = [[0b1101_0100u8], [0b0000_0001]];
+        assert_eq!(
+            "██·█·█··\n·······█\n",
+            block_grid(rows.iter().map(|row| bits_msb_first(row)))
+        );
+        assert_eq!("", block_grid(Vec::<Vec<bool>>::new()));
+    }
+
+    /// Tests the grouping of digits in numbers and formatted values.
+    #[test]
+    fn test_group_thousands() {
+        for (value, expected) in [
+            (0, "0"),
+            (999, "999"),
+            (1000, "1,000"),
+            (-1234567, "-1,234,567"),
+            (i64::MAX, "9,223,372,036,854,775,807"),
+        ] {
+            assert_eq!(expected, group_thousands(value));
+        }
+        assert_eq!("1,234.50ms", group_thousands("1234.50ms"));
+        assert_eq!("n/a", group_thousands("n/a"));
+    }
+
+    /// Tests that durations are formatted to two decimal places with the digits grouped.
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("1.50ms", format_duration(Duration::from_micros(1500)));
+        assert_eq!("1,234.00s", format_duration(Duration::from_secs(1234)));
+    }
+}
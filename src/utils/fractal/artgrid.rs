@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::utils::fractal::{MissingRulesError, Pattern, RuleBook};
+
+/// Number of pixels held in each word of a row bitset.
+const WORD_BITS: usize = 64;
+
+/// Square grid of pixels used to generate fractal art. Each row is held as a bitset, with on
+/// pixels represented by set bits.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ArtGrid {
+    size: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl ArtGrid {
+    /// Creates a new [`ArtGrid`] of the given size with all pixels off.
+    pub fn new(size: usize) -> ArtGrid {
+        let words_per_row = size.div_ceil(WORD_BITS);
+        ArtGrid {
+            size,
+            words_per_row,
+            words: vec![0; words_per_row * size],
+        }
+    }
+
+    /// Creates the starting [`ArtGrid`] used by the AOC 2017 Day 21 problem (".#./..#/###").
+    pub fn start() -> ArtGrid {
+        let mut artgrid = ArtGrid::new(3);
+        for (r, c) in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)] {
+            artgrid.set(r, c, true);
+        }
+        artgrid
+    }
+
+    /// Gets the size (number of rows and columns) of the grid.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Checks if the pixel in the given row and column is on.
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.words[r * self.words_per_row + c / WORD_BITS] & (1 << (c % WORD_BITS)) != 0
+    }
+
+    /// Turns the pixel in the given row and column on or off.
+    pub fn set(&mut self, r: usize, c: usize, on: bool) {
+        let word = &mut self.words[r * self.words_per_row + c / WORD_BITS];
+        if on {
+            *word |= 1 << (c % WORD_BITS);
+        } else {
+            *word &= !(1 << (c % WORD_BITS));
+        }
+    }
+
+    /// Counts the number of pixels that are on.
+    pub fn count_on(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Applies the enhancement rules to the grid, returning the new and enhanced grid.
+    ///
+    /// If any block of the grid does not have a matching rule, the returned error lists the
+    /// patterns of those blocks (in canonical form, as given by [`RuleBook::validate`]). [`RuleBook::validate`] can be
+    /// used to check for missing rules ahead of time.
+    pub fn enhance(&self, rulebook: &RuleBook) -> Result<ArtGrid, MissingRulesError> {
+        // Calculate old and new block sizes
+        let (old_block_size, new_block_size) = if self.size.is_multiple_of(2) {
+            (2, 3)
+        } else {
+            (3, 4)
+        };
+        let blocks = self.size / old_block_size;
+        let mut new_artgrid = ArtGrid::new(blocks * new_block_size);
+        let mut missing: Vec<Pattern> = vec![];
+        for block_r in 0..blocks {
+            for block_c in 0..blocks {
+                let pattern = self.get_block(
+                    block_r * old_block_size,
+                    block_c * old_block_size,
+                    old_block_size,
+                );
+                let Some(enhanced) = rulebook.enhance(pattern) else {
+                    let pattern = pattern.canonical();
+                    if !missing.contains(&pattern) {
+                        missing.push(pattern);
+                    }
+                    continue;
+                };
+                new_artgrid.set_block(block_r * new_block_size, block_c * new_block_size, enhanced);
+            }
+        }
+        if !missing.is_empty() {
+            return Err(MissingRulesError { missing });
+        }
+        Ok(new_artgrid)
+    }
+
+    /// Gets the pattern of the n x n block (n = 2 to 4) with its top-left pixel at the given row and
+    /// column.
+    pub(crate) fn get_block(&self, r: usize, c: usize, size: usize) -> Pattern {
+        let mut pixels = 0;
+        for dr in 0..size {
+            for dc in 0..size {
+                if self.get(r + dr, c + dc) {
+                    pixels |= 1 << (dr * size + dc);
+                }
+            }
+        }
+        Pattern::from_bits(size, pixels)
+    }
+
+    /// Sets the pixels of the block with its top-left pixel at the given row and column from the
+    /// pattern.
+    pub(crate) fn set_block(&mut self, r: usize, c: usize, pattern: Pattern) {
+        for dr in 0..pattern.size() {
+            for dc in 0..pattern.size() {
+                self.set(r + dr, c + dc, pattern.get(dr, dc));
+            }
+        }
+    }
+}
+
+impl fmt::Display for ArtGrid {
+    /// Displays the grid with one line per row, using '#' for on pixels and '.' for off pixels.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in 0..self.size {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for c in 0..self.size {
+                write!(f, "{}", if self.get(r, c) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests two iterations of enhancement against the example from the AOC 2017 Day 21 problem
+    /// statement.
+    #[test]
+    fn test_artgrid_enhance_example() {
+        let mut rulebook = RuleBook::new();
+        for (input, output) in [
+            ("../.#", "##./#../..."),
+            (".#./..#/###", "#..#/..../..../#..#"),
+        ] {
+            rulebook
+                .add_rule(input.parse().unwrap(), output.parse().unwrap())
+                .unwrap();
+        }
+        let artgrid = ArtGrid::start();
+        assert_eq!(".#.\n..#\n###", artgrid.to_string());
+        let artgrid = artgrid.enhance(&rulebook).unwrap();
+        assert_eq!("#..#\n....\n....\n#..#", artgrid.to_string());
+        let artgrid = artgrid.enhance(&rulebook).unwrap();
+        assert_eq!(
+            "##.##.\n#..#..\n......\n##.##.\n#..#..\n......",
+            artgrid.to_string()
+        );
+        assert_eq!(12, artgrid.count_on());
+        // Third iteration needs rules for blocks which are not covered by the example rules
+        let error = artgrid.enhance(&rulebook).unwrap_err();
+        let missing = error
+            .missing
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![".#/##", "../##", "../.."], missing);
+    }
+}
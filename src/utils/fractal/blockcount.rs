@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use crate::utils::error::OverflowError;
+use crate::utils::fractal::{ArtGrid, Pattern, RuleBook};
+
+/// Number of iterations after which a 3x3 block has grown into a 9x9 grid, which is split into
+/// nine 3x3 blocks that are enhanced independently of each other from then on.
+const BLOCK_ITERATIONS: usize = 3;
+/// Size of the blocks counted between each group of iterations.
+const BLOCK_SIZE: usize = 3;
+
+/// Counts the number of pixels that are on after applying n iterations of the enhancement rules
+/// over the starting grid ([`ArtGrid::start`]).
+///
+/// Rather than building the full grid, the number of each distinct 3x3 block is counted. Every
+/// three iterations, each 3x3 block becomes nine 3x3 blocks, so the counts grow exponentially while
+/// the number of distinct blocks stays small. Counts are held as u128, and an [`OverflowError`] is
+/// returned if even these overflow.
+///
+/// The rule book must have a rule for every pattern (checked by [`RuleBook::validate`]), otherwise
+/// this function panics.
+pub fn count_on_after(rulebook: &RuleBook, iterations: usize) -> Result<u128, OverflowError> {
+    let start = ArtGrid::start();
+    let mut blocks: HashMap<Pattern, u128> =
+        HashMap::from([(start.get_block(0, 0, BLOCK_SIZE), 1)]);
+    let mut remaining = iterations;
+    while remaining >= BLOCK_ITERATIONS {
+        let mut next_blocks: HashMap<Pattern, u128> = HashMap::new();
+        for (pattern, count) in blocks {
+            let artgrid = enhance_block(rulebook, pattern, BLOCK_ITERATIONS);
+            for r in (0..artgrid.size()).step_by(BLOCK_SIZE) {
+                for c in (0..artgrid.size()).step_by(BLOCK_SIZE) {
+                    let next_count = next_blocks
+                        .entry(artgrid.get_block(r, c, BLOCK_SIZE))
+                        .or_insert(0);
+                    *next_count = next_count
+                        .checked_add(count)
+                        .ok_or_else(|| overflow_error(iterations))?;
+                }
+            }
+        }
+        blocks = next_blocks;
+        remaining -= BLOCK_ITERATIONS;
+    }
+    // Apply the remaining iterations to each block directly
+    let mut total: u128 = 0;
+    for (pattern, count) in blocks {
+        let on = enhance_block(rulebook, pattern, remaining).count_on() as u128;
+        total = count
+            .checked_mul(on)
+            .and_then(|on| total.checked_add(on))
+            .ok_or_else(|| overflow_error(iterations))?;
+    }
+    Ok(total)
+}
+
+/// Applies n iterations of the enhancement rules to the 3x3 block, returning the resulting grid.
+fn enhance_block(rulebook: &RuleBook, pattern: Pattern, iterations: usize) -> ArtGrid {
+    let mut artgrid = ArtGrid::new(BLOCK_SIZE);
+    artgrid.set_block(0, 0, pattern);
+    for _ in 0..iterations {
+        artgrid = artgrid.enhance(rulebook).unwrap_or_else(|e| panic!("{e}"));
+    }
+    artgrid
+}
+
+/// Creates the error returned when the pixel count overflows.
+fn overflow_error(iterations: usize) -> OverflowError {
+    OverflowError {
+        message: format!("number of pixels on after {iterations} iterations exceeds u128"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the block count agrees with counting the pixels of the full grid, and that the
+    /// count overflowing is reported, using rules that turn every pixel on.
+    #[test]
+    fn test_count_on_after_all_on() {
+        let mut rulebook = RuleBook::new();
+        for (size, output) in [(2, "###/###/###"), (3, "####/####/####/####")] {
+            for pattern in Pattern::all(size) {
+                rulebook.add_rule(pattern, output.parse().unwrap()).unwrap();
+            }
+        }
+        let mut artgrid = ArtGrid::start();
+        for iterations in 0..=7 {
+            assert_eq!(
+                Ok(artgrid.count_on() as u128),
+                count_on_after(&rulebook, iterations)
+            );
+            artgrid = artgrid.enhance(&rulebook).unwrap();
+        }
+        // Grid is 3 * 3^20 pixels wide after 60 iterations, far beyond the limit of usize
+        assert_eq!(Ok(9 * 3u128.pow(40)), count_on_after(&rulebook, 60));
+        assert!(count_on_after(&rulebook, 120).is_err());
+    }
+}
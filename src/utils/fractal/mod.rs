@@ -0,0 +1,10 @@
+pub mod artgrid;
+pub mod blockcount;
+pub mod pattern;
+pub mod rulebook;
+pub mod symmetry;
+
+pub use artgrid::ArtGrid;
+pub use blockcount::count_on_after;
+pub use pattern::Pattern;
+pub use rulebook::{MissingRulesError, RuleBook};
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::error::ParseError;
+use crate::utils::fractal::symmetry::{
+    grid_to_pattern, pattern_to_grid, symmetry_variants_grid, SYMMETRY_VARIANTS,
+};
+
+/// Smallest supported pattern size.
+const MIN_SIZE: usize = 2;
+/// Largest supported pattern size (limited by the number of bits used to hold the pixels).
+const MAX_SIZE: usize = 4;
+
+/// Square pattern of pixels (2x2 to 4x4) used by the fractal art enhancement rules from AOC 2017
+/// Day 21, written in slash-separated form with '#' for on pixels and '.' for off pixels (e.g.
+/// ".#./..#/###").
+///
+/// The pixel in row r and column c of an n x n pattern is held in bit (r * n + c). Patterns are
+/// ordered by size, then lexicographically by their pixels in row-major order, with off pixels
+/// before on pixels.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Pattern {
+    size: usize,
+    pixels: u16,
+}
+
+impl Pattern {
+    /// Creates a new [`Pattern`] of the given size (2 to 4) from its encoded pixels.
+    pub(crate) fn from_bits(size: usize, pixels: u16) -> Pattern {
+        debug_assert!((MIN_SIZE..=MAX_SIZE).contains(&size));
+        Pattern { size, pixels }
+    }
+
+    /// Gets an iterator over every possible pattern of the given size (2 to 4).
+    pub fn all(size: usize) -> impl Iterator<Item = Pattern> {
+        (0..1u32 << (size * size)).map(move |pixels| Pattern::from_bits(size, pixels as u16))
+    }
+
+    /// Gets the size (number of rows and columns) of the pattern.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets the encoded pixels of the pattern.
+    pub(crate) fn bits(&self) -> u16 {
+        self.pixels
+    }
+
+    /// Checks if the pixel in the given row and column is on.
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.pixels & (1 << (r * self.size + c)) != 0
+    }
+
+    /// Gets the eight rotations and reflections of the pattern.
+    pub fn variants(&self) -> [Pattern; SYMMETRY_VARIANTS] {
+        let pixels = match self.size {
+            2 => variant_bits::<2>(self.pixels),
+            3 => variant_bits::<3>(self.pixels),
+            _ => variant_bits::<4>(self.pixels),
+        };
+        pixels.map(|pixels| Pattern::from_bits(self.size, pixels))
+    }
+
+    /// Gets the canonical form of the pattern - the lexicographically smallest of its rotations and
+    /// reflections. Patterns that are rotations or reflections of each other share the same
+    /// canonical form.
+    pub fn canonical(&self) -> Pattern {
+        self.variants().into_iter().min().unwrap()
+    }
+
+    /// Checks if the pattern is equal to the other pattern under some rotation or reflection.
+    pub fn eq_under_symmetry(&self, other: &Pattern) -> bool {
+        self.size == other.size && self.canonical() == other.canonical()
+    }
+
+    /// Gets the key used to order patterns of the same size, with the first pixel held in the most
+    /// significant bit so that comparing keys compares the pixels lexicographically.
+    fn order_key(&self) -> u16 {
+        self.pixels.reverse_bits() >> (u16::BITS as usize - self.size * self.size)
+    }
+}
+
+/// Gets the encoded pixels of the eight rotations and reflections of the encoded N x N pattern.
+fn variant_bits<const N: usize>(pixels: u16) -> [u16; SYMMETRY_VARIANTS] {
+    symmetry_variants_grid(&pattern_to_grid::<N>(pixels)).map(|grid| grid_to_pattern(&grid))
+}
+
+impl Ord for Pattern {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.size, self.order_key()).cmp(&(other.size, other.order_key()))
+    }
+}
+
+impl PartialOrd for Pattern {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    /// Parses a pattern given in slash-separated form (e.g. ".#./..#/###").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.split('/').collect::<Vec<&str>>();
+        let size = rows.len();
+        if !(MIN_SIZE..=MAX_SIZE).contains(&size) || rows.iter().any(|row| row.len() != size) {
+            return Err(ParseError {
+                message: format!("pattern must be square with size 2 to 4, got \"{s}\""),
+            });
+        }
+        let mut pixels = 0;
+        for (i, pixel) in rows.iter().flat_map(|row| row.chars()).enumerate() {
+            match pixel {
+                '#' => pixels |= 1 << i,
+                '.' => (),
+                _ => {
+                    return Err(ParseError {
+                        message: format!("invalid pixel '{pixel}' in pattern \"{s}\""),
+                    })
+                }
+            }
+        }
+        Ok(Pattern::from_bits(size, pixels))
+    }
+}
+
+impl fmt::Display for Pattern {
+    /// Displays the pattern in slash-separated form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in 0..self.size {
+            if r > 0 {
+                write!(f, "/")?;
+            }
+            for c in 0..self.size {
+                write!(f, "{}", if self.get(r, c) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that patterns round trip through their slash-separated form, and that invalid patterns
+    /// are rejected.
+    #[test]
+    fn test_pattern_parse_display() {
+        for size in 2..=3 {
+            for pattern in Pattern::all(size) {
+                assert_eq!(Ok(pattern), pattern.to_string().parse());
+            }
+        }
+        let pattern = ".#./..#/###".parse::<Pattern>().unwrap();
+        assert_eq!(3, pattern.size());
+        assert!(pattern.get(0, 1) && pattern.get(1, 2) && !pattern.get(1, 1));
+        assert_eq!(
+            "#..#/..../..../#..#",
+            "#..#/..../..../#..#"
+                .parse::<Pattern>()
+                .unwrap()
+                .to_string()
+        );
+        for invalid in [
+            "#",
+            "../...",
+            "../.x",
+            ".../...",
+            "#####/#####/#####/#####/#####",
+            "",
+        ] {
+            assert!(invalid.parse::<Pattern>().is_err(), "{invalid}");
+        }
+    }
+
+    /// Tests the canonical form and equality under symmetry, using the rotations and reflections of
+    /// the starting pattern from the AOC 2017 Day 21 problem statement.
+    #[test]
+    fn test_pattern_canonical() {
+        let pattern = ".#./..#/###".parse::<Pattern>().unwrap();
+        let canonical = "..#/#.#/.##".parse::<Pattern>().unwrap();
+        for variant in [".#./..#/###", ".#./#../###", "#../#.#/##.", "###/..#/.#."] {
+            let variant = variant.parse::<Pattern>().unwrap();
+            assert_eq!(canonical, variant.canonical());
+            assert!(variant.eq_under_symmetry(&pattern));
+            assert!(pattern.variants().contains(&variant));
+        }
+        assert!(!pattern.eq_under_symmetry(&"###/###/...".parse().unwrap()));
+        assert!(!Pattern::from_bits(2, 0).eq_under_symmetry(&Pattern::from_bits(3, 0)));
+        // Lexicographic order puts off pixels before on pixels, starting from the top-left pixel
+        assert_eq!(
+            "../.#",
+            "#./..".parse::<Pattern>().unwrap().canonical().to_string()
+        );
+        assert!("../##".parse::<Pattern>().unwrap() < "#./..".parse().unwrap());
+        assert!("##/##".parse::<Pattern>().unwrap() < ".../.../...".parse().unwrap());
+        // There are 6 distinct 2x2 patterns and 102 distinct 3x3 patterns under symmetry
+        for (size, distinct) in [(2, 6), (3, 102)] {
+            let mut canonical = Pattern::all(size)
+                .map(|p| p.canonical())
+                .collect::<Vec<_>>();
+            canonical.sort_unstable();
+            canonical.dedup();
+            assert_eq!(distinct, canonical.len());
+        }
+    }
+}
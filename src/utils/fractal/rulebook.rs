@@ -0,0 +1,168 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::utils::error::ParseError;
+use crate::utils::fractal::Pattern;
+
+/// Custom error type indicating that a [`RuleBook`] has no rule for some patterns. Each missing
+/// pattern is listed once (in canonical form), rather than once for each of its rotations and
+/// reflections.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MissingRulesError {
+    pub missing: Vec<Pattern>,
+}
+
+impl fmt::Display for MissingRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "No enhancement rule for patterns: {}",
+            self.missing
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Enhancement rules used to generate fractal art, converting each 2x2 pattern into a 3x3 pattern
+/// and each 3x3 pattern into a 4x4 pattern.
+///
+/// Rules are held in tables indexed by the encoded pixels of their input [`Pattern`]. Each rule is
+/// expanded over all of the rotations and reflections of its input pattern when it is added, so the
+/// rule matching a pattern is found with a single table lookup.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RuleBook {
+    /// Output pattern (3x3) for each 2x2 input pattern.
+    rules_two: Vec<Option<Pattern>>,
+    /// Output pattern (4x4) for each 3x3 input pattern.
+    rules_three: Vec<Option<Pattern>>,
+}
+
+impl Default for RuleBook {
+    fn default() -> Self {
+        RuleBook::new()
+    }
+}
+
+impl RuleBook {
+    /// Creates a new [`RuleBook`] with no rules.
+    pub fn new() -> RuleBook {
+        RuleBook {
+            rules_two: vec![None; 1 << 4],
+            rules_three: vec![None; 1 << 9],
+        }
+    }
+
+    /// Adds a rule converting the input pattern into the output pattern. The rule applies to every
+    /// rotation and reflection of the input pattern.
+    pub fn add_rule(&mut self, input: Pattern, output: Pattern) -> Result<(), ParseError> {
+        let (input_size, output_size) = (input.size(), output.size());
+        let table = match (input_size, output_size) {
+            (2, 3) => &mut self.rules_two,
+            (3, 4) => &mut self.rules_three,
+            _ => {
+                return Err(ParseError {
+                    message: format!(
+                        "rule must convert 2x2 to 3x3 or 3x3 to 4x4, got {input_size}x{input_size} \
+                        to {output_size}x{output_size}"
+                    ),
+                })
+            }
+        };
+        for variant in input.variants() {
+            table[variant.bits() as usize] = Some(output);
+        }
+        Ok(())
+    }
+
+    /// Checks that every 2x2 and 3x3 pattern has a matching rule. If not, the returned error lists
+    /// the missing patterns in order, with each group of rotations and reflections represented by
+    /// its canonical form ([`Pattern::canonical`]).
+    pub fn validate(&self) -> Result<(), MissingRulesError> {
+        let missing = [2, 3]
+            .into_iter()
+            .flat_map(Pattern::all)
+            .filter(|pattern| self.enhance(*pattern).is_none())
+            .map(|pattern| pattern.canonical())
+            .collect::<BTreeSet<Pattern>>()
+            .into_iter()
+            .collect::<Vec<Pattern>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingRulesError { missing })
+        }
+    }
+
+    /// Gets the output pattern of the rule matching the input pattern, or None if there is no
+    /// matching rule.
+    pub fn enhance(&self, pattern: Pattern) -> Option<Pattern> {
+        let table = match pattern.size() {
+            2 => &self.rules_two,
+            3 => &self.rules_three,
+            _ => return None,
+        };
+        table[pattern.bits() as usize]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Parses a pattern given in slash-separated form.
+    fn pattern(s: &str) -> Pattern {
+        s.parse().unwrap()
+    }
+
+    /// Tests that the rules match every rotation and reflection of their input patterns, using the
+    /// example from the AOC 2017 Day 21 problem statement.
+    #[test]
+    fn test_rulebook_symmetry() {
+        let mut rulebook = RuleBook::new();
+        let output = pattern("#..#/..../..../#..#");
+        rulebook.add_rule(pattern(".#./..#/###"), output).unwrap();
+        for variant in [".#./..#/###", ".#./#../###", "#../#.#/##.", "###/..#/.#."] {
+            assert_eq!(Some(output), rulebook.enhance(pattern(variant)));
+        }
+        assert_eq!(None, rulebook.enhance(pattern("###/###/...")));
+        assert_eq!(None, rulebook.enhance(pattern("####/####/####/####")));
+        assert!(rulebook
+            .add_rule(pattern("../.."), pattern("../.."))
+            .is_err());
+        assert!(rulebook
+            .add_rule(pattern("###/###/###"), pattern("###/###/###"))
+            .is_err());
+    }
+
+    /// Tests that validation lists each missing pattern once, regardless of symmetry.
+    #[test]
+    fn test_rulebook_validate() {
+        let mut rulebook = RuleBook::new();
+        for input in ["../..", "#./..", "##/..", "#./.#", "##/#.", "##/##"] {
+            rulebook
+                .add_rule(pattern(input), pattern(".../.../..."))
+                .unwrap();
+        }
+        let error = rulebook.validate().unwrap_err();
+        // All 102 distinct 3x3 patterns are missing, starting from the all-off pattern
+        assert_eq!(102, error.missing.len());
+        assert_eq!(pattern(".../.../..."), error.missing[0]);
+        rulebook
+            .add_rule(pattern("##/#."), pattern("###/###/###"))
+            .unwrap();
+        assert_eq!(102, rulebook.validate().unwrap_err().missing.len());
+        let mut rulebook = RuleBook::new();
+        rulebook
+            .add_rule(pattern("../.."), pattern(".../.../..."))
+            .unwrap();
+        let error = rulebook.validate().unwrap_err();
+        assert_eq!(pattern("../.#"), error.missing[0]);
+        assert_eq!(5 + 102, error.missing.len());
+        assert!(error
+            .to_string()
+            .starts_with("No enhancement rule for patterns: ../.#, ../##, "));
+    }
+}
@@ -0,0 +1,73 @@
+/// Number of symmetry variants (rotations and reflections) of a square grid.
+pub const SYMMETRY_VARIANTS: usize = 8;
+
+/// Rotates the n x n grid a quarter turn clockwise.
+pub fn rotate_grid<T: Copy, const N: usize>(grid: &[[T; N]; N]) -> [[T; N]; N] {
+    std::array::from_fn(|r| std::array::from_fn(|c| grid[N - 1 - c][r]))
+}
+
+/// Flips the n x n grid horizontally, reversing the order of the pixels in each row.
+pub fn flip_horizontal_grid<T: Copy, const N: usize>(grid: &[[T; N]; N]) -> [[T; N]; N] {
+    std::array::from_fn(|r| std::array::from_fn(|c| grid[r][N - 1 - c]))
+}
+
+/// Flips the n x n grid vertically, reversing the order of the rows.
+pub fn flip_vertical_grid<T: Copy, const N: usize>(grid: &[[T; N]; N]) -> [[T; N]; N] {
+    std::array::from_fn(|r| grid[N - 1 - r])
+}
+
+/// Gets the eight rotations and reflections of the n x n grid. Even-numbered variants are the
+/// successive quarter turns of the grid, each followed by its horizontal reflection.
+pub fn symmetry_variants_grid<T: Copy, const N: usize>(
+    grid: &[[T; N]; N],
+) -> [[[T; N]; N]; SYMMETRY_VARIANTS] {
+    let mut variants = [*grid; SYMMETRY_VARIANTS];
+    let mut variant = *grid;
+    for i in 0..SYMMETRY_VARIANTS / 2 {
+        variants[2 * i] = variant;
+        variants[2 * i + 1] = flip_horizontal_grid(&variant);
+        variant = rotate_grid(&variant);
+    }
+    variants
+}
+
+/// Unpacks an encoded n x n pattern into a grid of pixels. The pixel in row r and column c is held
+/// in bit (r * n + c), with on pixels represented by set bits.
+pub fn pattern_to_grid<const N: usize>(pattern: u16) -> [[bool; N]; N] {
+    std::array::from_fn(|r| std::array::from_fn(|c| pattern & (1 << (r * N + c)) != 0))
+}
+
+/// Packs the n x n grid of pixels into an encoded pattern (the inverse of [`pattern_to_grid`]).
+pub fn grid_to_pattern<const N: usize>(grid: &[[bool; N]; N]) -> u16 {
+    grid.iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, on)| **on)
+        .fold(0, |pattern, (i, _)| pattern | (1 << i))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the transforms of a 4x4 grid produce eight distinct variants, with the inverse
+    /// transforms returning the original grid.
+    #[test]
+    fn test_symmetry_variants_grid4() {
+        let grid: [[u8; 4]; 4] =
+            std::array::from_fn(|r| std::array::from_fn(|c| (r * 4 + c) as u8));
+        assert_eq!([12, 8, 4, 0], rotate_grid(&grid)[0]);
+        assert_eq!([3, 2, 1, 0], flip_horizontal_grid(&grid)[0]);
+        assert_eq!(grid[3], flip_vertical_grid(&grid)[0]);
+        let rotated = (0..4).fold(grid, |g, _| rotate_grid(&g));
+        assert_eq!(grid, rotated);
+        assert_eq!(grid, flip_vertical_grid(&flip_vertical_grid(&grid)));
+        let variants = symmetry_variants_grid(&grid);
+        for (i, variant) in variants.iter().enumerate() {
+            assert!(variants[i + 1..].iter().all(|other| other != variant));
+        }
+        assert!(variants.contains(&flip_vertical_grid(&grid)));
+        let pattern = 0b1000_0100_0010_0011;
+        assert_eq!(pattern, grid_to_pattern(&pattern_to_grid::<4>(pattern)));
+    }
+}
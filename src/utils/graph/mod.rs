@@ -0,0 +1,3 @@
+pub mod weightedgraph;
+
+pub use weightedgraph::{ShortestPath, WeightedGraph};
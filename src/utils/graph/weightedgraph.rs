@@ -0,0 +1,176 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Shortest path between two nodes of a [`WeightedGraph`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShortestPath {
+    /// Total weight of the edges along the path.
+    pub cost: u64,
+    /// Nodes along the path, including the start and end nodes.
+    pub nodes: Vec<u64>,
+}
+
+/// Undirected graph with integer node IDs and non-negative integer edge weights.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct WeightedGraph {
+    edges: HashMap<u64, Vec<(u64, u64)>>,
+}
+
+impl WeightedGraph {
+    /// Creates a new empty [`WeightedGraph`].
+    pub fn new() -> WeightedGraph {
+        WeightedGraph::default()
+    }
+
+    /// Creates a [`WeightedGraph`] from the connections of each node, with every edge given a
+    /// weight of 1.
+    pub fn from_unweighted(conns: &HashMap<u64, Vec<u64>>) -> WeightedGraph {
+        let mut graph = WeightedGraph::new();
+        for (&node, others) in conns {
+            graph.add_node(node);
+            for &other in others {
+                graph.add_edge(node, other, 1);
+            }
+        }
+        graph
+    }
+
+    /// Adds the node to the graph, if not already present.
+    pub fn add_node(&mut self, node: u64) {
+        self.edges.entry(node).or_default();
+    }
+
+    /// Adds an edge with the given weight between the two nodes, adding the nodes if not already
+    /// present. If the nodes are already connected, the edge is only kept if it has a lower weight.
+    pub fn add_edge(&mut self, a: u64, b: u64, weight: u64) {
+        for (from, to) in [(a, b), (b, a)] {
+            let edges = self.edges.entry(from).or_default();
+            match edges.iter_mut().find(|(other, _)| *other == to) {
+                Some(edge) => edge.1 = edge.1.min(weight),
+                None => edges.push((to, weight)),
+            }
+        }
+    }
+
+    /// Checks if the node is present in the graph.
+    pub fn contains(&self, node: u64) -> bool {
+        self.edges.contains_key(&node)
+    }
+
+    /// Gets the number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Checks if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Gets an iterator over the nodes in the graph, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.edges.keys().copied()
+    }
+
+    /// Gets the nodes connected to the given node, along with the weight of each edge. Returns an
+    /// empty slice if the node is not present in the graph.
+    pub fn neighbours(&self, node: u64) -> &[(u64, u64)] {
+        self.edges.get(&node).map_or(&[], |edges| edges.as_slice())
+    }
+
+    /// Gets the weight of the edge between the two nodes, or None if they are not connected.
+    pub fn weight(&self, a: u64, b: u64) -> Option<u64> {
+        self.neighbours(a)
+            .iter()
+            .find(|(other, _)| *other == b)
+            .map(|(_, weight)| *weight)
+    }
+
+    /// Calculates the shortest distance from the start node to every node reachable from it
+    /// (including the start node itself, at distance 0), using Dijkstra's algorithm.
+    pub fn distances_from(&self, start: u64) -> HashMap<u64, u64> {
+        self.dijkstra(start, None).0
+    }
+
+    /// Finds the shortest path between the two nodes using Dijkstra's algorithm. Returns None if
+    /// either node is not present in the graph, or the end node cannot be reached from the start.
+    pub fn shortest_path(&self, start: u64, end: u64) -> Option<ShortestPath> {
+        if !self.contains(start) {
+            return None;
+        }
+        let (dist, prev) = self.dijkstra(start, Some(end));
+        let cost = *dist.get(&end)?;
+        let mut nodes = vec![end];
+        while let Some(&node) = prev.get(nodes.last().unwrap()) {
+            nodes.push(node);
+        }
+        nodes.reverse();
+        Some(ShortestPath { cost, nodes })
+    }
+
+    /// Runs Dijkstra's algorithm from the start node, stopping early once the target node (if any)
+    /// has been reached. Returns the shortest distance to each node settled and the previous node
+    /// along its shortest path.
+    fn dijkstra(&self, start: u64, target: Option<u64>) -> (HashMap<u64, u64>, HashMap<u64, u64>) {
+        let mut dist: HashMap<u64, u64> = HashMap::from([(start, 0)]);
+        let mut prev: HashMap<u64, u64> = HashMap::new();
+        let mut queue: BinaryHeap<Reverse<(u64, u64)>> = BinaryHeap::from([Reverse((0, start))]);
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if Some(node) == target {
+                break;
+            }
+            // Skip stale queue entries for nodes already reached more cheaply
+            if cost > dist[&node] {
+                continue;
+            }
+            for &(next, weight) in self.neighbours(node) {
+                let next_cost = cost.saturating_add(weight);
+                if dist.get(&next).is_none_or(|&current| next_cost < current) {
+                    dist.insert(next, next_cost);
+                    prev.insert(next, node);
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+        (dist, prev)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests shortest paths through a small graph where the direct edge is not the cheapest route.
+    #[test]
+    fn test_weighted_graph_shortest_path() {
+        let mut graph = WeightedGraph::new();
+        graph.add_edge(0, 1, 7);
+        graph.add_edge(0, 2, 2);
+        graph.add_edge(2, 3, 2);
+        graph.add_edge(3, 1, 1);
+        graph.add_edge(1, 4, 3);
+        graph.add_edge(1, 4, 5);
+        graph.add_node(5);
+        assert_eq!(6, graph.len());
+        assert_eq!(Some(3), graph.weight(4, 1));
+        assert_eq!(
+            Some(ShortestPath {
+                cost: 8,
+                nodes: vec![0, 2, 3, 1, 4]
+            }),
+            graph.shortest_path(0, 4)
+        );
+        assert_eq!(
+            Some(ShortestPath {
+                cost: 0,
+                nodes: vec![3]
+            }),
+            graph.shortest_path(3, 3)
+        );
+        assert_eq!(None, graph.shortest_path(0, 5));
+        assert_eq!(None, graph.shortest_path(6, 0));
+        let dist = graph.distances_from(0);
+        assert_eq!(5, dist.len());
+        assert_eq!(Some(&5), dist.get(&1));
+    }
+}
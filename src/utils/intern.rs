@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+/// Handle for a string held by an [`Interner`].
+///
+/// Symbols are numbered from 0 in the order their strings were first interned, so they can be used
+/// to index vectors in place of maps keyed by the strings themselves.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Gets the index of the symbol (the number of distinct strings interned before it).
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Interns strings (e.g. program or register names), mapping each distinct string to a [`Symbol`].
+///
+/// Each distinct string is only stored once, however many times it is interned, and the string
+/// for a symbol can be looked up again for display.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Interner {
+    symbols: HashMap<String, Symbol>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    /// Creates a new empty [`Interner`].
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Gets the symbol for the string, interning the string if it has not been seen before.
+    pub fn intern(&mut self, name: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(name) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(self.names.len()).expect("too many interned strings"));
+        self.symbols.insert(name.to_string(), symbol);
+        self.names.push(name.to_string());
+        symbol
+    }
+
+    /// Gets the symbol for the string, or None if the string has not been interned.
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.symbols.get(name).copied()
+    }
+
+    /// Gets the string for the symbol.
+    ///
+    /// Panics if the symbol was not created by this interner.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.names[symbol.index()]
+    }
+
+    /// Gets the number of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Checks if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Gets an iterator over the symbols and their strings, in the order they were interned.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (Symbol(i as u32), name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that each distinct string gets its own symbol, numbered in order of first use, and
+    /// that strings can be looked up from their symbols.
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+        assert!(interner.is_empty());
+        let names = ["pbga", "xhth", "pbga", "ebii", "xhth"];
+        let symbols = names.map(|name| interner.intern(name));
+        assert_eq!(
+            vec![0, 1, 0, 2, 1],
+            symbols.iter().map(|s| s.index()).collect::<Vec<usize>>()
+        );
+        assert_eq!(3, interner.len());
+        for (name, symbol) in names.iter().zip(symbols) {
+            assert_eq!(*name, interner.resolve(symbol));
+            assert_eq!(Some(symbol), interner.get(name));
+        }
+        assert_eq!(None, interner.get("tknk"));
+        assert_eq!(
+            vec!["pbga", "xhth", "ebii"],
+            interner.iter().map(|(_, name)| name).collect::<Vec<&str>>()
+        );
+    }
+}
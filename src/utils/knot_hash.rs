@@ -1,4 +1,10 @@
-use itertools::Itertools;
+use crate::utils::fmt::hex;
+
+/// Generates a new strand of the given size, holding the values 0 to (strand_size - 1) inclusive
+/// in order.
+pub fn new_strand(strand_size: usize) -> Vec<u64> {
+    (0..strand_size as u64).collect::<Vec<u64>>()
+}
 
 /// Calculates the sparse hash of the given strand. Returns the resulting strand, final cursor
 /// value, and final skip value.
@@ -9,31 +15,86 @@ pub fn calculate_sparse_hash(
     skip: usize,
 ) -> (Vec<u64>, usize, usize) {
     let mut strand = strand.to_vec();
+    let (cursor, skip) = twist_strand(&mut strand, lengths, cursor, skip);
+    (strand, cursor, skip)
+}
+
+/// Conducts a single round of the sparse hash algorithm on the strand in place, treating the strand
+/// as a ring buffer. Returns the final cursor value and final skip value.
+#[inline]
+fn twist_strand<T>(
+    strand: &mut [T],
+    lengths: &[usize],
+    cursor: usize,
+    skip: usize,
+) -> (usize, usize) {
+    let strand_len = strand.len();
     let mut cursor = cursor;
     let mut skip = skip;
     for &len in lengths {
-        // Reverse target segment
-        let mut segment: Vec<u64> = vec![];
-        for delta in 0..len {
-            let i = (cursor + delta) % strand.len();
-            segment.push(strand[i]);
-        }
-        segment.reverse();
-        for (j, &segment_elem) in segment.iter().enumerate() {
-            let i = (cursor + j) % strand.len();
-            strand[i] = segment_elem;
-        }
+        reverse_segment(strand, cursor, len);
         // Update cursor location and increment skip value
-        cursor = (cursor + len + skip) % strand.len();
+        cursor = (cursor + len + skip) % strand_len;
         skip += 1;
     }
-    (strand, cursor, skip)
+    (cursor, skip)
+}
+
+/// Reverses the segment of the given length starting at the index, wrapping around the end of the
+/// strand.
+///
+/// Segments that do not wrap are reversed as a single slice. Otherwise, the segment is made up of
+/// the end (tail) and start (head) of the strand. The shorter of these is swapped with the far end
+/// of the other, leaving the middle of the segment in a single slice to be reversed. This avoids a
+/// modulo operation for every element, so the compiler is free to vectorise the loops.
+#[inline]
+fn reverse_segment<T>(strand: &mut [T], start: usize, len: usize) {
+    let strand_len = strand.len();
+    if start + len <= strand_len {
+        strand[start..start + len].reverse();
+        return;
+    }
+    if len > strand_len {
+        // Segment overlaps itself, so fall back to swapping each pair of elements in turn
+        for delta in 0..len / 2 {
+            strand.swap(
+                (start + delta) % strand_len,
+                (start + len - 1 - delta) % strand_len,
+            );
+        }
+        return;
+    }
+    let (head, tail) = strand.split_at_mut(start);
+    let head = &mut head[..len - tail.len()];
+    let (tail_len, head_len) = (tail.len(), head.len());
+    let k = tail_len.min(head_len);
+    for (front, back) in tail[..k].iter_mut().zip(head.iter_mut().rev()) {
+        std::mem::swap(front, back);
+    }
+    if tail_len > head_len {
+        tail[k..].reverse();
+    } else {
+        head[..head_len - k].reverse();
+    }
+}
+
+/// Conducts a single round of the sparse hash algorithm on a new strand of the given size, and
+/// returns the product of the first two values of the resulting strand.
+pub fn twist_product(lengths: &[usize], strand_size: usize) -> u64 {
+    let (strand, _, _) = calculate_sparse_hash(&new_strand(strand_size), lengths, 0, 0);
+    strand[0] * strand[1]
 }
 
 /// Calculates the knot hash of the input string, including input processing (length sequence suffix
 /// append), 64 rounds of sparse algorithm and output processing (dense hash calculation and
 /// conversion to hexadecimal string).
 pub fn calculate_knot_hash(input_string: &str) -> String {
+    hex(&knot_hash_bytes(input_string))
+}
+
+/// Calculates the knot hash of the input string as described for [`calculate_knot_hash`], returning
+/// the 16 bytes of the dense hash rather than converting them to a hexadecimal string.
+pub fn knot_hash_bytes(input_string: &str) -> [u8; 16] {
     // Input processing
     let mut lengths = input_string
         .chars()
@@ -41,21 +102,71 @@ pub fn calculate_knot_hash(input_string: &str) -> String {
         .collect::<Vec<usize>>();
     lengths.append(&mut vec![17, 31, 73, 47, 23]);
     // Apply 64 rounds of the sparse hash algorithm
-    let mut strand = (0..=255).collect::<Vec<u64>>();
+    let mut strand: [u8; 256] = std::array::from_fn(|i| i as u8);
     let mut cursor = 0;
     let mut skip = 0;
     for _ in 0..64 {
-        (strand, cursor, skip) = calculate_sparse_hash(&strand, &lengths, cursor, skip);
+        (cursor, skip) = twist_strand(&mut strand, &lengths, cursor, skip);
     }
     // Convert to dense hash
-    let mut dense_hash: Vec<u64> = vec![];
-    for i in (0..strand.len()).step_by(16) {
-        let mut xor = strand[i];
-        for delta in 1..=15 {
-            xor ^= strand[i + delta];
+    std::array::from_fn(|i| {
+        strand[16 * i..16 * (i + 1)]
+            .iter()
+            .fold(0, |acc, val| acc ^ val)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the single round of the sparse hash algorithm against the example given in the AOC
+    /// 2017 Day 10 problem statement.
+    #[test]
+    fn test_twist_product_example() {
+        let (strand, cursor, skip) = calculate_sparse_hash(&new_strand(5), &[3, 4, 1, 5], 0, 0);
+        assert_eq!(vec![3, 4, 2, 1, 0], strand);
+        assert_eq!((4, 4), (cursor, skip));
+        assert_eq!(12, twist_product(&[3, 4, 1, 5], 5));
+    }
+
+    /// Tests that reversing segments in place (wrapping around the end of the strand or not) agrees
+    /// with reversing the wrapped segment element by element.
+    #[test]
+    fn test_reverse_segment() {
+        for strand_len in 1..=9 {
+            for start in 0..strand_len {
+                for len in 0..=2 * strand_len {
+                    let mut expected = new_strand(strand_len);
+                    for delta in 0..len / 2 {
+                        let i = (start + delta) % strand_len;
+                        let j = (start + len - 1 - delta) % strand_len;
+                        expected.swap(i, j);
+                    }
+                    let mut strand = new_strand(strand_len);
+                    reverse_segment(&mut strand, start, len);
+                    assert_eq!(expected, strand, "{strand_len} {start} {len}");
+                }
+            }
         }
-        dense_hash.push(xor);
     }
-    // Convert dense hash to hexadecimal representation
-    dense_hash.iter().map(|val| format!("{:02x}", val)).join("")
+
+    /// Tests the knot hash calculation against the examples given in the AOC 2017 Day 10 problem
+    /// statement.
+    #[test]
+    fn test_calculate_knot_hash_examples() {
+        assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", calculate_knot_hash(""));
+        assert_eq!(
+            "33efeb34ea91902bb2f59c9920caa6cd",
+            calculate_knot_hash("AoC 2017")
+        );
+        assert_eq!(
+            "3efbe78a8d82f29979031a4aa0b16a9d",
+            calculate_knot_hash("1,2,3")
+        );
+        assert_eq!(
+            "63960835bcdc130f0b66d7ff4f6a5a8e",
+            calculate_knot_hash("1,2,4")
+        );
+    }
 }
@@ -0,0 +1,260 @@
+use crate::utils::machines::optimiser::Rewrite;
+use crate::utils::machines::soundcomputer::{
+    register_index, Instruction, InstructionArgument, ModSemantics, RegisterWord, REGISTER_COUNT,
+};
+
+/// Source operand of a [`CompiledOp`], resolved to a register index or a constant value.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+impl Operand {
+    /// Compiles the instruction argument, returning None if it names an unknown register.
+    fn compile(arg: InstructionArgument) -> Option<Operand> {
+        match arg {
+            InstructionArgument::Value { val } => Some(Operand::Value(val)),
+            InstructionArgument::Register { reg } => register_index(reg).map(Operand::Register),
+        }
+    }
+
+    /// Reads the value of the operand from the register file.
+    #[inline(always)]
+    fn read<W: RegisterWord>(&self, registers: &[W; REGISTER_COUNT]) -> W {
+        match *self {
+            Operand::Register(index) => registers[index],
+            Operand::Value(val) => W::from(val),
+        }
+    }
+}
+
+/// Destination of a jump made by a [`CompiledOp`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum JumpTarget {
+    /// Constant offset, resolved to the index of the instruction jumped to.
+    Absolute(usize),
+    /// Offset read from the register with the given index when the jump is made.
+    Relative(usize),
+}
+
+/// Instruction of a [`CompiledProgram`], with register names resolved to indices in the
+/// register file and constant jump offsets resolved to instruction indices.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CompiledOp {
+    Set {
+        dst: usize,
+        src: Operand,
+    },
+    Add {
+        dst: usize,
+        src: Operand,
+    },
+    Sub {
+        dst: usize,
+        src: Operand,
+    },
+    Mul {
+        dst: usize,
+        src: Operand,
+    },
+    Mod {
+        dst: usize,
+        src: Operand,
+    },
+    /// Jump if the condition is greater than zero.
+    Jgz {
+        cond: Operand,
+        target: JumpTarget,
+    },
+    /// Jump if the condition is not zero.
+    Jnz {
+        cond: Operand,
+        target: JumpTarget,
+    },
+    /// Instruction that cannot be compiled ("snd", "rcv", the start of a rewritten block or a
+    /// jump out of the left of the program), which must be executed by the interpreter.
+    Interpret,
+}
+
+/// Program for the [`SoundComputer`] compiled into a dispatch table of [`CompiledOp`]s, which are
+/// executed against a fixed register file without the per-step overhead of the interpreter.
+///
+/// Compiled code only runs instructions that cannot yield or fail. Execution stops whenever the
+/// next instruction needs the interpreter - because it cannot be compiled, or because its result
+/// would overflow or divide by zero - so that the interpreter can execute it (and raise the error)
+/// with the full state of the machine.
+///
+/// [`SoundComputer`]: crate::utils::machines::soundcomputer::SoundComputer
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CompiledProgram {
+    ops: Vec<CompiledOp>,
+}
+
+impl CompiledProgram {
+    /// Compiles the program. Instructions at which a rewrite starts are left to the interpreter,
+    /// so that rewrites found by the optimiser are still applied.
+    pub fn compile(program: &[Instruction], rewrites: &[Option<Rewrite>]) -> CompiledProgram {
+        let ops = program
+            .iter()
+            .enumerate()
+            .map(|(index, instruction)| match rewrites.get(index) {
+                Some(Some(_)) => CompiledOp::Interpret,
+                _ => compile_instruction(index, instruction).unwrap_or(CompiledOp::Interpret),
+            })
+            .collect::<Vec<CompiledOp>>();
+        CompiledProgram { ops }
+    }
+
+    /// Gets the compiled instructions.
+    pub fn ops(&self) -> &[CompiledOp] {
+        &self.ops
+    }
+
+    /// Gets the number of instructions that were compiled, rather than left to the interpreter.
+    pub fn compiled_count(&self) -> usize {
+        self.ops
+            .iter()
+            .filter(|op| **op != CompiledOp::Interpret)
+            .count()
+    }
+
+    /// Runs the compiled code from the instruction at the program counter, until the program
+    /// counter moves outside of the program or reaches an instruction that must be executed by the
+    /// interpreter. Arithmetic is checked at the width of the registers. The count of "mul"
+    /// instructions executed is added to `mul_count`.
+    ///
+    /// Returned value is the new location of the program counter.
+    pub fn run<W: RegisterWord>(
+        &self,
+        registers: &mut [W; REGISTER_COUNT],
+        mut pc: usize,
+        mod_semantics: ModSemantics,
+        mul_count: &mut usize,
+    ) -> usize {
+        while let Some(op) = self.ops.get(pc) {
+            match *op {
+                CompiledOp::Set { dst, src } => registers[dst] = src.read(registers),
+                CompiledOp::Add { dst, src } => {
+                    let value = registers[dst].checked_add(src.read(registers));
+                    let Some(value) = value else {
+                        break;
+                    };
+                    registers[dst] = value;
+                }
+                CompiledOp::Sub { dst, src } => {
+                    let value = registers[dst].checked_sub(src.read(registers));
+                    let Some(value) = value else {
+                        break;
+                    };
+                    registers[dst] = value;
+                }
+                CompiledOp::Mul { dst, src } => {
+                    let value = registers[dst].checked_mul(src.read(registers));
+                    let Some(value) = value else {
+                        break;
+                    };
+                    registers[dst] = value;
+                    *mul_count += 1;
+                }
+                CompiledOp::Mod { dst, src } => {
+                    let value = mod_semantics.apply(registers[dst], src.read(registers));
+                    let Some(value) = value else {
+                        break;
+                    };
+                    registers[dst] = value;
+                }
+                CompiledOp::Jgz { cond, target } => {
+                    if cond.read(registers) > W::ZERO {
+                        match resolve_jump(registers, pc, target) {
+                            Some(next) => pc = next,
+                            None => break,
+                        }
+                        continue;
+                    }
+                }
+                CompiledOp::Jnz { cond, target } => {
+                    if cond.read(registers) != W::ZERO {
+                        match resolve_jump(registers, pc, target) {
+                            Some(next) => pc = next,
+                            None => break,
+                        }
+                        continue;
+                    }
+                }
+                CompiledOp::Interpret => break,
+            }
+            pc += 1;
+        }
+        pc
+    }
+}
+
+/// Compiles a single instruction found at the given index of the program.
+///
+/// Returns None if the instruction must be left to the interpreter.
+fn compile_instruction(index: usize, instruction: &Instruction) -> Option<CompiledOp> {
+    let op = match *instruction {
+        Instruction::Set { reg, arg } => CompiledOp::Set {
+            dst: register_index(reg)?,
+            src: Operand::compile(arg)?,
+        },
+        Instruction::Add { reg, arg } => CompiledOp::Add {
+            dst: register_index(reg)?,
+            src: Operand::compile(arg)?,
+        },
+        Instruction::Sub { reg, arg } => CompiledOp::Sub {
+            dst: register_index(reg)?,
+            src: Operand::compile(arg)?,
+        },
+        Instruction::Mul { reg, arg } => CompiledOp::Mul {
+            dst: register_index(reg)?,
+            src: Operand::compile(arg)?,
+        },
+        Instruction::Mod { reg, arg } => CompiledOp::Mod {
+            dst: register_index(reg)?,
+            src: Operand::compile(arg)?,
+        },
+        Instruction::Jgz { arg1, arg2 } => CompiledOp::Jgz {
+            cond: Operand::compile(arg1)?,
+            target: compile_jump_target(index, arg2)?,
+        },
+        Instruction::Jnz { arg1, arg2 } => CompiledOp::Jnz {
+            cond: Operand::compile(arg1)?,
+            target: compile_jump_target(index, arg2)?,
+        },
+        Instruction::Snd { .. } | Instruction::Rcv { .. } => return None,
+    };
+    Some(op)
+}
+
+/// Compiles the offset of the jump made by the instruction at the given index.
+///
+/// Returns None if the offset names an unknown register or is a constant that jumps out of the
+/// left of the program (which halts the machine, and so is left to the interpreter).
+fn compile_jump_target(index: usize, offset: InstructionArgument) -> Option<JumpTarget> {
+    match offset {
+        InstructionArgument::Value { val } => index
+            .checked_add_signed(isize::try_from(val).ok()?)
+            .map(JumpTarget::Absolute),
+        InstructionArgument::Register { reg } => register_index(reg).map(JumpTarget::Relative),
+    }
+}
+
+/// Resolves the destination of the jump made by the instruction at the program counter.
+///
+/// Returns None if the jump moves out of the left of the program.
+#[inline(always)]
+fn resolve_jump<W: RegisterWord>(
+    registers: &[W; REGISTER_COUNT],
+    pc: usize,
+    target: JumpTarget,
+) -> Option<usize> {
+    match target {
+        JumpTarget::Absolute(next) => Some(next),
+        JumpTarget::Relative(index) => {
+            let offset = isize::try_from(registers[index].to_i64()?).ok()?;
+            pc.checked_add_signed(offset)
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::utils::machines::optimiser::Rewrite;
+use crate::utils::machines::soundcomputer::{ExecutionReport, Instruction, StopReason};
+
+/// Execution coverage of a program run by a [`SoundComputer`], recorded by
+/// [`SoundComputer::run_with_coverage`].
+///
+/// Records the number of times each instruction of the program was executed, and the number of
+/// times each optimiser [`Rewrite`] was applied. Instructions inside a block replaced by a rewrite
+/// are not counted when the rewrite is applied, so comparing the coverage of a program with and
+/// without optimisation shows which hot paths the rewrites remove.
+///
+/// Displayed as an annotated listing of the program, giving the execution count (or "-" if never
+/// executed) and location of each instruction.
+///
+/// [`SoundComputer`]: crate::utils::machines::soundcomputer::SoundComputer
+/// [`SoundComputer::run_with_coverage`]: crate::utils::machines::soundcomputer::SoundComputer::run_with_coverage
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Coverage {
+    program: Vec<Instruction>,
+    counts: Vec<u64>,
+    rewrites: Vec<Option<Rewrite>>,
+    rewrite_counts: Vec<u64>,
+    report: ExecutionReport,
+}
+
+impl Coverage {
+    /// Creates a new [`Coverage`] for the program, with the rewrites found by the optimiser (if
+    /// any) and no instructions executed.
+    pub(crate) fn new(program: &[Instruction], rewrites: &[Option<Rewrite>]) -> Coverage {
+        let mut rewrites = rewrites.to_vec();
+        rewrites.resize(program.len(), None);
+        Coverage {
+            program: program.to_vec(),
+            counts: vec![0; program.len()],
+            rewrites,
+            rewrite_counts: vec![0; program.len()],
+            report: ExecutionReport {
+                steps: 0,
+                stop_reason: StopReason::StepLimit,
+            },
+        }
+    }
+
+    /// Records the execution of the instruction at the given location.
+    pub(crate) fn record(&mut self, pc: usize) {
+        self.counts[pc] += 1;
+    }
+
+    /// Records the application of the rewrite starting at the given location.
+    pub(crate) fn record_rewrite(&mut self, pc: usize) {
+        self.rewrite_counts[pc] += 1;
+    }
+
+    /// Records how the run finished.
+    pub(crate) fn finish(&mut self, report: ExecutionReport) {
+        self.report = report;
+    }
+
+    /// Gets the number of times the instruction at the given location was executed. Applications of
+    /// a rewrite starting at the location are not included (see [`Coverage::rewrite_count`]).
+    pub fn count(&self, pc: usize) -> u64 {
+        self.counts.get(pc).copied().unwrap_or(0)
+    }
+
+    /// Gets the number of times the rewrite starting at the given location was applied in place of
+    /// the block of instructions it replaces.
+    pub fn rewrite_count(&self, pc: usize) -> u64 {
+        self.rewrite_counts.get(pc).copied().unwrap_or(0)
+    }
+
+    /// Gets the number of times each instruction was executed, in program order.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Gets the locations of the instructions that were executed or started an applied rewrite.
+    pub fn executed(&self) -> Vec<usize> {
+        (0..self.program.len())
+            .filter(|&pc| self.count(pc) > 0 || self.rewrite_count(pc) > 0)
+            .collect()
+    }
+
+    /// Gets the locations of the instructions that were never executed and never started an
+    /// applied rewrite.
+    pub fn unexecuted(&self) -> Vec<usize> {
+        (0..self.program.len())
+            .filter(|&pc| self.count(pc) == 0 && self.rewrite_count(pc) == 0)
+            .collect()
+    }
+
+    /// Gets the number of steps executed and the reason that the run stopped. Each applied rewrite
+    /// counts as a single step.
+    pub fn report(&self) -> ExecutionReport {
+        self.report
+    }
+}
+
+impl fmt::Display for Coverage {
+    /// Displays the annotated listing, with one line per instruction giving its execution count,
+    /// location and text. Instructions starting an applied rewrite are annotated with the number of
+    /// applications and the length of the block replaced.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count_width = self
+            .counts
+            .iter()
+            .max()
+            .map_or(1, |max| max.to_string().len());
+        let pc_width = self.program.len().saturating_sub(1).to_string().len();
+        for (pc, instruction) in self.program.iter().enumerate() {
+            let count = match self.count(pc) {
+                0 => "-".to_string(),
+                n => n.to_string(),
+            };
+            write!(f, "{count:>count_width$} | {pc:>pc_width$} | {instruction}")?;
+            if let (Some(rewrite), n @ 1..) = (self.rewrites[pc], self.rewrite_count(pc)) {
+                write!(
+                    f,
+                    "  [rewrite x{n}, replacing {} instructions]",
+                    rewrite.block_len()
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::machines::optimiser::composite_check_block;
+    use crate::utils::machines::programbuilder::ProgramBuilder;
+    use crate::utils::machines::soundcomputer::{IsaProfile, SoundComputer};
+
+    /// Tests the counts recorded for a simple loop and the annotated listing.
+    #[test]
+    fn test_coverage_loop() {
+        let mut comp = SoundComputer::from_program_str(
+            "set a 3\nsub a 1\njnz a -1\nset b 1\njnz 1 2\nset b 2",
+            IsaProfile::Coprocessor,
+        )
+        .unwrap();
+        let coverage = comp.run_with_coverage(100).unwrap();
+        assert_eq!(&[1, 3, 3, 1, 1, 0], coverage.counts());
+        assert_eq!(vec![5], coverage.unexecuted());
+        assert_eq!(vec![0, 1, 2, 3, 4], coverage.executed());
+        assert_eq!(9, coverage.report().steps);
+        assert_eq!(StopReason::Halted, coverage.report().stop_reason);
+        assert_eq!(
+            "1 | 0 | set a 3\n3 | 1 | sub a 1\n3 | 2 | jnz a -1\n1 | 3 | set b 1\n\
+             1 | 4 | jnz 1 2\n- | 5 | set b 2\n",
+            coverage.to_string()
+        );
+        // Step limit stops the run part way through the loop
+        let mut comp =
+            SoundComputer::from_program_str("set a 1\njnz a 0", IsaProfile::Coprocessor).unwrap();
+        let coverage = comp.run_with_coverage(10).unwrap();
+        assert_eq!(&[1, 9], coverage.counts());
+        assert_eq!(StopReason::StepLimit, coverage.report().stop_reason);
+    }
+
+    /// Tests that the optimised program keeps the same coverage outside the rewritten block as the
+    /// original program, while the hot path inside the block is no longer executed.
+    #[test]
+    fn test_coverage_optimised() {
+        let mut program = ProgramBuilder::new()
+            .set('b', 15)
+            .set('f', 1)
+            .build()
+            .unwrap();
+        program.extend(composite_check_block('b', 'f', 'd', 'e', 'g'));
+        let mut plain = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+        let plain = plain.run_with_coverage(100_000).unwrap();
+        let mut optimised = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+        assert_eq!(1, optimised.optimise());
+        let optimised = optimised.run_with_coverage(100_000).unwrap();
+        // Every instruction of the original program is executed, with the inner loop hottest
+        assert!(plain.unexecuted().is_empty());
+        let hottest = (0..program.len())
+            .max_by_key(|&pc| plain.count(pc))
+            .unwrap();
+        assert!((2..program.len()).contains(&hottest));
+        assert_eq!(13 * 13, plain.count(4));
+        // Rewrite replaces the whole block, leaving only the instructions outside it executed
+        assert_eq!(1, optimised.rewrite_count(2));
+        assert_eq!(0, optimised.count(2));
+        assert_eq!(vec![0, 1, 2], optimised.executed());
+        assert_eq!(&plain.counts()[..2], &optimised.counts()[..2]);
+        assert_eq!(3, optimised.report().steps);
+        let listing = optimised.to_string();
+        assert!(listing
+            .lines()
+            .nth(2)
+            .unwrap()
+            .ends_with("set d 2  [rewrite x1, replacing 15 instructions]"));
+        assert_eq!(program.len(), listing.lines().count());
+    }
+}
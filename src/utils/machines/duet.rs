@@ -0,0 +1,181 @@
+use crate::utils::machines::soundcomputer::{
+    ExecutionError, Instruction, IsaProfile, ProgramLoadError, SoundComputer,
+};
+
+/// Reasons that a [`Duet`] stops running.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DuetTermination {
+    /// Both programs halted.
+    BothHalted,
+    /// Both programs are waiting to receive a sound that will never be sent (deadlock).
+    BothAwaitingInput,
+    /// One program halted while the other is waiting to receive a sound from it.
+    HaltedWhileAwaiting { halted: usize },
+}
+
+/// Summary of a run of a [`Duet`]. Statistics are indexed by program ID.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DuetReport {
+    pub termination: DuetTermination,
+    pub total_sounds_sent: [u64; 2],
+    pub total_sounds_received: [u64; 2],
+    pub max_received_queue_len: [usize; 2],
+}
+
+/// Harness running two copies of the same program on [`SoundComputer`]s in duet mode, with the
+/// sounds sent by each program received by the other.
+///
+/// Register "p" of each machine is initialised to its program ID (0 or 1).
+pub struct Duet {
+    machines: [SoundComputer; 2],
+}
+
+impl Duet {
+    /// Creates a new [`Duet`] with both machines loaded with the given program.
+    ///
+    /// Returns a [`ProgramLoadError`] if the program cannot be loaded under the
+    /// [`IsaProfile::Duet`] profile.
+    pub fn new(instructions: &[Instruction]) -> Result<Duet, ProgramLoadError> {
+        let comp0 = SoundComputer::new(instructions, IsaProfile::Duet)?;
+        let mut comp1 = SoundComputer::new(instructions, IsaProfile::Duet)?;
+        comp1.update_register(&'p', 1).unwrap(); // Set program ID for program 1
+        Ok(Duet {
+            machines: [comp0, comp1],
+        })
+    }
+
+    /// Runs both programs until neither can make further progress.
+    ///
+    /// Returned value is a [`DuetReport`] giving the reason the programs stopped along with the
+    /// sound queue statistics for each program, or the [`ExecutionError`] raised by either program.
+    pub fn run(&mut self) -> Result<DuetReport, ExecutionError> {
+        loop {
+            // Execute programs
+            for machine in self.machines.iter_mut() {
+                machine.execute()?;
+            }
+            self.exchange_sounds();
+            // Stop once neither program can continue
+            if self.is_finished() {
+                return Ok(self.report());
+            }
+        }
+    }
+
+    /// Executes a single instruction on each program that can continue, then passes the sounds
+    /// sent to the other program. Stepping until a report is returned gives the same result as
+    /// [`Duet::run`], as the interleaving of the programs does not affect the sounds they send.
+    ///
+    /// Returned value is the [`DuetReport`] once neither program can make further progress, or None
+    /// if either program can continue. The [`ExecutionError`] raised by either program is returned
+    /// if an instruction fails.
+    pub fn step(&mut self) -> Result<Option<DuetReport>, ExecutionError> {
+        for machine in self.machines.iter_mut() {
+            machine.step()?;
+        }
+        self.exchange_sounds();
+        Ok(self.is_finished().then(|| self.report()))
+    }
+
+    /// Checks if neither program can make further progress, as each has halted or is waiting to
+    /// receive a sound.
+    pub fn is_finished(&self) -> bool {
+        self.machines
+            .iter()
+            .all(|machine| machine.is_halted() || machine.is_awaiting_input())
+    }
+
+    /// Gets the machine running the program with the given ID (0 or 1).
+    pub fn machine(&self, id: usize) -> &SoundComputer {
+        &self.machines[id]
+    }
+
+    /// Passes the sounds sent by each program to the other program, unless it has halted.
+    fn exchange_sounds(&mut self) {
+        for id in 0..2 {
+            let sounds = self.machines[id].take_sent_sounds();
+            let other = &mut self.machines[1 - id];
+            if !other.is_halted() {
+                other.receive_sounds(&sounds);
+            }
+        }
+    }
+
+    /// Generates the report on the current state of the programs.
+    fn report(&self) -> DuetReport {
+        let termination = match (self.machines[0].is_halted(), self.machines[1].is_halted()) {
+            (true, true) => DuetTermination::BothHalted,
+            (false, false) => DuetTermination::BothAwaitingInput,
+            (true, false) => DuetTermination::HaltedWhileAwaiting { halted: 0 },
+            (false, true) => DuetTermination::HaltedWhileAwaiting { halted: 1 },
+        };
+        DuetReport {
+            termination,
+            total_sounds_sent: self.machines.each_ref().map(|m| m.get_total_sounds_sent()),
+            total_sounds_received: self
+                .machines
+                .each_ref()
+                .map(|m| m.get_total_sounds_received()),
+            max_received_queue_len: self
+                .machines
+                .each_ref()
+                .map(|m| m.get_max_received_queue_len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the duet harness against the example program given in the Day 18 problem statement,
+    /// which ends in deadlock.
+    #[test]
+    fn test_duet_example_deadlock() {
+        let program =
+            Instruction::parse_program("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d").unwrap();
+        let mut duet = Duet::new(&program).unwrap();
+        let report = duet.run().unwrap();
+        assert_eq!(DuetTermination::BothAwaitingInput, report.termination);
+        assert_eq!([3, 3], report.total_sounds_sent);
+        assert_eq!([3, 3], report.total_sounds_received);
+        assert_eq!([3, 3], report.max_received_queue_len);
+        assert_eq!(1, duet.machine(0).read_register(&'c').unwrap());
+        assert_eq!(0, duet.machine(1).read_register(&'c').unwrap());
+    }
+
+    /// Tests that a program halting while the other waits for input is reported.
+    #[test]
+    fn test_duet_halted_while_awaiting() {
+        // Program 0 halts straight away, program 1 waits for a sound that is never sent
+        let program = Instruction::parse_program("jgz p 2\njgz 1 2\nrcv a").unwrap();
+        let mut duet = Duet::new(&program).unwrap();
+        let report = duet.run().unwrap();
+        assert_eq!(
+            DuetTermination::HaltedWhileAwaiting { halted: 0 },
+            report.termination
+        );
+        assert_eq!([0, 0], report.total_sounds_received);
+    }
+
+    /// Tests that stepping the duet to completion gives the same report as running it, using the
+    /// example program given in the Day 18 problem statement.
+    #[test]
+    fn test_duet_step_matches_run() {
+        let program =
+            Instruction::parse_program("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d").unwrap();
+        let expected = Duet::new(&program).unwrap().run().unwrap();
+        let mut duet = Duet::new(&program).unwrap();
+        let mut steps = 0;
+        let report = loop {
+            steps += 1;
+            if let Some(report) = duet.step().unwrap() {
+                break report;
+            }
+            assert!(!duet.is_finished());
+        };
+        assert_eq!(expected, report);
+        assert_eq!(7, steps);
+        assert!(duet.is_finished());
+    }
+}
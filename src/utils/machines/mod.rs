@@ -1 +1,8 @@
+pub mod compiler;
+pub mod coverage;
+pub mod duet;
+pub mod optimiser;
+pub mod programbuilder;
+pub mod registervm;
 pub mod soundcomputer;
+pub mod turingmachine;
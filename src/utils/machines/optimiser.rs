@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::utils::machines::programbuilder::ProgramBuilder;
+use crate::utils::machines::soundcomputer::{Instruction, InstructionArgument};
+
+/// Template for the composite check loop. Its registers are placeholders, bound to whichever
+/// registers are used by the program being optimised.
+static COMPOSITE_CHECK_TEMPLATE: OnceLock<Vec<Instruction>> = OnceLock::new();
+
+/// Native operations that replace blocks of [`Instruction`]s found by the optimiser.
+///
+/// A rewrite is attached to the first instruction of the block it replaces, and is only applied
+/// when the program counter enters the block at that instruction. The original instructions are
+/// retained, so jumps into the middle of a block still execute correctly.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rewrite {
+    /// Clears the flag register if the value register holds a composite number. On completion the
+    /// divisor and cofactor registers hold the value, and the scratch register holds 0.
+    CompositeCheck {
+        value: char,
+        flag: char,
+        divisor: char,
+        cofactor: char,
+        scratch: char,
+        len: usize,
+    },
+}
+
+impl Rewrite {
+    /// Gets the number of instructions in the block replaced by the rewrite.
+    pub fn block_len(&self) -> usize {
+        match self {
+            Rewrite::CompositeCheck { len, .. } => *len,
+        }
+    }
+}
+
+/// Builds the nested loop that clears the flag register if the value register holds a number with
+/// a divisor and cofactor in the range [2, value).
+pub fn composite_check_block(
+    value: char,
+    flag: char,
+    divisor: char,
+    cofactor: char,
+    scratch: char,
+) -> Vec<Instruction> {
+    ProgramBuilder::new()
+        .set(divisor, 2)
+        .label("outer")
+        .set(cofactor, 2)
+        .label("inner")
+        .set(scratch, divisor)
+        .mul(scratch, cofactor)
+        .sub(scratch, value)
+        .jnz(scratch, 2)
+        .set(flag, 0)
+        .sub(cofactor, -1)
+        .set(scratch, cofactor)
+        .sub(scratch, value)
+        .jnz_label(scratch, "inner")
+        .sub(divisor, -1)
+        .set(scratch, divisor)
+        .sub(scratch, value)
+        .jnz_label(scratch, "outer")
+        .build()
+        .unwrap()
+}
+
+/// Searches the program for blocks of instructions that can be replaced with a native operation.
+///
+/// Returned vector is the same length as the program, holding the [`Rewrite`] (if any) that
+/// starts at each instruction.
+pub fn find_rewrites(instructions: &[Instruction]) -> Vec<Option<Rewrite>> {
+    let template: &[Instruction] =
+        COMPOSITE_CHECK_TEMPLATE.get_or_init(|| composite_check_block('b', 'f', 'd', 'e', 'g'));
+    let mut rewrites: Vec<Option<Rewrite>> = vec![None; instructions.len()];
+    for (i, rewrite) in rewrites.iter_mut().enumerate() {
+        if i + template.len() > instructions.len() {
+            break;
+        }
+        if let Some(regs) = match_template(template, &instructions[i..i + template.len()]) {
+            *rewrite = Some(Rewrite::CompositeCheck {
+                value: regs[&'b'],
+                flag: regs[&'f'],
+                divisor: regs[&'d'],
+                cofactor: regs[&'e'],
+                scratch: regs[&'g'],
+                len: template.len(),
+            });
+        }
+    }
+    rewrites
+}
+
+/// Matches the block of instructions against the template.
+///
+/// Returns the mapping of template placeholders to program registers if the block matches, with
+/// each placeholder bound to a different register. Otherwise, returns None.
+fn match_template(template: &[Instruction], block: &[Instruction]) -> Option<HashMap<char, char>> {
+    let mut regs: HashMap<char, char> = HashMap::new();
+    for (pattern, instruction) in template.iter().zip(block.iter()) {
+        let matched = match (pattern, instruction) {
+            (Instruction::Set { reg: p, arg: pa }, Instruction::Set { reg, arg })
+            | (Instruction::Mul { reg: p, arg: pa }, Instruction::Mul { reg, arg })
+            | (Instruction::Sub { reg: p, arg: pa }, Instruction::Sub { reg, arg }) => {
+                bind_register(&mut regs, *p, *reg) && bind_argument(&mut regs, pa, arg)
+            }
+            (
+                Instruction::Jnz {
+                    arg1: pa1,
+                    arg2: pa2,
+                },
+                Instruction::Jnz { arg1, arg2 },
+            ) => bind_argument(&mut regs, pa1, arg1) && bind_argument(&mut regs, pa2, arg2),
+            _ => false,
+        };
+        if !matched {
+            return None;
+        }
+    }
+    Some(regs)
+}
+
+/// Binds the placeholder to the program register, checking consistency with existing bindings.
+fn bind_register(regs: &mut HashMap<char, char>, placeholder: char, reg: char) -> bool {
+    match regs.get(&placeholder) {
+        Some(&bound) => bound == reg,
+        None => {
+            if regs.values().any(|&bound| bound == reg) {
+                return false;
+            }
+            regs.insert(placeholder, reg);
+            true
+        }
+    }
+}
+
+/// Binds the template argument to the program argument. Constant values must match exactly.
+fn bind_argument(
+    regs: &mut HashMap<char, char>,
+    pattern: &InstructionArgument,
+    arg: &InstructionArgument,
+) -> bool {
+    match (pattern, arg) {
+        (InstructionArgument::Value { val: p }, InstructionArgument::Value { val }) => p == val,
+        (InstructionArgument::Register { reg: p }, InstructionArgument::Register { reg }) => {
+            bind_register(regs, *p, *reg)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::machines::soundcomputer::{IsaProfile, SoundComputer};
+    use crate::utils::math::is_prime;
+
+    /// Tests that the composite check loop is found with the registers used by the program.
+    #[test]
+    fn test_find_rewrites_composite_check() {
+        let mut program = ProgramBuilder::new()
+            .set('x', 10)
+            .set('y', 1)
+            .build()
+            .unwrap();
+        program.extend(composite_check_block('x', 'y', 'p', 'q', 'r'));
+        let rewrites = find_rewrites(&program);
+        assert_eq!(program.len(), rewrites.len());
+        assert_eq!(
+            Some(Rewrite::CompositeCheck {
+                value: 'x',
+                flag: 'y',
+                divisor: 'p',
+                cofactor: 'q',
+                scratch: 'r',
+                len: 15,
+            }),
+            rewrites[2]
+        );
+        assert_eq!(1, rewrites.iter().flatten().count());
+        // Loop using the same register for the divisor and cofactor is not a match
+        assert!(find_rewrites(&composite_check_block('x', 'y', 'p', 'p', 'r'))[0].is_none());
+    }
+
+    /// Tests that the optimised machine finishes in the same state as the unoptimised machine.
+    #[test]
+    fn test_composite_check_equivalence() {
+        for value in 1..40 {
+            let mut program = ProgramBuilder::new()
+                .set('b', value)
+                .set('f', 1)
+                .build()
+                .unwrap();
+            program.extend(composite_check_block('b', 'f', 'd', 'e', 'g'));
+            let mut plain = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+            plain.execute_with_limit(100_000, None).unwrap();
+            let mut optimised = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+            assert_eq!(1, optimised.optimise());
+            optimised.execute_with_limit(100_000, None).unwrap();
+            assert_eq!(plain.snapshot(), optimised.snapshot());
+            if value >= 3 {
+                assert_eq!(
+                    !is_prime(value as u64),
+                    plain.read_register(&'f').unwrap() == 0
+                );
+            }
+        }
+    }
+
+    /// Tests that the rewrite is not applied when the number of multiplications in the loop would
+    /// overflow, so the loop is left to run as normal.
+    #[test]
+    fn test_composite_check_overflow() {
+        let mut program = ProgramBuilder::new()
+            .set('b', i64::MAX)
+            .set('f', 1)
+            .build()
+            .unwrap();
+        program.extend(composite_check_block('b', 'f', 'd', 'e', 'g'));
+        let mut plain = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+        plain.execute_with_limit(1_000, None).unwrap();
+        let mut optimised = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+        assert_eq!(1, optimised.optimise());
+        optimised.execute_with_limit(1_000, None).unwrap();
+        assert_eq!(plain.snapshot(), optimised.snapshot());
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::utils::machines::soundcomputer::{Instruction, InstructionArgument};
+
+/// Custom error type indicating that a [`ProgramBuilder`] failed to resolve the labels used in the
+/// program into relative jumps, or the program refers to a register that does not exist.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProgramBuildError {
+    /// A jump instruction referred to a label that was never defined.
+    UndefinedLabel { label: String },
+    /// The same label was defined more than once.
+    DuplicateLabel { label: String },
+    /// An instruction referred to a register other than 'a' to 'z'.
+    InvalidRegister { index: usize, reg: char },
+}
+
+impl fmt::Display for ProgramBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramBuildError::UndefinedLabel { label } => {
+                write!(f, "Program build failed: undefined label \"{label}\"")
+            }
+            ProgramBuildError::DuplicateLabel { label } => {
+                write!(f, "Program build failed: duplicate label \"{label}\"")
+            }
+            ProgramBuildError::InvalidRegister { index, reg } => write!(
+                f,
+                "Program build failed: instruction {index} refers to invalid register \"{reg}\""
+            ),
+        }
+    }
+}
+
+/// Instruction held by the [`ProgramBuilder`] prior to label resolution.
+enum PendingInstruction {
+    Resolved(Instruction),
+    JgzLabel {
+        arg1: InstructionArgument,
+        label: String,
+    },
+    JnzLabel {
+        arg1: InstructionArgument,
+        label: String,
+    },
+}
+
+/// Builder used to construct a program (vector of [`Instruction`]) for the [`SoundComputer`]
+/// without going through the text representation.
+///
+/// Jump instructions can target a label instead of giving a relative offset. Labels are resolved
+/// into relative jumps when the program is built. A label defined after the final instruction
+/// refers to the location just outside of the instruction space, so jumping to it halts the
+/// machine.
+///
+/// [`SoundComputer`]: crate::utils::machines::soundcomputer::SoundComputer
+#[derive(Default)]
+pub struct ProgramBuilder {
+    pending: Vec<PendingInstruction>,
+    labels: HashMap<String, usize>,
+    duplicate_labels: Vec<String>,
+}
+
+impl ProgramBuilder {
+    /// Creates a new [`ProgramBuilder`] with no instructions.
+    pub fn new() -> ProgramBuilder {
+        ProgramBuilder::default()
+    }
+
+    /// Adds a "snd" instruction.
+    pub fn snd(self, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Snd { arg: arg.into() })
+    }
+
+    /// Adds a "set" instruction.
+    pub fn set(self, reg: char, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Set {
+            reg,
+            arg: arg.into(),
+        })
+    }
+
+    /// Adds an "add" instruction.
+    pub fn add(self, reg: char, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Add {
+            reg,
+            arg: arg.into(),
+        })
+    }
+
+    /// Adds a "mul" instruction.
+    pub fn mul(self, reg: char, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Mul {
+            reg,
+            arg: arg.into(),
+        })
+    }
+
+    /// Adds a "mod" instruction.
+    pub fn modulus(self, reg: char, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Mod {
+            reg,
+            arg: arg.into(),
+        })
+    }
+
+    /// Adds a "rcv" instruction.
+    pub fn rcv(self, reg: char) -> Self {
+        self.push(Instruction::Rcv { reg })
+    }
+
+    /// Adds a "jgz" instruction with a relative jump.
+    pub fn jgz(
+        self,
+        arg1: impl Into<InstructionArgument>,
+        arg2: impl Into<InstructionArgument>,
+    ) -> Self {
+        self.push(Instruction::Jgz {
+            arg1: arg1.into(),
+            arg2: arg2.into(),
+        })
+    }
+
+    /// Adds a "sub" instruction.
+    pub fn sub(self, reg: char, arg: impl Into<InstructionArgument>) -> Self {
+        self.push(Instruction::Sub {
+            reg,
+            arg: arg.into(),
+        })
+    }
+
+    /// Adds a "jnz" instruction with a relative jump.
+    pub fn jnz(
+        self,
+        arg1: impl Into<InstructionArgument>,
+        arg2: impl Into<InstructionArgument>,
+    ) -> Self {
+        self.push(Instruction::Jnz {
+            arg1: arg1.into(),
+            arg2: arg2.into(),
+        })
+    }
+
+    /// Adds a "jgz" instruction that jumps to the given label.
+    pub fn jgz_label(mut self, arg1: impl Into<InstructionArgument>, label: &str) -> Self {
+        self.pending.push(PendingInstruction::JgzLabel {
+            arg1: arg1.into(),
+            label: label.to_string(),
+        });
+        self
+    }
+
+    /// Adds a "jnz" instruction that jumps to the given label.
+    pub fn jnz_label(mut self, arg1: impl Into<InstructionArgument>, label: &str) -> Self {
+        self.pending.push(PendingInstruction::JnzLabel {
+            arg1: arg1.into(),
+            label: label.to_string(),
+        });
+        self
+    }
+
+    /// Defines a label referring to the location of the next instruction added to the builder.
+    pub fn label(mut self, label: &str) -> Self {
+        if self
+            .labels
+            .insert(label.to_string(), self.pending.len())
+            .is_some()
+        {
+            self.duplicate_labels.push(label.to_string());
+        }
+        self
+    }
+
+    /// Builds the program, resolving all labels into relative jumps.
+    ///
+    /// Returns a [`ProgramBuildError`] if a label is defined more than once, a jump refers to an
+    /// undefined label or an instruction refers to a register other than 'a' to 'z'.
+    pub fn build(self) -> Result<Vec<Instruction>, ProgramBuildError> {
+        if let Some(label) = self.duplicate_labels.into_iter().next() {
+            return Err(ProgramBuildError::DuplicateLabel { label });
+        }
+        let mut program: Vec<Instruction> = vec![];
+        for (i, pending) in self.pending.into_iter().enumerate() {
+            let instruction = match pending {
+                PendingInstruction::Resolved(instruction) => instruction,
+                PendingInstruction::JgzLabel { arg1, label } => Instruction::Jgz {
+                    arg1,
+                    arg2: resolve_label(&self.labels, &label, i)?,
+                },
+                PendingInstruction::JnzLabel { arg1, label } => Instruction::Jnz {
+                    arg1,
+                    arg2: resolve_label(&self.labels, &label, i)?,
+                },
+            };
+            if let Some(reg) = instruction.invalid_register() {
+                return Err(ProgramBuildError::InvalidRegister { index: i, reg });
+            }
+            program.push(instruction);
+        }
+        Ok(program)
+    }
+
+    /// Adds an instruction that does not require label resolution.
+    fn push(mut self, instruction: Instruction) -> Self {
+        self.pending.push(PendingInstruction::Resolved(instruction));
+        self
+    }
+}
+
+/// Converts the label into a jump relative to the instruction at the given index.
+fn resolve_label(
+    labels: &HashMap<String, usize>,
+    label: &str,
+    index: usize,
+) -> Result<InstructionArgument, ProgramBuildError> {
+    match labels.get(label) {
+        Some(&target) => Ok(InstructionArgument::Value {
+            val: target as i64 - index as i64,
+        }),
+        None => Err(ProgramBuildError::UndefinedLabel {
+            label: label.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that a program built with relative jumps matches the parsed text representation.
+    #[test]
+    fn test_build_matches_parsed_program() {
+        let built = ProgramBuilder::new()
+            .set('a', 5)
+            .add('a', -1)
+            .jgz('a', -1)
+            .snd('b')
+            .rcv('b')
+            .build()
+            .unwrap();
+        let parsed =
+            Instruction::parse_program("set a 5\nadd a -1\njgz a -1\nsnd b\nrcv b").unwrap();
+        assert_eq!(parsed, built);
+    }
+
+    /// Tests that labels are resolved into relative jumps in both directions.
+    #[test]
+    fn test_build_resolves_labels() {
+        let built = ProgramBuilder::new()
+            .set('i', 3)
+            .label("loop")
+            .sub('i', 1)
+            .jnz_label('i', "loop")
+            .jgz_label(1, "end")
+            .mul('a', 2)
+            .label("end")
+            .build()
+            .unwrap();
+        let parsed =
+            Instruction::parse_program("set i 3\nsub i 1\njnz i -1\njgz 1 2\nmul a 2").unwrap();
+        assert_eq!(parsed, built);
+    }
+
+    /// Tests that undefined and duplicate labels are reported as errors.
+    #[test]
+    fn test_build_label_errors() {
+        let undefined = ProgramBuilder::new().jnz_label(1, "nowhere").build();
+        assert_eq!(
+            Err(ProgramBuildError::UndefinedLabel {
+                label: String::from("nowhere")
+            }),
+            undefined
+        );
+        let duplicate = ProgramBuilder::new()
+            .label("a")
+            .set('a', 1)
+            .label("a")
+            .build();
+        assert_eq!(
+            Err(ProgramBuildError::DuplicateLabel {
+                label: String::from("a")
+            }),
+            duplicate
+        );
+    }
+
+    /// Tests that instructions referring to registers other than 'a' to 'z' are rejected.
+    #[test]
+    fn test_build_invalid_register() {
+        let built = ProgramBuilder::new().set('a', 1).add('A', 'a').build();
+        assert_eq!(
+            Err(ProgramBuildError::InvalidRegister { index: 1, reg: 'A' }),
+            built
+        );
+        let built = ProgramBuilder::new().label("l").jnz_label('$', "l").build();
+        assert_eq!(
+            Err(ProgramBuildError::InvalidRegister { index: 0, reg: '$' }),
+            built
+        );
+    }
+}
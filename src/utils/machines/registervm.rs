@@ -0,0 +1,222 @@
+/// Control flow resulting from the execution of a single instruction by a [`RegisterVm`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Flow {
+    /// Continue with the next instruction.
+    Next,
+    /// Jump by the given offset relative to the current instruction.
+    Jump(i64),
+    /// Stop without moving the program counter, so that the same instruction is executed again on
+    /// the next step (e.g. while waiting for input).
+    Yield,
+}
+
+/// Outcome of a single call to [`RegisterVm::step`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VmStep {
+    /// An instruction was executed.
+    Executed,
+    /// The instruction at the program counter yielded instead of executing.
+    Yielded,
+    /// No instruction was executed because the machine has halted.
+    Halted,
+}
+
+/// Execution state of a [`RegisterVm`] that is visible to the instructions it executes - the
+/// register file, the program counter and the extra state required by the instruction set.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VmState<F, C> {
+    pub registers: F,
+    pub context: C,
+    pub pc: usize,
+}
+
+/// Instruction set that can be executed by a [`RegisterVm`].
+pub trait Op {
+    /// Register file holding the values of the registers (e.g. a map from register names to
+    /// values, or an array for a fixed set of registers).
+    type Registers;
+    /// Extra state (beyond the registers and program counter) used by the instructions.
+    type Context;
+    /// Error raised by an instruction that cannot be executed.
+    type Error;
+
+    /// Executes the instruction against the state of the machine, returning the resulting control
+    /// flow. The program counter is updated by the machine, not the instruction.
+    fn execute(
+        &self,
+        state: &mut VmState<Self::Registers, Self::Context>,
+    ) -> Result<Flow, Self::Error>;
+}
+
+/// Virtual machine holding a set of registers, a program made up of instructions from the
+/// instruction set `I` and a program counter.
+///
+/// The machine halts when the program counter moves outside of the program.
+pub struct RegisterVm<I: Op> {
+    program: Vec<I>,
+    state: VmState<I::Registers, I::Context>,
+    halted: bool,
+}
+
+impl<I: Op + Clone> RegisterVm<I> {
+    /// Creates a new [`RegisterVm`] loaded with the given program, initial register file and
+    /// instruction set context.
+    pub fn new(program: &[I], registers: I::Registers, context: I::Context) -> RegisterVm<I> {
+        RegisterVm {
+            program: program.to_vec(),
+            state: VmState {
+                registers,
+                context,
+                pc: 0,
+            },
+            halted: false,
+        }
+    }
+}
+
+impl<I: Op> RegisterVm<I> {
+    /// Executes the single instruction at the current location of the program counter.
+    ///
+    /// Returned value indicates whether an instruction was executed, or the reason that no
+    /// instruction was executed. If the instruction cannot be executed, its error is returned and
+    /// the program counter is left unchanged.
+    pub fn step(&mut self) -> Result<VmStep, I::Error> {
+        if self.halted {
+            return Ok(VmStep::Halted);
+        }
+        // Halt execution if PC is outside of instruction space
+        let Some(instruction) = self.program.get(self.state.pc) else {
+            self.halted = true;
+            return Ok(VmStep::Halted);
+        };
+        match instruction.execute(&mut self.state)? {
+            Flow::Next => self.state.pc += 1,
+            Flow::Jump(offset) => self.jump(offset),
+            Flow::Yield => return Ok(VmStep::Yielded),
+        }
+        Ok(VmStep::Executed)
+    }
+
+    /// Executes instructions until the machine halts or an instruction yields.
+    ///
+    /// Returned value is the number of instructions executed.
+    pub fn run(&mut self) -> Result<u64, I::Error> {
+        let mut steps = 0;
+        while self.step()? == VmStep::Executed {
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Moves the program counter by the given offset. If this would move the program counter left
+    /// of the instruction space (or beyond the range of `usize`), the machine is halted.
+    pub fn jump(&mut self, offset: i64) {
+        match usize::try_from(offset.unsigned_abs()) {
+            Ok(distance) if offset >= 0 => match self.state.pc.checked_add(distance) {
+                Some(pc) => self.state.pc = pc,
+                None => self.halted = true,
+            },
+            Ok(distance) if distance <= self.state.pc => self.state.pc -= distance,
+            _ => self.halted = true,
+        }
+    }
+
+    /// Gets the program loaded into the machine.
+    pub fn program(&self) -> &[I] {
+        &self.program
+    }
+
+    /// Gets the execution state of the machine.
+    pub fn state(&self) -> &VmState<I::Registers, I::Context> {
+        &self.state
+    }
+
+    /// Gets mutable access to the execution state of the machine.
+    pub fn state_mut(&mut self) -> &mut VmState<I::Registers, I::Context> {
+        &mut self.state
+    }
+
+    /// Checks if the machine has halted.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Sets whether the machine has halted.
+    pub fn set_halted(&mut self, halted: bool) {
+        self.halted = halted;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Minimal instruction set used to test the execution core.
+    #[derive(Copy, Clone)]
+    enum TestOp {
+        Add(char, i64),
+        JumpIfPositive(char, i64),
+        YieldIfNegative(char),
+        Fail,
+    }
+
+    impl Op for TestOp {
+        type Registers = HashMap<char, i64>;
+        type Context = u64;
+        type Error = &'static str;
+
+        fn execute(
+            &self,
+            state: &mut VmState<HashMap<char, i64>, u64>,
+        ) -> Result<Flow, &'static str> {
+            state.context += 1;
+            match *self {
+                TestOp::Add(reg, value) => {
+                    *state.registers.entry(reg).or_insert(0) += value;
+                    Ok(Flow::Next)
+                }
+                TestOp::JumpIfPositive(reg, offset) => match state.registers[&reg] > 0 {
+                    true => Ok(Flow::Jump(offset)),
+                    false => Ok(Flow::Next),
+                },
+                TestOp::YieldIfNegative(reg) => match state.registers[&reg] < 0 {
+                    true => Ok(Flow::Yield),
+                    false => Ok(Flow::Next),
+                },
+                TestOp::Fail => Err("failed"),
+            }
+        }
+    }
+
+    /// Tests jumps, yields, halting and errors raised by instructions.
+    #[test]
+    fn test_register_vm_control_flow() {
+        let program = [
+            TestOp::Add('a', 3),
+            TestOp::Add('a', -1),
+            TestOp::JumpIfPositive('a', -1),
+            TestOp::YieldIfNegative('b'),
+            TestOp::JumpIfPositive('c', 2),
+            TestOp::Fail,
+            TestOp::JumpIfPositive('c', -10),
+        ];
+        let registers = HashMap::from([('a', 0), ('b', -1), ('c', 1)]);
+        let mut vm = RegisterVm::new(&program, registers, 0);
+        assert_eq!(Ok(7), vm.run());
+        assert_eq!(Ok(VmStep::Yielded), vm.step());
+        assert_eq!(3, vm.state().pc);
+        vm.state_mut().registers.insert('b', 0);
+        assert_eq!(Ok(3), vm.run());
+        assert!(vm.is_halted());
+        assert_eq!(Ok(VmStep::Halted), vm.step());
+        assert_eq!(12, vm.state().context);
+        // Error leaves the program counter on the failing instruction
+        vm.state_mut().registers.insert('c', 0);
+        vm.state_mut().pc = 4;
+        vm.set_halted(false);
+        assert_eq!(Err("failed"), vm.run());
+        assert_eq!(5, vm.state().pc);
+    }
+}
@@ -1,38 +1,319 @@
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::VecDeque,
+    fmt,
     str::FromStr,
+    time::{Duration, Instant},
 };
 
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
 use fancy_regex::Regex;
-use lazy_static::lazy_static;
 
-lazy_static! {
-    static ref SND_REGEX: Regex = Regex::new(r"^snd ([a-z]|-?\d+)$").unwrap();
-    static ref SET_REGEX: Regex = Regex::new(r"^set ([a-z]) ([a-z]|-?\d+)$").unwrap();
-    static ref ADD_REGEX: Regex = Regex::new(r"^add ([a-z]) ([a-z]|-?\d+)$").unwrap();
-    static ref MUL_REGEX: Regex = Regex::new(r"^mul ([a-z]) ([a-z]|-?\d+)$").unwrap();
-    static ref MOD_REGEX: Regex = Regex::new(r"^mod ([a-z]) ([a-z]|-?\d+)$").unwrap();
-    static ref RCV_REGEX: Regex = Regex::new(r"^rcv ([a-z])$").unwrap();
-    static ref JGZ_REGEX: Regex = Regex::new(r"^jgz ([a-z]|-?\d+) ([a-z]|-?\d+)$").unwrap();
-    static ref SUB_REGEX: Regex = Regex::new(r"^sub ([a-z]) ([a-z]|-?\d+)$").unwrap();
-    static ref JNZ_REGEX: Regex = Regex::new(r"^jnz ([a-z]|-?\d+) ([a-z]|-?\d+)$").unwrap();
-}
+use crate::utils::error::AocError;
+use crate::utils::machines::compiler::CompiledProgram;
+use crate::utils::machines::coverage::Coverage;
+use crate::utils::machines::optimiser::{find_rewrites, Rewrite};
+use crate::utils::machines::registervm::{Flow, Op, RegisterVm, VmState, VmStep};
+use crate::utils::math::is_prime;
+use crate::utils::parse::{parse_integer, parse_lines_with};
 
 /// Custom error type indicating that the parsing of raw input to a variant of the [`Instruction`]
 /// enum has failed.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct InstructionParseError;
 
 /// Custom error type indicating that a register read for a [`SoundComputer`] has failed.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct RegisterReadError;
 
 /// Custom error type indicating that a register write for a [`SoundComputer`] has failed.
 #[derive(Debug)]
 pub struct RegisterWriteError;
 
+/// Custom error type indicating that a program given to a [`SoundComputer`] contains an
+/// instruction that is not legal under the selected [`IsaProfile`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct IllegalInstructionError {
+    pub index: usize,
+    pub opcode: &'static str,
+    pub profile: IsaProfile,
+}
+
+/// Custom error type indicating that an instruction executed by a [`SoundComputer`] has failed. The
+/// program counter is left on the failing instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExecutionError {
+    /// Result of the instruction is outside of the range of values the registers can hold under
+    /// the selected [`RegisterMode`] (or the value sent by "snd" does not fit in 64 bits).
+    Overflow { index: usize, opcode: &'static str },
+    /// A "mod" instruction was executed with a divisor of zero.
+    DivideByZero { index: usize },
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::Overflow { index, opcode } => {
+                write!(f, "Instruction {index} (\"{opcode}\") overflowed")
+            }
+            ExecutionError::DivideByZero { index } => {
+                write!(f, "Instruction {index} (\"mod\") divided by zero")
+            }
+        }
+    }
+}
+
+/// Custom error type indicating that a program could not be loaded into a [`SoundComputer`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProgramLoadError {
+    /// A line of the program could not be parsed as an [`Instruction`].
+    Parse(AocError),
+    /// The program contains an instruction that is not legal under the selected [`IsaProfile`].
+    IllegalInstruction(IllegalInstructionError),
+    /// The instruction refers to a register other than 'a' to 'z'.
+    InvalidRegister { index: usize, reg: char },
+}
+
+impl fmt::Display for ProgramLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramLoadError::Parse(e) => write!(f, "Program load failed: {e}"),
+            ProgramLoadError::IllegalInstruction(e) => write!(f, "Program load failed: {e}"),
+            ProgramLoadError::InvalidRegister { index, reg } => write!(
+                f,
+                "Program load failed: Instruction {index} refers to invalid register \"{reg}\""
+            ),
+        }
+    }
+}
+
+impl From<AocError> for ProgramLoadError {
+    fn from(e: AocError) -> Self {
+        ProgramLoadError::Parse(e)
+    }
+}
+
+impl From<IllegalInstructionError> for ProgramLoadError {
+    fn from(e: IllegalInstructionError) -> Self {
+        ProgramLoadError::IllegalInstruction(e)
+    }
+}
+
+impl fmt::Display for IllegalInstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Instruction {} (\"{}\") is not legal under the {:?} profile",
+            self.index, self.opcode, self.profile
+        )
+    }
+}
+
+/// Instruction set profiles supported by the [`SoundComputer`]. The profile controls which
+/// instructions are legal and how the "snd" and "rcv" instructions behave.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum IsaProfile {
+    /// Day 18 (solo) - "snd" plays a sound and "rcv" recovers the frequency of the last sound
+    /// played if its register is non-zero.
+    Sound,
+    /// Day 18 (duet) - "snd" sends a value to the paired machine and "rcv" waits to receive a
+    /// value from the paired machine.
+    Duet,
+    /// Day 23 - only the "set", "sub", "mul" and "jnz" instructions are legal.
+    Coprocessor,
+}
+
+impl IsaProfile {
+    /// Checks if the instruction is legal under the profile.
+    pub fn permits(&self, instruction: &Instruction) -> bool {
+        match self {
+            IsaProfile::Sound | IsaProfile::Duet => !matches!(
+                instruction,
+                Instruction::Sub { .. } | Instruction::Jnz { .. }
+            ),
+            IsaProfile::Coprocessor => matches!(
+                instruction,
+                Instruction::Set { .. }
+                    | Instruction::Sub { .. }
+                    | Instruction::Mul { .. }
+                    | Instruction::Jnz { .. }
+            ),
+        }
+    }
+}
+
+/// Semantics of the "mod" instruction used by the [`SoundComputer`] when either of its operands is
+/// negative.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ModSemantics {
+    /// Remainder takes the sign of the register value (Rust's `%` operator), e.g. -7 mod 3 = -1.
+    Truncated,
+    /// Remainder is never negative, as for the modulo operation described by the puzzle, e.g.
+    /// -7 mod 3 = 2.
+    #[default]
+    Euclidean,
+}
+
+impl ModSemantics {
+    /// Calculates the remainder of dividing the value by the divisor under these semantics.
+    ///
+    /// Returns None if the divisor is zero or the calculation overflows.
+    pub fn apply<W: RegisterWord>(&self, value: W, divisor: W) -> Option<W> {
+        match self {
+            ModSemantics::Truncated => value.checked_rem(divisor),
+            ModSemantics::Euclidean => value.checked_rem_euclid(divisor),
+        }
+    }
+}
+
+/// Range of values that can be held in the registers of the [`SoundComputer`]. Arithmetic that
+/// produces a value outside of the range raises an [`ExecutionError::Overflow`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum RegisterMode {
+    /// Registers hold 64-bit values.
+    #[default]
+    Checked,
+    /// Registers hold 128-bit values. Sounds sent and received are still limited to 64 bits.
+    #[cfg(feature = "wide-registers")]
+    Wide,
+}
+
+/// Integer type held in the registers of the [`SoundComputer`] under one of the [`RegisterMode`]s,
+/// providing the checked arithmetic used by the instructions.
+pub trait RegisterWord: Copy + Ord + From<i64> + fmt::Debug {
+    const ZERO: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_rem_euclid(self, rhs: Self) -> Option<Self>;
+
+    /// Converts the value to 64 bits, returning None if it does not fit.
+    fn to_i64(self) -> Option<i64>;
+}
+
+macro_rules! impl_register_word {
+    ($t:ty) => {
+        impl RegisterWord for $t {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem_euclid(self, rhs)
+            }
+
+            fn to_i64(self) -> Option<i64> {
+                i64::try_from(self).ok()
+            }
+        }
+    };
+}
+
+impl_register_word!(i64);
+#[cfg(feature = "wide-registers")]
+impl_register_word!(i128);
+
+/// Number of registers held by the [`SoundComputer`] ('a' to 'z').
+pub const REGISTER_COUNT: usize = 26;
+
+/// Register file of the [`SoundComputer`] under [`RegisterMode::Checked`], with register 'a' held
+/// at index 0.
+pub type RegisterFile = [i64; REGISTER_COUNT];
+
+/// Register file of the [`SoundComputer`] under [`RegisterMode::Wide`], with register 'a' held at
+/// index 0.
+#[cfg(feature = "wide-registers")]
+pub type WideRegisterFile = [i128; REGISTER_COUNT];
+
+/// Registers of the [`SoundComputer`], held in the register file for the selected
+/// [`RegisterMode`]. The wide register file is boxed so that it does not enlarge the bank under
+/// the default mode.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RegisterBank {
+    Checked(RegisterFile),
+    #[cfg(feature = "wide-registers")]
+    Wide(Box<WideRegisterFile>),
+}
+
+impl RegisterBank {
+    /// Creates a new [`RegisterBank`] for the register mode, with every register set to 0.
+    pub fn new(mode: RegisterMode) -> RegisterBank {
+        match mode {
+            RegisterMode::Checked => RegisterBank::Checked([0; REGISTER_COUNT]),
+            #[cfg(feature = "wide-registers")]
+            RegisterMode::Wide => RegisterBank::Wide(Box::new([0; REGISTER_COUNT])),
+        }
+    }
+
+    /// Gets the register mode of the bank.
+    pub fn mode(&self) -> RegisterMode {
+        match self {
+            RegisterBank::Checked(_) => RegisterMode::Checked,
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(_) => RegisterMode::Wide,
+        }
+    }
+
+    /// Gets the value held in the register at the given index.
+    ///
+    /// Returns None if there is no register at the index or its value does not fit in 64 bits.
+    pub fn get(&self, index: usize) -> Option<i64> {
+        match self {
+            RegisterBank::Checked(registers) => registers.get(index).copied(),
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(registers) => registers.get(index)?.to_i64(),
+        }
+    }
+
+    /// Gets the full value held in the register at the given index, or None if there is no
+    /// register at the index.
+    pub fn get_wide(&self, index: usize) -> Option<i128> {
+        match self {
+            RegisterBank::Checked(registers) => registers.get(index).map(|&value| value.into()),
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(registers) => registers.get(index).copied(),
+        }
+    }
+
+    /// Sets the value held in the register at the given index, which must be less than
+    /// [`REGISTER_COUNT`].
+    fn set(&mut self, index: usize, value: i64) {
+        match self {
+            RegisterBank::Checked(registers) => registers[index] = value,
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(registers) => registers[index] = value.into(),
+        }
+    }
+}
+
+/// Gets the index of the register in the [`RegisterFile`], or None if the register is not one of
+/// 'a' to 'z'.
+pub fn register_index(reg: char) -> Option<usize> {
+    reg.is_ascii_lowercase()
+        .then(|| (reg as u8 - b'a') as usize)
+}
+
 /// Enum representing the different instructions that can be executed by the [`SoundComputer`].
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Instruction {
     /// Play sound / send (duet mode)
     Snd { arg: InstructionArgument },
@@ -61,13 +342,82 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    /// Parses a line-separated sequence of instructions into a vector.
-    pub fn parse_raw_input(raw_input: &str) -> Vec<Instruction> {
-        raw_input
-            .trim()
-            .lines()
-            .map(|line| Instruction::from_str(line).unwrap())
-            .collect::<Vec<Instruction>>()
+    /// Returns the opcode (mnemonic) of the instruction.
+    pub fn opcode(&self) -> &'static str {
+        match self {
+            Instruction::Snd { .. } => "snd",
+            Instruction::Set { .. } => "set",
+            Instruction::Add { .. } => "add",
+            Instruction::Mul { .. } => "mul",
+            Instruction::Mod { .. } => "mod",
+            Instruction::Rcv { .. } => "rcv",
+            Instruction::Jgz { .. } => "jgz",
+            Instruction::Sub { .. } => "sub",
+            Instruction::Jnz { .. } => "jnz",
+        }
+    }
+
+    /// Gets the first register named by the instruction (as its target or in an argument) that is
+    /// not one of 'a' to 'z', or None if all of its registers are valid.
+    pub fn invalid_register(&self) -> Option<char> {
+        let arg_reg = |arg: &InstructionArgument| match *arg {
+            InstructionArgument::Register { reg } => Some(reg),
+            InstructionArgument::Value { .. } => None,
+        };
+        let registers = match self {
+            Instruction::Snd { arg } => [arg_reg(arg), None],
+            Instruction::Rcv { reg } => [Some(*reg), None],
+            Instruction::Set { reg, arg }
+            | Instruction::Add { reg, arg }
+            | Instruction::Mul { reg, arg }
+            | Instruction::Mod { reg, arg }
+            | Instruction::Sub { reg, arg } => [Some(*reg), arg_reg(arg)],
+            Instruction::Jgz { arg1, arg2 } | Instruction::Jnz { arg1, arg2 } => {
+                [arg_reg(arg1), arg_reg(arg2)]
+            }
+        };
+        registers
+            .into_iter()
+            .flatten()
+            .find(|&reg| register_index(reg).is_none())
+    }
+
+    /// Parses a line-separated sequence of instructions into a vector. Blank lines and whitespace
+    /// around each line are ignored.
+    ///
+    /// Returns an [`AocError`] giving the line number of the first line that is not a valid
+    /// instruction.
+    pub fn parse_program(raw_input: &str) -> Result<Vec<Instruction>, AocError> {
+        parse_lines_with(raw_input, Instruction::from_str)
+    }
+
+    /// Formats the instructions as line-separated text, which is parsed back into the same
+    /// instructions by [`Instruction::parse_program`].
+    pub fn format_program(instructions: &[Instruction]) -> String {
+        instructions
+            .iter()
+            .map(|instruction| format!("{instruction}\n"))
+            .collect()
+    }
+}
+
+impl fmt::Display for Instruction {
+    /// Formats the instruction in the same form as the problem input, so that the text is parsed
+    /// back into the same instruction.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let opcode = self.opcode();
+        match self {
+            Instruction::Snd { arg } => write!(f, "{opcode} {arg}"),
+            Instruction::Rcv { reg } => write!(f, "{opcode} {reg}"),
+            Instruction::Set { reg, arg }
+            | Instruction::Add { reg, arg }
+            | Instruction::Mul { reg, arg }
+            | Instruction::Mod { reg, arg }
+            | Instruction::Sub { reg, arg } => write!(f, "{opcode} {reg} {arg}"),
+            Instruction::Jgz { arg1, arg2 } | Instruction::Jnz { arg1, arg2 } => {
+                write!(f, "{opcode} {arg1} {arg2}")
+            }
+        }
     }
 }
 
@@ -75,192 +425,703 @@ impl FromStr for Instruction {
     type Err = InstructionParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(Some(caps)) = SND_REGEX.captures(s) {
-            let arg = InstructionArgument::from_str(&caps[1]).unwrap();
-            return Ok(Instruction::Snd { arg });
-        } else if let Ok(Some(caps)) = SET_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            let arg = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Set { reg, arg });
-        } else if let Ok(Some(caps)) = ADD_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            let arg = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Add { reg, arg });
-        } else if let Ok(Some(caps)) = MUL_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            let arg = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Mul { reg, arg });
-        } else if let Ok(Some(caps)) = MOD_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            let arg = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Mod { reg, arg });
-        } else if let Ok(Some(caps)) = RCV_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            return Ok(Instruction::Rcv { reg });
-        } else if let Ok(Some(caps)) = JGZ_REGEX.captures(s) {
-            let arg1 = InstructionArgument::from_str(&caps[1]).unwrap();
-            let arg2 = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Jgz { arg1, arg2 });
-        } else if let Ok(Some(caps)) = SUB_REGEX.captures(s) {
-            let reg = caps[1].parse::<char>().unwrap();
-            let arg = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Sub { reg, arg });
-        } else if let Ok(Some(caps)) = JNZ_REGEX.captures(s) {
-            let arg1 = InstructionArgument::from_str(&caps[1]).unwrap();
-            let arg2 = InstructionArgument::from_str(&caps[2]).unwrap();
-            return Ok(Instruction::Jnz { arg1, arg2 });
-        }
-        // Failed to match the input text to an instruction pattern
-        Err(InstructionParseError)
+        let tokens = tokenise_instruction(s).ok_or(InstructionParseError)?;
+        let reg = |token: &str| parse_register(token).ok_or(InstructionParseError);
+        let arg = |token: &str| parse_argument(token).ok_or(InstructionParseError);
+        match tokens.as_slice() {
+            ["snd", arg1] => Ok(Instruction::Snd { arg: arg(arg1)? }),
+            ["set", reg1, arg1] => Ok(Instruction::Set {
+                reg: reg(reg1)?,
+                arg: arg(arg1)?,
+            }),
+            ["add", reg1, arg1] => Ok(Instruction::Add {
+                reg: reg(reg1)?,
+                arg: arg(arg1)?,
+            }),
+            ["mul", reg1, arg1] => Ok(Instruction::Mul {
+                reg: reg(reg1)?,
+                arg: arg(arg1)?,
+            }),
+            ["mod", reg1, arg1] => Ok(Instruction::Mod {
+                reg: reg(reg1)?,
+                arg: arg(arg1)?,
+            }),
+            ["rcv", reg1] => Ok(Instruction::Rcv { reg: reg(reg1)? }),
+            ["jgz", arg1, arg2] => Ok(Instruction::Jgz {
+                arg1: arg(arg1)?,
+                arg2: arg(arg2)?,
+            }),
+            ["sub", reg1, arg1] => Ok(Instruction::Sub {
+                reg: reg(reg1)?,
+                arg: arg(arg1)?,
+            }),
+            ["jnz", arg1, arg2] => Ok(Instruction::Jnz {
+                arg1: arg(arg1)?,
+                arg2: arg(arg2)?,
+            }),
+            // Failed to match the input text to an instruction pattern
+            _ => Err(InstructionParseError),
+        }
+    }
+}
+
+/// Splits the text of an instruction into its opcode and arguments, each separated by a single
+/// space. Returns None if the text is not a three-letter opcode followed by one or two arguments.
+#[cfg(feature = "regex")]
+fn tokenise_instruction(s: &str) -> Option<Vec<&str>> {
+    static INSTRUCTION_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = INSTRUCTION_REGEX
+        .get_or_init(|| Regex::new(r"^([a-z]{3}) ([a-z]|-?\d+)(?: ([a-z]|-?\d+))?$").unwrap());
+    let caps = regex.captures(s).ok()??;
+    Some(caps.iter().skip(1).flatten().map(|m| m.as_str()).collect())
+}
+
+/// Splits the text of an instruction into its opcode and arguments, each separated by a single
+/// space. The opcode and number of arguments are checked when the instruction is built from the
+/// tokens, so this always succeeds.
+#[cfg(not(feature = "regex"))]
+fn tokenise_instruction(s: &str) -> Option<Vec<&str>> {
+    Some(s.split(' ').collect())
+}
+
+/// Parses the name of a register - a single lowercase letter.
+fn parse_register(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(reg), None) if reg.is_ascii_lowercase() => Some(reg),
+        _ => None,
+    }
+}
+
+/// Parses an instruction argument, given either as an integer value or the name of a register.
+fn parse_argument(s: &str) -> Option<InstructionArgument> {
+    match parse_integer::<i64>(s) {
+        Ok(val) => Some(InstructionArgument::Value { val }),
+        Err(_) => parse_register(s).map(|reg| InstructionArgument::Register { reg }),
     }
 }
 
 /// Enum used to represent the two possible types of arguments present in [`Instruction`] variant
 /// fields. Some Instructions have arguments have can either be a constant integer value or a value
 /// read from the register of a [`SoundComputer`].
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InstructionArgument {
     Value { val: i64 },
     Register { reg: char },
 }
 
+impl fmt::Display for InstructionArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstructionArgument::Value { val } => write!(f, "{val}"),
+            InstructionArgument::Register { reg } => write!(f, "{reg}"),
+        }
+    }
+}
+
+impl From<i64> for InstructionArgument {
+    fn from(val: i64) -> Self {
+        InstructionArgument::Value { val }
+    }
+}
+
+impl From<i32> for InstructionArgument {
+    fn from(val: i32) -> Self {
+        InstructionArgument::Value { val: val as i64 }
+    }
+}
+
+impl From<char> for InstructionArgument {
+    fn from(reg: char) -> Self {
+        InstructionArgument::Register { reg }
+    }
+}
+
 impl FromStr for InstructionArgument {
     type Err = InstructionParseError;
 
+    /// Parses an argument given either as an integer value or the name of a register ('a' to 'z').
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(val) = s.parse::<i64>() {
-            return Ok(InstructionArgument::Value { val });
-        } else if let Ok(reg) = s.parse::<char>() {
-            return Ok(InstructionArgument::Register { reg });
+        parse_argument(s).ok_or(InstructionParseError)
+    }
+}
+
+/// Number of steps executed between checks of the wall-clock timeout in
+/// [`SoundComputer::execute_with_limit`].
+const TIMEOUT_CHECK_INTERVAL: u64 = 4096;
+
+/// Outcome of a single call to [`SoundComputer::step`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StepOutcome {
+    /// An instruction was executed.
+    Executed,
+    /// No instruction was executed because the machine is waiting to receive input.
+    AwaitingInput,
+    /// No instruction was executed because the machine has recovered a sound frequency.
+    Recovered,
+    /// No instruction was executed because the machine has halted.
+    Halted,
+}
+
+/// Reasons that execution under [`SoundComputer::execute_with_limit`] can stop.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StopReason {
+    Halted,
+    AwaitingInput,
+    Recovered,
+    StepLimit,
+    Timeout,
+}
+
+/// Summary of a run of [`SoundComputer::execute_with_limit`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ExecutionReport {
+    pub steps: u64,
+    pub stop_reason: StopReason,
+}
+
+impl ExecutionReport {
+    fn new(steps: u64, stop_reason: StopReason) -> ExecutionReport {
+        ExecutionReport { steps, stop_reason }
+    }
+
+    /// Checks if execution was stopped by the step limit or timeout rather than the machine itself.
+    pub fn limit_reached(&self) -> bool {
+        matches!(
+            self.stop_reason,
+            StopReason::StepLimit | StopReason::Timeout
+        )
+    }
+}
+
+/// Result of a run of [`SoundComputer::run_solo`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SoloOutcome {
+    /// Frequency of the last sound played when a "rcv" instruction recovered it, or None if the
+    /// machine stopped without recovering a frequency.
+    pub recovered_frequency: Option<i64>,
+    /// Number of instructions executed before the machine stopped. The "rcv" instruction that
+    /// recovers a frequency is not counted.
+    pub steps: u64,
+}
+
+/// Instruction executed by a [`SoundComputer`], yielded by [`SoundComputer::iter_steps`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExecutedStep {
+    /// Location of the instruction in the program.
+    pub pc: usize,
+    /// Instruction executed. If an optimiser rewrite was applied in place of a block of
+    /// instructions, this is the first instruction of the block.
+    pub instruction: Instruction,
+    /// Values held in the registers after the instruction was executed.
+    pub registers_after: RegisterBank,
+}
+
+/// Iterator over the instructions executed by a [`SoundComputer`], created by
+/// [`SoundComputer::iter_steps`].
+///
+/// Each call to `next` executes a single step. The iterator ends when the machine stops executing
+/// instructions, the step limit is reached or an instruction fails (in which case the
+/// [`ExecutionError`] is the final item).
+pub struct StepIter<'a> {
+    comp: &'a mut SoundComputer,
+    steps_remaining: u64,
+    failed: bool,
+}
+
+impl Iterator for StepIter<'_> {
+    type Item = Result<ExecutedStep, ExecutionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.steps_remaining == 0 {
+            return None;
+        }
+        let pc = self.comp.vm.state().pc;
+        let instruction = *self.comp.vm.program().get(pc)?;
+        match self.comp.step() {
+            Ok(StepOutcome::Executed) => {
+                self.steps_remaining -= 1;
+                Some(Ok(ExecutedStep {
+                    pc,
+                    instruction,
+                    registers_after: self.comp.vm.state().registers.clone(),
+                }))
+            }
+            Ok(_) => None,
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
         }
-        Err(InstructionParseError)
     }
 }
 
-/// Represents a sound computer that can execute instructions (see [`Instruction`]) in either
-/// single-mode or duet-mode.
+/// Snapshot of the execution state of a [`SoundComputer`], captured by
+/// [`SoundComputer::snapshot`] and restored by [`SoundComputer::restore`].
 ///
-/// The sound computer has 26 registers (labelled 'a' to 'z') that are initialised to 0.
-pub struct SoundComputer {
-    instructions: Vec<Instruction>,
-    registers: HashMap<char, i64>,
-    duet_mode: bool,
-    pc: usize,
+/// The program held by the machine is not part of the state, so a snapshot should only be
+/// restored into a machine loaded with the same program.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MachineState {
+    pub registers: RegisterBank,
+    pub pc: usize,
+    pub sounds_sent: VecDeque<i64>,
+    pub sounds_received: VecDeque<i64>,
+    pub awaiting_input: bool,
+    pub halted: bool,
+    pub total_sounds_sent: u64,
+    pub total_sounds_received: u64,
+    pub max_received_queue_len: usize,
+    pub last_sound_sent: Option<i64>,
+    pub mul_executions_count: usize,
+}
+
+/// Instruction set context used by the [`SoundComputer`], holding the selected [`IsaProfile`] and
+/// [`ModSemantics`] along with the sound buffers and execution statistics.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SoundState {
+    profile: IsaProfile,
+    mod_semantics: ModSemantics,
     sounds_sent: VecDeque<i64>,
     sounds_received: VecDeque<i64>,
     awaiting_input: bool,
-    halted: bool,
     total_sounds_sent: u64,
+    total_sounds_received: u64,
+    max_received_queue_len: usize,
     last_sound_sent: Option<i64>,
     mul_executions_count: usize,
 }
 
-impl SoundComputer {
-    pub fn new(instructions: &[Instruction], duet_mode: bool) -> SoundComputer {
-        SoundComputer {
-            instructions: instructions.to_vec(),
-            registers: ('a'..='z').map(|c| (c, 0)).collect::<HashMap<char, i64>>(),
-            duet_mode,
-            pc: 0,
+impl SoundState {
+    /// Creates a new [`SoundState`] with empty sound buffers, for the given profile.
+    fn new(profile: IsaProfile) -> SoundState {
+        SoundState {
+            profile,
+            mod_semantics: ModSemantics::default(),
             sounds_sent: VecDeque::new(),
             sounds_received: VecDeque::new(),
             awaiting_input: false,
-            halted: false,
             total_sounds_sent: 0,
+            total_sounds_received: 0,
+            max_received_queue_len: 0,
             last_sound_sent: None,
             mul_executions_count: 0,
         }
     }
+}
 
-    /// Executes instructions held by the [`SoundComputer`] until execution is halted or input is
-    /// required.
-    pub fn execute(&mut self) {
-        if self.halted || self.awaiting_input {
-            return;
+impl Op for Instruction {
+    type Registers = RegisterBank;
+    type Context = SoundState;
+    type Error = ExecutionError;
+
+    /// Executes the instruction under the profile held in the context. The "rcv" instruction yields
+    /// when it recovers a frequency (solo mode) or is waiting for input (duet mode).
+    fn execute(
+        &self,
+        state: &mut VmState<RegisterBank, SoundState>,
+    ) -> Result<Flow, ExecutionError> {
+        match &mut state.registers {
+            RegisterBank::Checked(registers) => {
+                self.execute_on(registers, &mut state.context, state.pc)
+            }
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(registers) => {
+                self.execute_on(registers, &mut state.context, state.pc)
+            }
         }
-        // Execute instructions while the program counter remains within the instruction space
-        loop {
-            if self.pc >= self.instructions.len() {
-                break;
-            }
-            // Execute current instruction
-            match self.instructions[self.pc] {
-                Instruction::Snd { arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    self.sounds_sent.push_back(value);
-                    self.total_sounds_sent += 1;
-                    self.last_sound_sent = Some(value);
-                }
-                Instruction::Set { reg, arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    self.update_register(&reg, value).unwrap();
+    }
+}
+
+impl Instruction {
+    /// Executes the instruction against the register file, with arithmetic checked at the width
+    /// of the registers.
+    fn execute_on<W: RegisterWord>(
+        &self,
+        registers: &mut [W; REGISTER_COUNT],
+        sound: &mut SoundState,
+        pc: usize,
+    ) -> Result<Flow, ExecutionError> {
+        let overflow = ExecutionError::Overflow {
+            index: pc,
+            opcode: self.opcode(),
+        };
+        match *self {
+            Instruction::Snd { arg } => {
+                let value = decode_argument(registers, arg);
+                let value = value.to_i64().ok_or(overflow)?;
+                if sound.profile == IsaProfile::Duet {
+                    sound.sounds_sent.push_back(value);
                 }
-                Instruction::Add { reg, arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    *self.registers.get_mut(&reg).unwrap() += value;
+                sound.total_sounds_sent += 1;
+                sound.last_sound_sent = Some(value);
+            }
+            Instruction::Set { reg, arg } => {
+                let value = decode_argument(registers, arg);
+                registers[slot(reg)] = value;
+            }
+            Instruction::Add { reg, arg } => {
+                let value = decode_argument(registers, arg);
+                let held = &mut registers[slot(reg)];
+                *held = held.checked_add(value).ok_or(overflow)?;
+            }
+            Instruction::Mul { reg, arg } => {
+                let value = decode_argument(registers, arg);
+                let held = &mut registers[slot(reg)];
+                *held = held.checked_mul(value).ok_or(overflow)?;
+                sound.mul_executions_count += 1;
+            }
+            Instruction::Mod { reg, arg } => {
+                let value = decode_argument(registers, arg);
+                if value == W::ZERO {
+                    return Err(ExecutionError::DivideByZero { index: pc });
                 }
-                Instruction::Mul { reg, arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    *self.registers.get_mut(&reg).unwrap() *= value;
-                    self.mul_executions_count += 1;
+                let held = &mut registers[slot(reg)];
+                *held = sound.mod_semantics.apply(*held, value).ok_or(overflow)?;
+            }
+            Instruction::Rcv { reg } => {
+                if sound.profile == IsaProfile::Sound {
+                    // Recover the last sound played if the register value is non-zero
+                    if registers[slot(reg)] != W::ZERO {
+                        return Ok(Flow::Yield);
+                    }
+                } else {
+                    let Some(sound_received) = sound.sounds_received.pop_front() else {
+                        sound.awaiting_input = true;
+                        return Ok(Flow::Yield);
+                    };
+                    registers[slot(reg)] = W::from(sound_received);
                 }
-                Instruction::Mod { reg, arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    *self.registers.get_mut(&reg).unwrap() %= value;
+            }
+            Instruction::Jgz { arg1, arg2 } => {
+                let check_value = decode_argument(registers, arg1);
+                let jmp = decode_argument(registers, arg2);
+                if check_value > W::ZERO {
+                    return Ok(Flow::Jump(jump_offset(jmp)));
                 }
-                Instruction::Rcv { reg } => {
-                    let value = self.read_register(&reg).unwrap();
-                    if !self.duet_mode && value != 0 {
-                        return;
-                    } else {
-                        if self.sounds_received.is_empty() {
-                            self.awaiting_input = true;
-                            return;
-                        }
-                        let sound_received = self.sounds_received.pop_front().unwrap();
-                        self.update_register(&reg, sound_received).unwrap();
-                    }
+            }
+            Instruction::Sub { reg, arg } => {
+                let value = decode_argument(registers, arg);
+                let held = &mut registers[slot(reg)];
+                *held = held.checked_sub(value).ok_or(overflow)?;
+            }
+            Instruction::Jnz { arg1, arg2 } => {
+                let check_value = decode_argument(registers, arg1);
+                let jmp = decode_argument(registers, arg2);
+                if check_value != W::ZERO {
+                    return Ok(Flow::Jump(jump_offset(jmp)));
                 }
-                Instruction::Jgz { arg1, arg2 } => {
-                    let check_value = self.decode_instruction_argument(arg1).unwrap();
-                    let jmp = self.decode_instruction_argument(arg2).unwrap();
-                    if check_value > 0 {
-                        if self.conduct_pc_jump(jmp) {
-                            return;
-                        }
-                        continue;
-                    }
+            }
+        }
+        Ok(Flow::Next)
+    }
+}
+
+/// Decodes an [`InstructionArgument`] variant by returning its integer value or the value held in
+/// the designated register.
+fn decode_argument<W: RegisterWord>(
+    registers: &[W; REGISTER_COUNT],
+    arg: InstructionArgument,
+) -> W {
+    match arg {
+        InstructionArgument::Value { val } => W::from(val),
+        InstructionArgument::Register { reg } => registers[slot(reg)],
+    }
+}
+
+/// Gets the index of the register in the register file. Registers are checked when the program is
+/// loaded (see [`Instruction::invalid_register`]), so the register must exist.
+fn slot(reg: char) -> usize {
+    register_index(reg).expect("register checked when program loaded")
+}
+
+/// Converts a jump offset read from a register to the offset used by the [`RegisterVm`]. Offsets
+/// too large for 64 bits (under the wide register mode) are clamped, since they move outside of the
+/// program either way.
+fn jump_offset<W: RegisterWord>(jmp: W) -> i64 {
+    jmp.to_i64()
+        .unwrap_or(if jmp > W::ZERO { i64::MAX } else { i64::MIN })
+}
+
+/// Represents a sound computer that can execute instructions (see [`Instruction`]) under one of
+/// the instruction set profiles given by [`IsaProfile`]. Execution is carried out by a
+/// [`RegisterVm`], with the sound buffers and statistics held in its [`SoundState`] context.
+///
+/// The sound computer has 26 registers (labelled 'a' to 'z') that are initialised to 0.
+pub struct SoundComputer {
+    vm: RegisterVm<Instruction>,
+    rewrites: Vec<Option<Rewrite>>,
+    compiled: Option<CompiledProgram>,
+}
+
+impl SoundComputer {
+    /// Creates a new [`SoundComputer`] loaded with the given program, with 64-bit registers.
+    ///
+    /// Returns a [`ProgramLoadError`] if the program contains an instruction that is not legal
+    /// under the given [`IsaProfile`] or refers to a register other than 'a' to 'z'.
+    pub fn new(
+        instructions: &[Instruction],
+        profile: IsaProfile,
+    ) -> Result<SoundComputer, ProgramLoadError> {
+        SoundComputer::with_register_mode(instructions, profile, RegisterMode::default())
+    }
+
+    /// Creates a new [`SoundComputer`] loaded with the program given as line-separated
+    /// instructions, with 64-bit registers.
+    ///
+    /// Returns a [`ProgramLoadError`] if a line of the program is not a valid instruction, or the
+    /// program contains an instruction that is not legal under the given [`IsaProfile`].
+    pub fn from_program_str(
+        program: &str,
+        profile: IsaProfile,
+    ) -> Result<SoundComputer, ProgramLoadError> {
+        let instructions = Instruction::parse_program(program)?;
+        SoundComputer::new(&instructions, profile)
+    }
+
+    /// Creates a new [`SoundComputer`] loaded with the given program, with registers holding the
+    /// range of values given by the [`RegisterMode`].
+    ///
+    /// Returns a [`ProgramLoadError`] if the program contains an instruction that is not legal
+    /// under the given [`IsaProfile`] or refers to a register other than 'a' to 'z'.
+    pub fn with_register_mode(
+        instructions: &[Instruction],
+        profile: IsaProfile,
+        register_mode: RegisterMode,
+    ) -> Result<SoundComputer, ProgramLoadError> {
+        for (index, instruction) in instructions.iter().enumerate() {
+            if !profile.permits(instruction) {
+                return Err(ProgramLoadError::IllegalInstruction(
+                    IllegalInstructionError {
+                        index,
+                        opcode: instruction.opcode(),
+                        profile,
+                    },
+                ));
+            }
+            if let Some(reg) = instruction.invalid_register() {
+                return Err(ProgramLoadError::InvalidRegister { index, reg });
+            }
+        }
+        let registers = RegisterBank::new(register_mode);
+        let context = SoundState::new(profile);
+        Ok(SoundComputer {
+            vm: RegisterVm::new(instructions, registers, context),
+            rewrites: vec![],
+            compiled: None,
+        })
+    }
+
+    /// Sets the semantics used by the "mod" instruction for negative operands. The default is
+    /// [`ModSemantics::Euclidean`].
+    pub fn set_mod_semantics(&mut self, mod_semantics: ModSemantics) {
+        self.sound_mut().mod_semantics = mod_semantics;
+    }
+
+    /// Gets the semantics used by the "mod" instruction for negative operands.
+    pub fn mod_semantics(&self) -> ModSemantics {
+        self.sound().mod_semantics
+    }
+
+    /// Searches the loaded program for blocks of instructions that can be replaced by native
+    /// operations (see [`Rewrite`]), which are then used in place of the original instructions
+    /// during execution. Each applied rewrite counts as a single step.
+    ///
+    /// Returned value is the number of rewrites found.
+    pub fn optimise(&mut self) -> usize {
+        self.rewrites = find_rewrites(self.vm.program());
+        // Recompile so that the rewrites are applied by compiled execution
+        if self.compiled.is_some() {
+            self.compile();
+        }
+        self.rewrites.iter().flatten().count()
+    }
+
+    /// Compiles the loaded program (see [`CompiledProgram`]), which is then used by
+    /// [`SoundComputer::execute`] in place of the interpreter for every instruction that can be
+    /// compiled. Execution otherwise behaves exactly as it does under the interpreter.
+    ///
+    /// Returned value is the number of instructions compiled.
+    pub fn compile(&mut self) -> usize {
+        let compiled = CompiledProgram::compile(self.vm.program(), &self.rewrites);
+        let count = compiled.compiled_count();
+        self.compiled = Some(compiled);
+        count
+    }
+
+    /// Checks if the loaded program has been compiled.
+    pub fn is_compiled(&self) -> bool {
+        self.compiled.is_some()
+    }
+
+    /// Executes instructions held by the [`SoundComputer`] until execution is halted or input is
+    /// required. If the program has been compiled, compiled code is run wherever possible.
+    ///
+    /// Returns an [`ExecutionError`] if an instruction fails.
+    pub fn execute(&mut self) -> Result<(), ExecutionError> {
+        loop {
+            self.run_compiled();
+            if self.step()? != StepOutcome::Executed {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Executes instructions held by the [`SoundComputer`] as a single machine playing sounds, until
+    /// a "rcv" instruction recovers the frequency of the last sound played or execution otherwise
+    /// stops.
+    ///
+    /// Frequencies are only recovered under the [`IsaProfile::Sound`] profile. Returns an
+    /// [`ExecutionError`] if an instruction fails.
+    pub fn run_solo(&mut self) -> Result<SoloOutcome, ExecutionError> {
+        let mut steps: u64 = 0;
+        loop {
+            match self.step()? {
+                StepOutcome::Executed => steps += 1,
+                StepOutcome::Recovered => {
+                    return Ok(SoloOutcome {
+                        recovered_frequency: self.sound().last_sound_sent,
+                        steps,
+                    })
                 }
-                Instruction::Sub { reg, arg } => {
-                    let value = self.decode_instruction_argument(arg).unwrap();
-                    *self.registers.get_mut(&reg).unwrap() -= value;
+                StepOutcome::AwaitingInput | StepOutcome::Halted => {
+                    return Ok(SoloOutcome {
+                        recovered_frequency: None,
+                        steps,
+                    })
                 }
-                Instruction::Jnz { arg1, arg2 } => {
-                    let check_value = self.decode_instruction_argument(arg1).unwrap();
-                    let jmp = self.decode_instruction_argument(arg2).unwrap();
-                    if check_value != 0 {
-                        if self.conduct_pc_jump(jmp) {
-                            return;
-                        }
-                        continue;
-                    }
+            }
+        }
+    }
+
+    /// Executes instructions held by the [`SoundComputer`] until execution is halted, input is
+    /// required, the maximum number of steps have been executed or the (optional) timeout has
+    /// elapsed.
+    ///
+    /// Returned value is an [`ExecutionReport`] giving the number of steps executed and the reason
+    /// that execution stopped, or an [`ExecutionError`] if an instruction fails.
+    pub fn execute_with_limit(
+        &mut self,
+        max_steps: u64,
+        timeout: Option<Duration>,
+    ) -> Result<ExecutionReport, ExecutionError> {
+        let start = Instant::now();
+        let mut steps: u64 = 0;
+        loop {
+            if steps >= max_steps {
+                return Ok(ExecutionReport::new(steps, StopReason::StepLimit));
+            }
+            // Only check the clock periodically to keep overhead low
+            if steps.is_multiple_of(TIMEOUT_CHECK_INTERVAL)
+                && timeout.is_some_and(|timeout| start.elapsed() >= timeout)
+            {
+                return Ok(ExecutionReport::new(steps, StopReason::Timeout));
+            }
+            let stop_reason = match self.step()? {
+                StepOutcome::Executed => {
+                    steps += 1;
+                    continue;
                 }
+                StepOutcome::AwaitingInput => StopReason::AwaitingInput,
+                StepOutcome::Recovered => StopReason::Recovered,
+                StepOutcome::Halted => StopReason::Halted,
+            };
+            return Ok(ExecutionReport::new(steps, stop_reason));
+        }
+    }
+
+    /// Returns an iterator that executes the program one step at a time, yielding an
+    /// [`ExecutedStep`] for each instruction executed, for at most `max_steps` steps. Execution
+    /// stops in the same way as for [`SoundComputer::execute`], and can be resumed afterwards.
+    pub fn iter_steps(&mut self, max_steps: u64) -> StepIter<'_> {
+        StepIter {
+            comp: self,
+            steps_remaining: max_steps,
+            failed: false,
+        }
+    }
+
+    /// Executes instructions held by the [`SoundComputer`] in the same way as
+    /// [`SoundComputer::execute_with_limit`] (without a timeout), recording the number of times each
+    /// instruction is executed and each optimiser rewrite is applied.
+    ///
+    /// Compiled code is not used, so that every step is recorded. Returned value is the
+    /// [`Coverage`] of the run, or an [`ExecutionError`] if an instruction fails.
+    pub fn run_with_coverage(&mut self, max_steps: u64) -> Result<Coverage, ExecutionError> {
+        let mut coverage = Coverage::new(self.vm.program(), &self.rewrites);
+        let mut steps: u64 = 0;
+        let stop_reason = loop {
+            if steps >= max_steps {
+                break StopReason::StepLimit;
+            }
+            let pc = self.vm.state().pc;
+            let block_len = self
+                .rewrites
+                .get(pc)
+                .copied()
+                .flatten()
+                .map(|r| r.block_len());
+            match self.step()? {
+                StepOutcome::Executed => steps += 1,
+                StepOutcome::AwaitingInput => break StopReason::AwaitingInput,
+                StepOutcome::Recovered => break StopReason::Recovered,
+                StepOutcome::Halted => break StopReason::Halted,
+            }
+            // Rewritten blocks start with a "set", so only an applied rewrite skips the block
+            match block_len {
+                Some(len) if self.vm.state().pc == pc + len => coverage.record_rewrite(pc),
+                _ => coverage.record(pc),
+            }
+        };
+        coverage.finish(ExecutionReport::new(steps, stop_reason));
+        Ok(coverage)
+    }
+
+    /// Executes the single instruction at the current location of the program counter.
+    ///
+    /// Returned value indicates whether an instruction was executed, or the reason that no
+    /// instruction could be executed. If the instruction fails, an [`ExecutionError`] is returned.
+    pub fn step(&mut self) -> Result<StepOutcome, ExecutionError> {
+        if self.vm.is_halted() {
+            return Ok(StepOutcome::Halted);
+        }
+        if self.sound().awaiting_input {
+            return Ok(StepOutcome::AwaitingInput);
+        }
+        // Apply native operation if the current instruction starts an optimised block
+        if let Some(&Some(rewrite)) = self.rewrites.get(self.vm.state().pc) {
+            if self.apply_rewrite(rewrite) {
+                return Ok(StepOutcome::Executed);
             }
-            // Go to the next instruction
-            self.pc += 1;
         }
-        // Halt execution now that PC is outside of instruction space
-        self.halted = true;
+        // Execute current instruction
+        Ok(match self.vm.step()? {
+            VmStep::Executed => StepOutcome::Executed,
+            VmStep::Halted => StepOutcome::Halted,
+            VmStep::Yielded if self.sound().awaiting_input => StepOutcome::AwaitingInput,
+            VmStep::Yielded => StepOutcome::Recovered,
+        })
     }
 
     /// Returns the value held in the specified register.
     ///
-    /// If the register does not exist, a [`RegisterReadError`] is returned.
+    /// If the register does not exist or its value does not fit in 64 bits, a
+    /// [`RegisterReadError`] is returned.
     pub fn read_register(&self, register: &char) -> Result<i64, RegisterReadError> {
-        if !self.registers.contains_key(register) {
-            return Err(RegisterReadError);
-        }
-        Ok(*self.registers.get(register).unwrap())
+        register_index(*register)
+            .and_then(|index| self.vm.state().registers.get(index))
+            .ok_or(RegisterReadError)
+    }
+
+    /// Returns the full value held in the specified register, which may exceed 64 bits under the
+    /// wide register mode.
+    ///
+    /// If the register does not exist, a [`RegisterReadError`] is returned.
+    pub fn read_register_wide(&self, register: &char) -> Result<i128, RegisterReadError> {
+        register_index(*register)
+            .and_then(|index| self.vm.state().registers.get_wide(index))
+            .ok_or(RegisterReadError)
     }
 
     /// Updates the value held in the specified register.
@@ -271,53 +1132,110 @@ impl SoundComputer {
         register: &char,
         value: i64,
     ) -> Result<(), RegisterWriteError> {
-        if !self.registers.contains_key(register) {
-            return Err(RegisterWriteError);
-        }
-        self.registers.insert(*register, value);
+        let index = register_index(*register).ok_or(RegisterWriteError)?;
+        self.vm.state_mut().registers.set(index, value);
         Ok(())
     }
 
     /// Takes the sounds that have been added to the sent buffer.
     pub fn take_sent_sounds(&mut self) -> Vec<i64> {
-        let taken_sounds = self.sounds_sent.iter().copied().collect::<Vec<i64>>();
-        self.sounds_sent = VecDeque::new();
-        taken_sounds
+        self.sound_mut().sounds_sent.drain(..).collect::<Vec<i64>>()
     }
 
     /// Adds the sounds to the receive buffer.
     pub fn receive_sounds(&mut self, sounds: &[i64]) {
-        for sound in sounds {
-            self.sounds_received.push_back(*sound);
-        }
-        if !self.sounds_received.is_empty() {
-            self.awaiting_input = false;
+        let sound = self.sound_mut();
+        sound.sounds_received.extend(sounds);
+        sound.total_sounds_received += sounds.len() as u64;
+        sound.max_received_queue_len = sound
+            .max_received_queue_len
+            .max(sound.sounds_received.len());
+        if !sound.sounds_received.is_empty() {
+            sound.awaiting_input = false;
         }
     }
 
     /// Gets the last sound sent by the [`SoundComputer`].
     pub fn get_last_sent_sound(&self) -> Option<i64> {
-        self.last_sound_sent
+        self.sound().last_sound_sent
     }
 
     /// Checks if the [`SoundComputer`] is awaiting input.
     pub fn is_awaiting_input(&self) -> bool {
-        self.awaiting_input
+        self.sound().awaiting_input
     }
 
     /// Checks if the [`SoundComputer`] has halted execution.
     pub fn is_halted(&self) -> bool {
-        self.halted
+        self.vm.is_halted()
     }
 
     /// Gets the total number of sounds sent by the [`SoundComputer`].
     pub fn get_total_sounds_sent(&self) -> u64 {
-        self.total_sounds_sent
+        self.sound().total_sounds_sent
+    }
+
+    /// Gets the total number of sounds received by the [`SoundComputer`], including those still
+    /// waiting in the receive buffer.
+    pub fn get_total_sounds_received(&self) -> u64 {
+        self.sound().total_sounds_received
+    }
+
+    /// Gets the number of sounds in the send buffer that have not yet been taken.
+    pub fn get_sent_queue_len(&self) -> usize {
+        self.sound().sounds_sent.len()
+    }
+
+    /// Gets the number of sounds in the receive buffer that have not yet been consumed by a "rcv"
+    /// instruction.
+    pub fn get_received_queue_len(&self) -> usize {
+        self.sound().sounds_received.len()
+    }
+
+    /// Gets the maximum number of sounds held in the receive buffer at any one time.
+    pub fn get_max_received_queue_len(&self) -> usize {
+        self.sound().max_received_queue_len
     }
 
     /// Gets the number of times the [`SoundComputer`] has executed the MUL (multiply) instruction.
     pub fn get_mul_executions_count(&self) -> usize {
-        self.mul_executions_count
+        self.sound().mul_executions_count
+    }
+
+    /// Captures the current execution state of the [`SoundComputer`].
+    pub fn snapshot(&self) -> MachineState {
+        let state = self.vm.state();
+        let sound = &state.context;
+        MachineState {
+            registers: state.registers.clone(),
+            pc: state.pc,
+            sounds_sent: sound.sounds_sent.clone(),
+            sounds_received: sound.sounds_received.clone(),
+            awaiting_input: sound.awaiting_input,
+            halted: self.vm.is_halted(),
+            total_sounds_sent: sound.total_sounds_sent,
+            total_sounds_received: sound.total_sounds_received,
+            max_received_queue_len: sound.max_received_queue_len,
+            last_sound_sent: sound.last_sound_sent,
+            mul_executions_count: sound.mul_executions_count,
+        }
+    }
+
+    /// Restores the [`SoundComputer`] to the execution state held in the snapshot.
+    pub fn restore(&mut self, state: &MachineState) {
+        self.vm.set_halted(state.halted);
+        let vm_state = self.vm.state_mut();
+        vm_state.registers = state.registers.clone();
+        vm_state.pc = state.pc;
+        let sound = &mut vm_state.context;
+        sound.sounds_sent = state.sounds_sent.clone();
+        sound.sounds_received = state.sounds_received.clone();
+        sound.awaiting_input = state.awaiting_input;
+        sound.total_sounds_sent = state.total_sounds_sent;
+        sound.total_sounds_received = state.total_sounds_received;
+        sound.max_received_queue_len = state.max_received_queue_len;
+        sound.last_sound_sent = state.last_sound_sent;
+        sound.mul_executions_count = state.mul_executions_count;
     }
 
     /// Extracts the the value from the last argument in the instruction at the given index.
@@ -325,10 +1243,7 @@ impl SoundComputer {
     /// Returns None if the [`SoundComputer`] has an empty instruction set, the index is outside of
     /// the instruction space or the instruction at the location does not have any argument fields.
     pub fn extract_last_arg_value(&self, i: usize) -> Option<i64> {
-        if self.instructions.is_empty() || i >= self.instructions.len() {
-            return None;
-        }
-        let arg = match self.instructions[i] {
+        let arg = match *self.vm.program().get(i)? {
             Instruction::Snd { arg } => arg,
             Instruction::Set { reg: _, arg } => arg,
             Instruction::Add { reg: _, arg } => arg,
@@ -339,42 +1254,653 @@ impl SoundComputer {
             Instruction::Sub { reg: _, arg } => arg,
             Instruction::Jnz { arg1: _, arg2 } => arg2,
         };
-        self.decode_instruction_argument(arg).ok()
+        match arg {
+            InstructionArgument::Value { val } => Some(val),
+            InstructionArgument::Register { reg } => self.read_register(&reg).ok(),
+        }
     }
 
-    /// Decodes an [`InstructionArgument`] variant by returning its integer value or the value held
-    /// in the designated register.
-    ///
-    /// If the register does not exist, a [`RegisterReadError`] is returned.
-    fn decode_instruction_argument(
-        &self,
-        arg: InstructionArgument,
-    ) -> Result<i64, RegisterReadError> {
-        match arg {
-            InstructionArgument::Value { val: value } => Ok(value),
-            InstructionArgument::Register { reg: register } => self.read_register(&register),
+    /// Gets the sound buffers and statistics held in the context of the [`RegisterVm`].
+    fn sound(&self) -> &SoundState {
+        &self.vm.state().context
+    }
+
+    /// Gets mutable access to the sound buffers and statistics held in the context of the
+    /// [`RegisterVm`].
+    fn sound_mut(&mut self) -> &mut SoundState {
+        &mut self.vm.state_mut().context
+    }
+
+    /// Runs compiled code from the current location of the program counter, until the next
+    /// instruction must be executed by the interpreter. Does nothing if the program has not been
+    /// compiled or the machine cannot execute instructions.
+    fn run_compiled(&mut self) {
+        let Some(compiled) = &self.compiled else {
+            return;
+        };
+        if self.vm.is_halted() || self.vm.state().context.awaiting_input {
+            return;
         }
+        let state = self.vm.state_mut();
+        let sound = &mut state.context;
+        state.pc = match &mut state.registers {
+            RegisterBank::Checked(registers) => compiled.run(
+                registers,
+                state.pc,
+                sound.mod_semantics,
+                &mut sound.mul_executions_count,
+            ),
+            #[cfg(feature = "wide-registers")]
+            RegisterBank::Wide(registers) => compiled.run(
+                registers,
+                state.pc,
+                sound.mod_semantics,
+                &mut sound.mul_executions_count,
+            ),
+        };
     }
 
-    /// Helper function to implement a jump to the [`SoundComputer`] program counter.
+    /// Applies the native operation in place of the block of instructions it replaces.
     ///
-    /// Returns true if the jump moves the PC outside of the instruction space and thereby halts the
-    /// machine, otherwise returns false.
-    fn conduct_pc_jump(&mut self, jmp: i64) -> bool {
-        match jmp.is_negative() {
-            true => {
-                // Check if the jump would move the pc left of instruction space
-                let jump_value = usize::try_from(jmp.unsigned_abs()).unwrap();
-                if jump_value > self.pc {
-                    self.halted = true;
-                    return true;
+    /// Returns false if the preconditions for the rewrite are not met, in which case the original
+    /// instructions should be executed instead.
+    fn apply_rewrite(&mut self, rewrite: Rewrite) -> bool {
+        match rewrite {
+            Rewrite::CompositeCheck {
+                value,
+                flag,
+                divisor,
+                cofactor,
+                scratch,
+                len,
+            } => {
+                let Ok(value_held) = self.read_register(&value) else {
+                    return false;
+                };
+                // Loop does not terminate for values less than 3, so leave it to run as normal
+                if value_held < 3 {
+                    return false;
+                }
+                // Leave the loop to run as normal if the number of multiplications cannot be counted
+                let Some(mul_count) = value_held
+                    .checked_sub(2)
+                    .and_then(|n| n.checked_mul(n))
+                    .and_then(|n| usize::try_from(n).ok())
+                    .and_then(|n| n.checked_add(self.sound().mul_executions_count))
+                else {
+                    return false;
+                };
+                if !is_prime(value_held as u64) {
+                    self.update_register(&flag, 0).unwrap();
+                }
+                self.update_register(&divisor, value_held).unwrap();
+                self.update_register(&cofactor, value_held).unwrap();
+                self.update_register(&scratch, 0).unwrap();
+                self.sound_mut().mul_executions_count = mul_count;
+                self.vm.state_mut().pc += len;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::utils::testgen::SeededRng;
+
+    /// Runs the program under the given profile until it halts or stops, returning the machine.
+    fn run_program(raw_input: &str, profile: IsaProfile) -> SoundComputer {
+        let mut comp = SoundComputer::from_program_str(raw_input, profile).unwrap();
+        comp.execute().unwrap();
+        comp
+    }
+
+    /// Tests that instructions are only parsed from the exact forms given in the problem
+    /// statements, with or without the "regex" feature.
+    #[test]
+    fn test_instruction_parse() {
+        assert_eq!(
+            Instruction::Jgz {
+                arg1: InstructionArgument::Register { reg: 'a' },
+                arg2: InstructionArgument::Value { val: -2 },
+            },
+            Instruction::from_str("jgz a -2").unwrap()
+        );
+        assert_eq!(
+            Instruction::Rcv { reg: 'z' },
+            Instruction::from_str("rcv z").unwrap()
+        );
+        for invalid in [
+            "",
+            "snd",
+            "rcv 1",
+            "set 1 a",
+            "set ab 1",
+            "add a +1",
+            "mul a 1 2",
+            "jgz  a 1",
+            "nop a 1",
+            "SET a 1",
+        ] {
+            assert!(Instruction::from_str(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    /// Property test checking that formatting and then parsing randomly generated instructions
+    /// (covering every opcode, register and the full range of values) gives back the same
+    /// instructions.
+    #[test]
+    fn test_instruction_display_round_trip() {
+        let mut rng = SeededRng::new(18);
+        let arg = |rng: &mut SeededRng| match rng.index(2) {
+            0 => InstructionArgument::Value {
+                val: rng.range(i64::MIN..=i64::MAX),
+            },
+            _ => InstructionArgument::Register {
+                reg: (b'a' + rng.index(26) as u8) as char,
+            },
+        };
+        let program = (0..1000)
+            .map(|_| {
+                let reg = (b'a' + rng.index(26) as u8) as char;
+                let (arg1, arg2) = (arg(&mut rng), arg(&mut rng));
+                match rng.index(9) {
+                    0 => Instruction::Snd { arg: arg1 },
+                    1 => Instruction::Set { reg, arg: arg1 },
+                    2 => Instruction::Add { reg, arg: arg1 },
+                    3 => Instruction::Mul { reg, arg: arg1 },
+                    4 => Instruction::Mod { reg, arg: arg1 },
+                    5 => Instruction::Rcv { reg },
+                    6 => Instruction::Jgz { arg1, arg2 },
+                    7 => Instruction::Sub { reg, arg: arg1 },
+                    _ => Instruction::Jnz { arg1, arg2 },
                 }
-                self.pc -= jump_value;
+            })
+            .collect::<Vec<Instruction>>();
+        for instruction in program.iter() {
+            assert_eq!(
+                Ok(*instruction),
+                instruction.to_string().parse::<Instruction>()
+            );
+        }
+        assert_eq!(
+            Ok(program.clone()),
+            Instruction::parse_program(&Instruction::format_program(&program))
+        );
+        let jump = Instruction::Jgz {
+            arg1: InstructionArgument::Register { reg: 'a' },
+            arg2: InstructionArgument::Value { val: -2 },
+        };
+        assert_eq!("jgz a -2", jump.to_string());
+    }
+
+    /// Tests loading programs from text, with parse errors and illegal instructions reported.
+    #[test]
+    fn test_from_program_str() {
+        let mut comp =
+            SoundComputer::from_program_str("\n  set a 3\n\nmul a a  \n", IsaProfile::Coprocessor)
+                .unwrap();
+        comp.execute().unwrap();
+        assert_eq!(Ok(9), comp.read_register(&'a'));
+        let error = SoundComputer::from_program_str("set a 1\nadd a\nsnd a", IsaProfile::Sound)
+            .err()
+            .unwrap();
+        assert_eq!(
+            ProgramLoadError::Parse(AocError::LineParse {
+                line: 2,
+                text: String::from("add a")
+            }),
+            error
+        );
+        assert_eq!(
+            "Program load failed: Parsing of input failed at line 2 [add a]",
+            error.to_string()
+        );
+        let error = SoundComputer::from_program_str("set a 1\nsnd a", IsaProfile::Coprocessor)
+            .err()
+            .unwrap();
+        assert_eq!(
+            ProgramLoadError::IllegalInstruction(IllegalInstructionError {
+                index: 1,
+                opcode: "snd",
+                profile: IsaProfile::Coprocessor
+            }),
+            error
+        );
+    }
+
+    /// Tests the arithmetic opcodes with literal, register and negative arguments.
+    #[test]
+    fn test_opcodes_arithmetic() {
+        let comp = run_program("set a 5\nset b a\nset c -2", IsaProfile::Sound);
+        assert_eq!(Ok(5), comp.read_register(&'b'));
+        assert_eq!(Ok(-2), comp.read_register(&'c'));
+        assert_eq!(Err(RegisterReadError), comp.read_register(&'A'));
+        let comp = run_program("add a -3\nadd a a\nadd b 4", IsaProfile::Sound);
+        assert_eq!(Ok(-6), comp.read_register(&'a'));
+        assert_eq!(Ok(4), comp.read_register(&'b'));
+        let comp = run_program("set a 3\nmul a -4\nmul a a\nmul b 9", IsaProfile::Sound);
+        assert_eq!(Ok(144), comp.read_register(&'a'));
+        assert_eq!(Ok(0), comp.read_register(&'b'));
+        assert_eq!(3, comp.get_mul_executions_count());
+        let comp = run_program(
+            "set a 1\nsub a 5\nsub b -5\nsub b b",
+            IsaProfile::Coprocessor,
+        );
+        assert_eq!(Ok(-4), comp.read_register(&'a'));
+        assert_eq!(Ok(0), comp.read_register(&'b'));
+    }
+
+    /// Tests the "mod" opcode under both semantics, including negative values in the register and
+    /// argument.
+    #[test]
+    fn test_opcodes_mod() {
+        let program = Instruction::parse_program(
+            "set a 17\nmod a 5\nset b -7\nmod b 3\nset c 7\nmod c -3\nset d -7\nmod d -3",
+        )
+        .unwrap();
+        for (mod_semantics, expected) in [
+            (ModSemantics::Euclidean, [2, 2, 1, 2]),
+            (ModSemantics::Truncated, [2, -1, 1, -1]),
+        ] {
+            let mut comp = SoundComputer::new(&program, IsaProfile::Sound).unwrap();
+            comp.set_mod_semantics(mod_semantics);
+            comp.execute().unwrap();
+            let registers = ['a', 'b', 'c', 'd'].map(|reg| comp.read_register(&reg).unwrap());
+            assert_eq!(expected, registers);
+        }
+        let comp = run_program("set a -7\nmod a 3", IsaProfile::Sound);
+        assert_eq!(ModSemantics::Euclidean, comp.mod_semantics());
+        assert_eq!(Ok(2), comp.read_register(&'a'));
+    }
+
+    /// Tests that arithmetic overflowing 64 bits and "mod" by zero raise errors, leaving the program
+    /// counter on the failing instruction.
+    #[test]
+    fn test_execution_errors() {
+        let mut comp = SoundComputer::from_program_str(
+            "set a 4294967296\nmul a a\nadd a 1",
+            IsaProfile::Sound,
+        )
+        .unwrap();
+        let error = ExecutionError::Overflow {
+            index: 1,
+            opcode: "mul",
+        };
+        assert_eq!(Err(error), comp.execute());
+        assert_eq!(Ok(1 << 32), comp.read_register(&'a'));
+        assert_eq!(1, comp.snapshot().pc);
+        let mut comp =
+            SoundComputer::from_program_str("set a 5\nadd b -1\nmod a 0", IsaProfile::Sound)
+                .unwrap();
+        comp.update_register(&'b', i64::MIN).unwrap();
+        let error = comp.execute_with_limit(10, None).unwrap_err();
+        assert_eq!("Instruction 1 (\"add\") overflowed", error.to_string());
+        comp.update_register(&'b', 0).unwrap();
+        let error = ExecutionError::DivideByZero { index: 2 };
+        assert_eq!(Err(error), comp.execute());
+    }
+
+    /// Tests that registers other than 'a' to 'z' are rejected when parsed, and when the program is
+    /// loaded if given directly in an instruction.
+    #[test]
+    fn test_invalid_registers() {
+        for program in ["set A 1", "add a $", "jgz Z 1", "rcv ab"] {
+            assert!(Instruction::parse_program(program).is_err(), "{program}");
+        }
+        assert!("A".parse::<InstructionArgument>().is_err());
+        assert_eq!(
+            Ok(InstructionArgument::Register { reg: 'q' }),
+            "q".parse::<InstructionArgument>()
+        );
+        let program = [
+            Instruction::Set {
+                reg: 'a',
+                arg: InstructionArgument::Value { val: 1 },
+            },
+            Instruction::Add {
+                reg: 'a',
+                arg: InstructionArgument::Register { reg: '$' },
+            },
+        ];
+        assert_eq!(None, program[0].invalid_register());
+        assert_eq!(Some('$'), program[1].invalid_register());
+        let error = ProgramLoadError::InvalidRegister { index: 1, reg: '$' };
+        assert_eq!(
+            Some(error),
+            SoundComputer::new(&program, IsaProfile::Sound).err()
+        );
+        assert_eq!(
+            "Program load failed: Instruction 1 refers to invalid register \"$\"",
+            SoundComputer::new(&program, IsaProfile::Duet)
+                .err()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    /// Tests that registers hold values beyond 64 bits under the wide register mode, while sounds
+    /// remain limited to 64 bits.
+    #[cfg(feature = "wide-registers")]
+    #[test]
+    fn test_wide_register_mode() {
+        let program = Instruction::parse_program("set a 4294967296\nmul a a\nsnd a").unwrap();
+        let mut comp =
+            SoundComputer::with_register_mode(&program, IsaProfile::Sound, RegisterMode::Wide)
+                .unwrap();
+        let error = ExecutionError::Overflow {
+            index: 2,
+            opcode: "snd",
+        };
+        assert_eq!(Err(error), comp.execute());
+        assert_eq!(RegisterMode::Wide, comp.snapshot().registers.mode());
+        assert_eq!(Ok(1 << 64), comp.read_register_wide(&'a'));
+        assert_eq!(Err(RegisterReadError), comp.read_register(&'a'));
+    }
+
+    /// Tests the "jgz" and "jnz" opcodes, including literal first arguments, register offsets and
+    /// jumps that move outside of the program in either direction.
+    #[test]
+    fn test_opcodes_jumps() {
+        // Literal check value
+        let comp = run_program("jgz 1 2\nset a 1\nset b 1", IsaProfile::Sound);
+        assert_eq!(
+            (Ok(0), Ok(1)),
+            (comp.read_register(&'a'), comp.read_register(&'b'))
+        );
+        let comp = run_program("jgz 0 2\njgz -1 2\nset a 1", IsaProfile::Sound);
+        assert_eq!(Ok(1), comp.read_register(&'a'));
+        // Register offset
+        let comp = run_program("set c 2\njgz c c\nset a 1\nset b 1", IsaProfile::Sound);
+        assert_eq!(
+            (Ok(0), Ok(1)),
+            (comp.read_register(&'a'), comp.read_register(&'b'))
+        );
+        // Jumps left of the first instruction or past the last instruction halt the machine
+        for (raw_input, steps) in [
+            ("jgz 1 -1", 1),
+            ("set a 1\njgz a -2", 2),
+            ("jgz 1 3\nadd a 1", 1),
+        ] {
+            let program = Instruction::parse_program(raw_input).unwrap();
+            let mut comp = SoundComputer::new(&program, IsaProfile::Sound).unwrap();
+            let report = comp.execute_with_limit(10, None).unwrap();
+            assert_eq!(ExecutionReport::new(steps, StopReason::Halted), report);
+        }
+        let comp = run_program(
+            "jnz 0 2\nset a 1\njnz a -3\nset b 1",
+            IsaProfile::Coprocessor,
+        );
+        assert!(comp.is_halted());
+        assert_eq!(
+            (Ok(1), Ok(0)),
+            (comp.read_register(&'a'), comp.read_register(&'b'))
+        );
+        let comp = run_program("jnz -1 2\nset a 1\nset b 1", IsaProfile::Coprocessor);
+        assert_eq!(
+            (Ok(0), Ok(1)),
+            (comp.read_register(&'a'), comp.read_register(&'b'))
+        );
+    }
+
+    /// Tests the "snd" and "rcv" opcodes under the solo and duet profiles.
+    #[test]
+    fn test_opcodes_snd_rcv() {
+        // Solo - "rcv" is skipped for a zero register and recovers the last sound otherwise
+        let mut comp = SoundComputer::from_program_str(
+            "snd 3\nsnd a\nrcv a\nadd a 1\nrcv a",
+            IsaProfile::Sound,
+        )
+        .unwrap();
+        assert_eq!(
+            SoloOutcome {
+                recovered_frequency: Some(0),
+                steps: 4
+            },
+            comp.run_solo().unwrap()
+        );
+        assert_eq!(2, comp.get_total_sounds_sent());
+        assert!(comp.take_sent_sounds().is_empty());
+        // Duet - "snd" queues the value and "rcv" waits for a value to be received
+        let mut comp =
+            SoundComputer::from_program_str("snd 3\nsnd p\nrcv a\nrcv b\nsnd b", IsaProfile::Duet)
+                .unwrap();
+        comp.update_register(&'p', 7).unwrap();
+        let report = comp.execute_with_limit(10, None).unwrap();
+        assert_eq!(ExecutionReport::new(2, StopReason::AwaitingInput), report);
+        assert_eq!(vec![3, 7], comp.take_sent_sounds());
+        comp.receive_sounds(&[-4, 9]);
+        comp.execute().unwrap();
+        assert!(comp.is_halted());
+        assert_eq!(
+            (Ok(-4), Ok(9)),
+            (comp.read_register(&'a'), comp.read_register(&'b'))
+        );
+        assert_eq!(vec![9], comp.take_sent_sounds());
+    }
+
+    /// Tests the coprocessor profile against the Day 23 program, with and without the optimiser
+    /// rewriting its prime-checking loop. Register "b" holds 81, so the loop runs (81 - 2)^2 "mul"
+    /// instructions and "h" is incremented once since 81 is composite.
+    #[test]
+    fn test_coprocessor_day23_program() {
+        let program = Instruction::parse_program(
+            "set b 81\nset c b\njnz a 2\njnz 1 5\nmul b 100\nsub b -100000\nset c b\n\
+            sub c -17000\nset f 1\nset d 2\nset e 2\nset g d\nmul g e\nsub g b\njnz g 2\n\
+            set f 0\nsub e -1\nset g e\nsub g b\njnz g -8\nsub d -1\nset g d\nsub g b\n\
+            jnz g -13\njnz f 2\nsub h -1\nset g b\nsub g c\njnz g 2\njnz 1 3\nsub b -17\n\
+            jnz 1 -23",
+        )
+        .unwrap();
+        for (optimise, compile) in [(false, false), (true, false), (false, true), (true, true)] {
+            let mut comp = SoundComputer::new(&program, IsaProfile::Coprocessor).unwrap();
+            if optimise {
+                assert_eq!(1, comp.optimise());
+            }
+            if compile {
+                comp.compile();
             }
-            false => {
-                self.pc += usize::try_from(jmp.unsigned_abs()).unwrap();
+            comp.execute().unwrap();
+            assert!(comp.is_halted());
+            assert_eq!(6241, comp.get_mul_executions_count());
+            assert_eq!(Ok(1), comp.read_register(&'h'));
+        }
+    }
+
+    /// Tests that compiled execution leaves "snd", "rcv", failing instructions and jumps out of the
+    /// left of the program to the interpreter, matching the interpreted machine state throughout.
+    #[test]
+    fn test_compiled_execution() {
+        let programs = [
+            ("set a 1\nadd a 2\nmul a a\nmod a 5\nsnd a\nset a 0\nrcv a\njgz a -1\nset a 1\njgz a -2", IsaProfile::Sound),
+            ("rcv a\nmul a 3\nsnd a\nset b -4\njgz 1 b\nset c 1", IsaProfile::Duet),
+            ("set a 2\nmul a a\njnz a -1", IsaProfile::Coprocessor),
+            ("set a 1\nset b 5\nadd a -1\nmod b a", IsaProfile::Sound),
+            ("set a 3\njgz a -9", IsaProfile::Sound),
+        ];
+        for (raw_input, profile) in programs {
+            let program = Instruction::parse_program(raw_input).unwrap();
+            let mut interpreted = SoundComputer::new(&program, profile).unwrap();
+            let mut compiled = SoundComputer::new(&program, profile).unwrap();
+            assert!(compiled.compile() > 0);
+            assert!(compiled.is_compiled());
+            for input in [5, 2] {
+                assert_eq!(interpreted.execute(), compiled.execute());
+                assert_eq!(interpreted.snapshot(), compiled.snapshot());
+                interpreted.receive_sounds(&[input]);
+                compiled.receive_sounds(&[input]);
             }
         }
-        false
+    }
+
+    /// Tests that programs using opcodes outside of the selected profile are rejected.
+    #[test]
+    fn test_profile_rejects_illegal_instructions() {
+        let program = Instruction::parse_program("set a 1\nsnd a\nsub a 1").unwrap();
+        let err = SoundComputer::new(&program, IsaProfile::Coprocessor).err();
+        assert_eq!(
+            Some(ProgramLoadError::IllegalInstruction(
+                IllegalInstructionError {
+                    index: 1,
+                    opcode: "snd",
+                    profile: IsaProfile::Coprocessor
+                }
+            )),
+            err
+        );
+        let err = SoundComputer::new(&program, IsaProfile::Duet).err();
+        assert_eq!(
+            Some(ProgramLoadError::IllegalInstruction(
+                IllegalInstructionError {
+                    index: 2,
+                    opcode: "sub",
+                    profile: IsaProfile::Duet
+                }
+            )),
+            err
+        );
+    }
+
+    /// Tests solo execution against the example program given in the Day 18 problem statement.
+    #[test]
+    fn test_run_solo_example() {
+        let program = Instruction::parse_program(
+            "set a 1\nadd a 2\nmul a a\nmod a 5\nsnd a\nset a 0\nrcv a\njgz a -1\nset a 1\njgz a -2",
+        ).unwrap();
+        let mut sound_computer = SoundComputer::new(&program, IsaProfile::Sound).unwrap();
+        assert_eq!(
+            SoloOutcome {
+                recovered_frequency: Some(4),
+                steps: 11
+            },
+            sound_computer.run_solo().unwrap()
+        );
+        // Program that never executes "rcv" with a non-zero value halts without recovering
+        let program = Instruction::parse_program("snd 7\nrcv a\nadd a 1").unwrap();
+        let mut sound_computer = SoundComputer::new(&program, IsaProfile::Sound).unwrap();
+        assert_eq!(
+            SoloOutcome {
+                recovered_frequency: None,
+                steps: 3
+            },
+            sound_computer.run_solo().unwrap()
+        );
+    }
+
+    /// Tests that the steps yielded by the iterator can be used to analyse the program, with the
+    /// iterator stopping at the step limit, when execution stops and after an error.
+    #[test]
+    fn test_iter_steps() {
+        let program = Instruction::parse_program(
+            "set a 1\nadd a 2\nmul a a\nmod a 5\nsnd a\nset a 0\nrcv a\njgz a -1\nset a 1\njgz a -2",
+        ).unwrap();
+        let mut comp = SoundComputer::new(&program, IsaProfile::Sound).unwrap();
+        let steps = comp
+            .iter_steps(3)
+            .collect::<Result<Vec<ExecutedStep>, ExecutionError>>()
+            .unwrap();
+        assert_eq!(
+            vec![0, 1, 2],
+            steps.iter().map(|s| s.pc).collect::<Vec<usize>>()
+        );
+        assert_eq!(Some(9), steps[2].registers_after.get(0));
+        let opcode_counts = comp
+            .iter_steps(100)
+            .map(|step| step.unwrap().instruction.opcode())
+            .counts();
+        assert_eq!(Some(&3), opcode_counts.get("jgz"));
+        assert_eq!(Some(&2), opcode_counts.get("set"));
+        assert_eq!(8, opcode_counts.values().sum::<usize>());
+        // Machine recovers a frequency at the second "rcv", so no further steps are executed
+        assert_eq!(6, comp.vm.state().pc);
+        assert_eq!(0, comp.iter_steps(100).count());
+        let mut comp =
+            SoundComputer::from_program_str("set a 1\nmod b 0\nset c 1", IsaProfile::Sound)
+                .unwrap();
+        let steps = comp.iter_steps(100).collect::<Vec<_>>();
+        assert_eq!(2, steps.len());
+        assert_eq!(Err(ExecutionError::DivideByZero { index: 1 }), steps[1]);
+    }
+
+    /// Tests that restoring a snapshot rolls back all execution state, so the same input can be
+    /// replayed with different values.
+    #[test]
+    fn test_snapshot_restore_rollback() {
+        let mut comp =
+            SoundComputer::from_program_str("rcv a\nmul a 3\nsnd a\nrcv b", IsaProfile::Duet)
+                .unwrap();
+        comp.execute().unwrap();
+        assert!(comp.is_awaiting_input());
+        let state = comp.snapshot();
+        // Try one input value
+        comp.receive_sounds(&[2]);
+        comp.execute().unwrap();
+        assert_eq!(vec![6], comp.take_sent_sounds());
+        assert_ne!(state, comp.snapshot());
+        // Roll back and try another input value
+        comp.restore(&state);
+        assert_eq!(state, comp.snapshot());
+        comp.receive_sounds(&[5]);
+        comp.execute().unwrap();
+        assert_eq!(vec![15], comp.take_sent_sounds());
+        assert_eq!(1, comp.get_total_sounds_sent());
+    }
+
+    /// Tests that two duet machines that are both waiting for input are detected as deadlocked,
+    /// and that the deadlocked state is stable under further execution.
+    #[test]
+    fn test_snapshot_duet_deadlock() {
+        let program = Instruction::parse_program("snd p\nrcv a\nrcv b").unwrap();
+        let mut comp0 = SoundComputer::new(&program, IsaProfile::Duet).unwrap();
+        let mut comp1 = SoundComputer::new(&program, IsaProfile::Duet).unwrap();
+        comp1.update_register(&'p', 1).unwrap();
+        comp0.execute().unwrap();
+        comp1.execute().unwrap();
+        comp0.receive_sounds(&comp1.take_sent_sounds());
+        comp1.receive_sounds(&comp0.take_sent_sounds());
+        comp0.execute().unwrap();
+        comp1.execute().unwrap();
+        assert!(comp0.is_awaiting_input() && comp1.is_awaiting_input());
+        let (state0, state1) = (comp0.snapshot(), comp1.snapshot());
+        assert_eq!(RegisterMode::Checked, state0.registers.mode());
+        assert_eq!(Some(1), state0.registers.get(register_index('a').unwrap()));
+        assert_eq!(Some(0), state1.registers.get(register_index('a').unwrap()));
+        comp0.execute().unwrap();
+        comp1.execute().unwrap();
+        assert_eq!(state0, comp0.snapshot());
+        assert_eq!(state1, comp1.snapshot());
+    }
+
+    /// Tests that a non-halting program is stopped by the step limit, and that a halting program
+    /// reports the number of steps it executed.
+    #[test]
+    fn test_execute_with_limit() {
+        let mut comp =
+            SoundComputer::from_program_str("add a 1\njgz 1 -1", IsaProfile::Sound).unwrap();
+        let report = comp.execute_with_limit(1001, None).unwrap();
+        assert_eq!(ExecutionReport::new(1001, StopReason::StepLimit), report);
+        assert!(report.limit_reached());
+        assert_eq!(501, comp.read_register(&'a').unwrap());
+        let mut comp =
+            SoundComputer::from_program_str("set a 3\nadd a -1\njgz a -1", IsaProfile::Sound)
+                .unwrap();
+        let report = comp.execute_with_limit(1000, None).unwrap();
+        assert_eq!(ExecutionReport::new(7, StopReason::Halted), report);
+        assert!(!report.limit_reached());
+    }
+
+    /// Tests that a non-halting program is stopped by the wall-clock timeout.
+    #[test]
+    fn test_execute_with_timeout() {
+        let mut comp =
+            SoundComputer::from_program_str("add a 1\njgz 1 -1", IsaProfile::Sound).unwrap();
+        let report = comp
+            .execute_with_limit(u64::MAX, Some(Duration::from_millis(20)))
+            .unwrap();
+        assert_eq!(StopReason::Timeout, report.stop_reason);
+        assert!(report.steps > 0);
     }
 }
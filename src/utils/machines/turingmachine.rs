@@ -0,0 +1,559 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::ops::RangeBounds;
+use std::str::FromStr;
+
+/// Direction in which the [`TuringMachine`] cursor moves after writing a value to the tape.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TapeMove {
+    Left,
+    Right,
+}
+
+/// Action carried out by the [`TuringMachine`] for one of the values that can be read from the
+/// tape while in a given state.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Transition {
+    pub write: bool,
+    pub movement: TapeMove,
+    pub next_state: String,
+}
+
+impl Transition {
+    /// Creates a new [`Transition`].
+    pub fn new(write: bool, movement: TapeMove, next_state: &str) -> Transition {
+        Transition {
+            write,
+            movement,
+            next_state: next_state.to_string(),
+        }
+    }
+}
+
+/// Custom error type indicating that a [`Blueprint`] could not be built or parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlueprintError {
+    /// The start state or a transition referred to a state that was never defined.
+    UndefinedState { state: String },
+    /// The same state was defined more than once.
+    DuplicateState { state: String },
+    /// The text representation of the blueprint was malformed.
+    Malformed { message: String },
+}
+
+impl fmt::Display for BlueprintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlueprintError::UndefinedState { state } => {
+                write!(f, "Blueprint build failed: undefined state \"{state}\"")
+            }
+            BlueprintError::DuplicateState { state } => {
+                write!(f, "Blueprint build failed: duplicate state \"{state}\"")
+            }
+            BlueprintError::Malformed { message } => {
+                write!(f, "Blueprint parse failed: {message}")
+            }
+        }
+    }
+}
+
+/// Transition with the next state resolved into an index into the [`Blueprint`] states.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct ResolvedTransition {
+    write: bool,
+    movement: TapeMove,
+    next_state: usize,
+}
+
+/// Builder used to construct a [`Blueprint`] without going through the text representation.
+///
+/// States can be given any name, and may refer to states that are defined later. State names are
+/// resolved when the blueprint is built.
+pub struct BlueprintBuilder {
+    start_state: String,
+    checksum_steps: u64,
+    states: Vec<(String, [Transition; 2])>,
+}
+
+impl BlueprintBuilder {
+    /// Creates a new [`BlueprintBuilder`] for a machine beginning in the given state.
+    pub fn new(start_state: &str) -> BlueprintBuilder {
+        BlueprintBuilder {
+            start_state: start_state.to_string(),
+            checksum_steps: 0,
+            states: vec![],
+        }
+    }
+
+    /// Sets the number of steps after which the diagnostic checksum is taken.
+    pub fn checksum_after(mut self, steps: u64) -> Self {
+        self.checksum_steps = steps;
+        self
+    }
+
+    /// Adds a state, with the transitions carried out when the current value is 0 and 1.
+    pub fn state(mut self, name: &str, if_zero: Transition, if_one: Transition) -> Self {
+        self.states.push((name.to_string(), [if_zero, if_one]));
+        self
+    }
+
+    /// Builds the blueprint, resolving all state names.
+    ///
+    /// Returns a [`BlueprintError`] if a state is defined more than once, or the start state or a
+    /// transition refers to an undefined state.
+    pub fn build(self) -> Result<Blueprint, BlueprintError> {
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        for (i, (name, _)) in self.states.iter().enumerate() {
+            if indices.insert(name, i).is_some() {
+                return Err(BlueprintError::DuplicateState {
+                    state: name.to_string(),
+                });
+            }
+        }
+        let resolve = |state: &str| {
+            indices
+                .get(state)
+                .copied()
+                .ok_or_else(|| BlueprintError::UndefinedState {
+                    state: state.to_string(),
+                })
+        };
+        let start_state = resolve(&self.start_state)?;
+        let mut transitions: Vec<[ResolvedTransition; 2]> = vec![];
+        for (_, state_transitions) in self.states.iter() {
+            let mut resolved = [ResolvedTransition {
+                write: false,
+                movement: TapeMove::Left,
+                next_state: 0,
+            }; 2];
+            for (i, transition) in state_transitions.iter().enumerate() {
+                resolved[i] = ResolvedTransition {
+                    write: transition.write,
+                    movement: transition.movement,
+                    next_state: resolve(&transition.next_state)?,
+                };
+            }
+            transitions.push(resolved);
+        }
+        Ok(Blueprint {
+            start_state,
+            checksum_steps: self.checksum_steps,
+            names: self.states.into_iter().map(|(name, _)| name).collect(),
+            transitions,
+        })
+    }
+}
+
+/// Blueprint for a multi-state [`TuringMachine`] writing 0 and 1 values to its tape.
+///
+/// States are referred to by their index, in the order they were added to the
+/// [`BlueprintBuilder`] (or defined in the text representation).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Blueprint {
+    start_state: usize,
+    checksum_steps: u64,
+    names: Vec<String>,
+    transitions: Vec<[ResolvedTransition; 2]>,
+}
+
+impl Blueprint {
+    /// Gets the index of the state the machine begins in.
+    pub fn start_state(&self) -> usize {
+        self.start_state
+    }
+
+    /// Gets the number of steps after which the diagnostic checksum is taken.
+    pub fn checksum_steps(&self) -> u64 {
+        self.checksum_steps
+    }
+
+    /// Gets the number of states in the blueprint.
+    pub fn state_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Gets the name of the state with the given index.
+    pub fn state_name(&self, state: usize) -> &str {
+        &self.names[state]
+    }
+
+    /// Gets the index of the state with the given name, if it exists.
+    pub fn state_index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Gets the transition carried out when the given value is read while in the given state.
+    pub fn transition(&self, state: usize, value: bool) -> Transition {
+        let resolved = self.transitions[state][usize::from(value)];
+        Transition {
+            write: resolved.write,
+            movement: resolved.movement,
+            next_state: self.names[resolved.next_state].clone(),
+        }
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = BlueprintError;
+
+    /// Parses a blueprint from the text representation given in the AOC 2017 Day 25 problem
+    /// statement. State names can be any text not containing a colon or full stop.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .peekable();
+        let start_state = next_field(&mut lines, "Begin in state ", ".")?;
+        let steps = next_field(
+            &mut lines,
+            "Perform a diagnostic checksum after ",
+            " steps.",
+        )?;
+        let steps = steps
+            .parse::<u64>()
+            .map_err(|_| BlueprintError::Malformed {
+                message: format!("invalid checksum step count \"{steps}\""),
+            })?;
+        let mut builder = BlueprintBuilder::new(start_state).checksum_after(steps);
+        while lines.peek().is_some() {
+            let name = next_field(&mut lines, "In state ", ":")?;
+            let mut transitions = vec![];
+            for value in ["0", "1"] {
+                next_field(&mut lines, &format!("If the current value is {value}"), ":")?;
+                let write = match next_field(&mut lines, "- Write the value ", ".")? {
+                    "0" => false,
+                    "1" => true,
+                    other => {
+                        return Err(BlueprintError::Malformed {
+                            message: format!("invalid value to write \"{other}\""),
+                        })
+                    }
+                };
+                let movement = match next_field(&mut lines, "- Move one slot to the ", ".")? {
+                    "left" => TapeMove::Left,
+                    "right" => TapeMove::Right,
+                    other => {
+                        return Err(BlueprintError::Malformed {
+                            message: format!("invalid direction \"{other}\""),
+                        })
+                    }
+                };
+                let next_state = next_field(&mut lines, "- Continue with state ", ".")?;
+                transitions.push(Transition::new(write, movement, next_state));
+            }
+            let if_one = transitions.pop().unwrap();
+            let if_zero = transitions.pop().unwrap();
+            builder = builder.state(name, if_zero, if_one);
+        }
+        builder.build()
+    }
+}
+
+/// Takes the next line of the blueprint text, returning the field between the expected prefix and
+/// suffix.
+fn next_field<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    prefix: &str,
+    suffix: &str,
+) -> Result<&'a str, BlueprintError> {
+    let line = lines.next().ok_or_else(|| BlueprintError::Malformed {
+        message: format!("expected \"{prefix}\" at end of input"),
+    })?;
+    line.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(suffix))
+        .ok_or_else(|| BlueprintError::Malformed {
+            message: format!("expected \"{prefix}...{suffix}\" at \"{line}\""),
+        })
+}
+
+/// Sparse, unbounded tape for the [`TuringMachine`]. Only the slots holding a 1 are stored, with all
+/// other slots holding a 0.
+///
+/// The tape also tracks the bounds of the slots that have been visited, which grow as the cursor
+/// moves beyond them.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Tape {
+    ones: BTreeSet<i64>,
+    min_visited: i64,
+    max_visited: i64,
+}
+
+impl Tape {
+    /// Creates a new [`Tape`] holding 0 in every slot.
+    pub fn new() -> Tape {
+        Tape::default()
+    }
+
+    /// Gets the value held in the given slot.
+    pub fn get(&self, slot: i64) -> bool {
+        self.ones.contains(&slot)
+    }
+
+    /// Sets the value held in the given slot, extending the visited bounds to include it.
+    pub fn set(&mut self, slot: i64, value: bool) {
+        self.visit(slot);
+        if value {
+            self.ones.insert(slot);
+        } else {
+            self.ones.remove(&slot);
+        }
+    }
+
+    /// Extends the visited bounds to include the given slot.
+    pub fn visit(&mut self, slot: i64) {
+        self.min_visited = self.min_visited.min(slot);
+        self.max_visited = self.max_visited.max(slot);
+    }
+
+    /// Gets the minimum and maximum slots visited.
+    pub fn bounds(&self) -> (i64, i64) {
+        (self.min_visited, self.max_visited)
+    }
+
+    /// Counts the slots on the whole tape holding a 1 (the diagnostic checksum).
+    pub fn checksum(&self) -> usize {
+        self.ones.len()
+    }
+
+    /// Counts the slots within the given window holding a 1.
+    pub fn checksum_window(&self, window: impl RangeBounds<i64>) -> usize {
+        self.ones.range(window).count()
+    }
+}
+
+impl fmt::Display for Tape {
+    /// Prints the visited section of the tape as a sequence of 0 and 1 values.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for slot in self.min_visited..=self.max_visited {
+            write!(f, "{}", u8::from(self.get(slot)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Record of a single step carried out by the [`TuringMachine`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TuringStep {
+    /// Number of the step, starting at 1.
+    pub step: u64,
+    /// Index of the state the machine was in before the step.
+    pub state: usize,
+    /// Slot under the cursor before the step.
+    pub cursor: i64,
+    /// Value read from the tape.
+    pub read: bool,
+    /// Value written to the tape.
+    pub written: bool,
+    /// Index of the state the machine was in after the step.
+    pub next_state: usize,
+}
+
+/// Multi-state Turing machine that follows a [`Blueprint`], starting with the cursor at slot 0 of
+/// an empty [`Tape`].
+pub struct TuringMachine {
+    blueprint: Blueprint,
+    tape: Tape,
+    cursor: i64,
+    state: usize,
+    steps: u64,
+}
+
+impl TuringMachine {
+    /// Creates a new [`TuringMachine`] in the start state of the blueprint.
+    pub fn new(blueprint: Blueprint) -> TuringMachine {
+        let state = blueprint.start_state;
+        TuringMachine {
+            blueprint,
+            tape: Tape::new(),
+            cursor: 0,
+            state,
+            steps: 0,
+        }
+    }
+
+    /// Gets the blueprint followed by the machine.
+    pub fn blueprint(&self) -> &Blueprint {
+        &self.blueprint
+    }
+
+    /// Gets the tape of the machine.
+    pub fn tape(&self) -> &Tape {
+        &self.tape
+    }
+
+    /// Gets the slot currently under the cursor.
+    pub fn cursor(&self) -> i64 {
+        self.cursor
+    }
+
+    /// Gets the index of the current state.
+    pub fn state(&self) -> usize {
+        self.state
+    }
+
+    /// Gets the name of the current state.
+    pub fn state_name(&self) -> &str {
+        self.blueprint.state_name(self.state)
+    }
+
+    /// Gets the number of steps carried out so far.
+    pub fn step_count(&self) -> u64 {
+        self.steps
+    }
+
+    /// Carries out a single step - writing to the tape, moving the cursor and changing state.
+    pub fn step(&mut self) -> TuringStep {
+        let read = self.tape.get(self.cursor);
+        let transition = self.blueprint.transitions[self.state][usize::from(read)];
+        let record = TuringStep {
+            step: self.steps + 1,
+            state: self.state,
+            cursor: self.cursor,
+            read,
+            written: transition.write,
+            next_state: transition.next_state,
+        };
+        if transition.write != read {
+            self.tape.set(self.cursor, transition.write);
+        }
+        self.cursor += match transition.movement {
+            TapeMove::Left => -1,
+            TapeMove::Right => 1,
+        };
+        self.tape.visit(self.cursor);
+        self.state = transition.next_state;
+        self.steps += 1;
+        record
+    }
+
+    /// Gets an unbounded iterator carrying out one step of the machine per item.
+    pub fn steps(&mut self) -> impl Iterator<Item = TuringStep> + '_ {
+        std::iter::repeat_with(move || self.step())
+    }
+
+    /// Carries out the given number of steps.
+    pub fn run(&mut self, steps: u64) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Runs the machine until the number of steps given in the blueprint have been carried out,
+    /// then returns the diagnostic checksum of the tape.
+    pub fn run_diagnostic(&mut self) -> usize {
+        self.run(self.blueprint.checksum_steps.saturating_sub(self.steps));
+        self.tape.checksum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_BLUEPRINT: &str = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.
+";
+
+    /// Tests that the example blueprint from the Day 25 problem statement matches the same
+    /// blueprint built programmatically, and produces the expected diagnostic checksum.
+    #[test]
+    fn test_blueprint_example() {
+        let built = BlueprintBuilder::new("A")
+            .checksum_after(6)
+            .state(
+                "A",
+                Transition::new(true, TapeMove::Right, "B"),
+                Transition::new(false, TapeMove::Left, "B"),
+            )
+            .state(
+                "B",
+                Transition::new(true, TapeMove::Left, "A"),
+                Transition::new(true, TapeMove::Right, "A"),
+            )
+            .build()
+            .unwrap();
+        let parsed = EXAMPLE_BLUEPRINT.parse::<Blueprint>().unwrap();
+        assert_eq!(built, parsed);
+        let mut machine = TuringMachine::new(parsed);
+        assert_eq!(3, machine.run_diagnostic());
+        assert_eq!("A", machine.state_name());
+        assert_eq!("1101", machine.tape().to_string());
+        assert_eq!((-2, 1), machine.tape().bounds());
+        assert_eq!(2, machine.tape().checksum_window(-1..));
+    }
+
+    /// Tests the step iterator using a machine with arbitrary state names.
+    #[test]
+    fn test_turing_machine_steps() {
+        let blueprint = BlueprintBuilder::new("scan")
+            .state(
+                "scan",
+                Transition::new(true, TapeMove::Right, "scan"),
+                Transition::new(true, TapeMove::Left, "halt?"),
+            )
+            .state(
+                "halt?",
+                Transition::new(false, TapeMove::Left, "halt?"),
+                Transition::new(false, TapeMove::Left, "halt?"),
+            )
+            .build()
+            .unwrap();
+        let mut machine = TuringMachine::new(blueprint);
+        let steps = machine.steps().take(3).collect::<Vec<TuringStep>>();
+        assert_eq!(
+            vec![0, 1, 2],
+            steps.iter().map(|s| s.cursor).collect::<Vec<i64>>()
+        );
+        assert!(steps
+            .iter()
+            .all(|s| !s.read && s.written && s.next_state == 0));
+        assert_eq!(3, machine.tape().checksum());
+        assert_eq!(1, machine.tape().checksum_window(2..=5));
+        assert_eq!(3, machine.step_count());
+    }
+
+    /// Tests that duplicate and undefined states are reported as errors.
+    #[test]
+    fn test_blueprint_build_errors() {
+        let stay = || Transition::new(false, TapeMove::Left, "A");
+        assert_eq!(
+            Err(BlueprintError::UndefinedState {
+                state: String::from("Z")
+            }),
+            BlueprintBuilder::new("Z")
+                .state("A", stay(), stay())
+                .build()
+        );
+        assert_eq!(
+            Err(BlueprintError::DuplicateState {
+                state: String::from("A")
+            }),
+            BlueprintBuilder::new("A")
+                .state("A", stay(), stay())
+                .state("A", stay(), stay())
+                .build()
+        );
+        assert!("Begin in state A.".parse::<Blueprint>().is_err());
+    }
+}
@@ -0,0 +1,169 @@
+/// Witnesses sufficient for a deterministic Miller-Rabin primality test of all 64-bit integers.
+const MILLER_RABIN_WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Checks if the given number is prime, using a deterministic Miller-Rabin test.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    // Check small primes directly, which also covers all of the witnesses
+    for p in MILLER_RABIN_WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    // Write n - 1 as d * 2^s, with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for a in MILLER_RABIN_WITNESSES {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Calculates the greatest common divisor of a and b.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Calculates the lowest common multiple of a and b. The lowest common multiple of 0 and any
+/// number is 0.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// Calculates (base ^ exp) mod modulus using exponentiation by squaring.
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut result = 1;
+    let mut base = base % modulus;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solves the system of congruences x = residue (mod modulus) given as (residue, modulus) pairs.
+/// The moduli do not need to be pairwise coprime.
+///
+/// Returned value is the smallest non-negative solution and the modulus it is unique under (the
+/// lowest common multiple of the moduli). None is returned if the system has no solution or a
+/// modulus is not positive.
+pub fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        // Solve x + m * k = residue (mod modulus) for k
+        let (g, inv, _) = extended_gcd(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = (diff / g % step * inv % step).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        x = x.rem_euclid(m);
+    }
+    Some((i64::try_from(x).ok()?, i64::try_from(m).ok()?))
+}
+
+/// Calculates (a * b) mod modulus without overflowing. The product is only widened to u128 when it
+/// does not fit in a u64.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    match a.checked_mul(b) {
+        Some(product) => product % modulus,
+        None => ((a as u128 * b as u128) % modulus as u128) as u64,
+    }
+}
+
+/// Calculates the greatest common divisor g of a and b, along with x and y such that
+/// a * x + b * y = g.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the primality check against trial division for small numbers and against known large
+    /// primes and strong pseudoprimes.
+    #[test]
+    fn test_is_prime() {
+        for n in 0..10_000 {
+            let trial = n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(trial, is_prime(n), "n = {n}");
+        }
+        assert!(is_prime(2_147_483_647));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+    }
+
+    /// Tests the greatest common divisor and lowest common multiple calculations.
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(144, lcm(48, 18));
+        assert_eq!(0, lcm(0, 18));
+    }
+
+    /// Tests modular exponentiation, including a modulus close to the 64-bit limit.
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(1, mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(
+            1,
+            mod_pow(3, 18_446_744_073_709_551_556, 18_446_744_073_709_551_557)
+        );
+    }
+
+    /// Tests solving systems of congruences with coprime, non-coprime and inconsistent moduli.
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(
+            Some((23, 105)),
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)])
+        );
+        assert_eq!(Some((10, 12)), chinese_remainder(&[(4, 6), (2, 4)]));
+        assert_eq!(Some((3, 7)), chinese_remainder(&[(-4, 7)]));
+        assert_eq!(None, chinese_remainder(&[(1, 4), (2, 6)]));
+        assert_eq!(Some((0, 1)), chinese_remainder(&[]));
+    }
+}
@@ -1,4 +1,19 @@
+pub mod cartography;
 pub mod day20;
 pub mod error;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub mod fmt;
+pub mod fractal;
+pub mod graph;
+pub mod intern;
 pub mod knot_hash;
 pub mod machines;
+pub mod math;
+pub mod parse;
+pub mod search;
+pub mod spiral;
+pub mod stream;
+#[cfg(any(test, feature = "testgen"))]
+pub mod testgen;
+pub mod tree;
@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use crate::utils::error::{AocError, ParseError};
+
+/// Takes a decimal integer (with optional leading minus sign) from the start of the input.
+///
+/// Returned tuple contains the integer and the remaining input.
+pub fn take_integer<T: FromStr>(s: &str) -> Result<(T, &str), ParseError> {
+    let sign_len = usize::from(s.starts_with('-'));
+    let digits_len = s[sign_len..]
+        .bytes()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    let (number, rest) = s.split_at(sign_len + digits_len);
+    match number.parse::<T>() {
+        Ok(value) if digits_len > 0 => Ok((value, rest)),
+        _ => Err(ParseError {
+            message: format!("expected integer at \"{s}\""),
+        }),
+    }
+}
+
+/// Takes a single character from the start of the input.
+///
+/// Returned tuple contains the character and the remaining input.
+pub fn take_char(s: &str) -> Result<(char, &str), ParseError> {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => Ok((c, chars.as_str())),
+        None => Err(ParseError {
+            message: String::from("expected character at end of input"),
+        }),
+    }
+}
+
+/// Checks that the input starts with the literal, returning the input remaining after it.
+pub fn expect_literal<'a>(s: &'a str, literal: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(literal).ok_or_else(|| ParseError {
+        message: format!("expected \"{literal}\" at \"{s}\""),
+    })
+}
+
+/// Checks that the input has been fully consumed.
+pub fn expect_end(s: &str) -> Result<(), ParseError> {
+    match s.is_empty() {
+        true => Ok(()),
+        false => Err(ParseError {
+            message: format!("unexpected trailing input \"{s}\""),
+        }),
+    }
+}
+
+/// Parses the entire input as a decimal integer.
+pub fn parse_integer<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    let (value, rest) = take_integer(s)?;
+    expect_end(rest)?;
+    Ok(value)
+}
+
+/// Splits the input around the first occurrence of the separator.
+pub fn split_tuple<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep).ok_or_else(|| ParseError {
+        message: format!("expected separator \"{sep}\" in \"{s}\""),
+    })
+}
+
+/// Parses each non-empty line of the input (with surrounding whitespace trimmed) using the given
+/// parser function.
+///
+/// Returns an [`AocError`] giving the line number of the first line that could not be parsed.
+pub fn parse_lines_with<T, E>(
+    s: &str,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<Vec<T>, AocError> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line_num, line)| {
+            parse(line).map_err(|_| AocError::LineParse {
+                line: line_num,
+                text: line.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parses each non-empty line of the input (with surrounding whitespace trimmed) as a value of the
+/// given type.
+///
+/// Returns an [`AocError`] giving the line number of the first line that could not be parsed.
+pub fn parse_lines<T: FromStr>(s: &str) -> Result<Vec<T>, AocError> {
+    parse_lines_with(s, str::parse::<T>)
+}
+
+/// Splits the input by the separator and parses each element (with surrounding whitespace trimmed)
+/// as a value of the given type. Empty elements are skipped, so trailing separators are tolerated.
+/// A separator consisting only of whitespace matches any run of whitespace.
+///
+/// Returns an [`AocError`] giving the element number of the first element that could not be parsed.
+pub fn parse_split<T: FromStr>(s: &str, sep: &str) -> Result<Vec<T>, AocError> {
+    let elements: Box<dyn Iterator<Item = &str>> = match sep.trim().is_empty() {
+        true => Box::new(s.split_whitespace()),
+        false => Box::new(s.split(sep).map(|elem| elem.trim())),
+    };
+    elements
+        .filter(|elem| !elem.is_empty())
+        .enumerate()
+        .map(|(i, elem)| {
+            elem.parse::<T>().map_err(|_| AocError::ElementParse {
+                element: i + 1,
+                text: elem.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parses each character of the input (with surrounding whitespace trimmed) as a decimal digit.
+/// Only the ASCII digits "0" to "9" are accepted.
+///
+/// Returns an [`AocError`] giving the line and column (in characters, not bytes) of the first
+/// character that is not a digit.
+pub fn parse_digits(s: &str) -> Result<Vec<u32>, AocError> {
+    let start = s.len() - s.trim_start().len();
+    let (line, mut column) = position_at(s, start);
+    let mut digits = vec![];
+    for c in s.trim().chars() {
+        match c.to_digit(10) {
+            Some(digit) => digits.push(digit),
+            None => {
+                return Err(AocError::ValueParse {
+                    line,
+                    column,
+                    text: c.to_string(),
+                })
+            }
+        }
+        column += 1;
+    }
+    Ok(digits)
+}
+
+/// Parses each non-empty line of the input as a row of whitespace-separated values of the given
+/// type.
+///
+/// Returns an [`AocError`] giving the line and column (in characters, not bytes) of the first value
+/// that could not be parsed.
+pub fn parse_rows<T: FromStr>(s: &str) -> Result<Vec<Vec<T>>, AocError> {
+    let mut rows = vec![];
+    for (i, line) in s.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut row = vec![];
+        for value in line.split_whitespace() {
+            let offset = value.as_ptr() as usize - line.as_ptr() as usize;
+            let parsed = value.parse::<T>().map_err(|_| AocError::ValueParse {
+                line: i + 1,
+                column: line[..offset].chars().count() + 1,
+                text: value.to_string(),
+            })?;
+            row.push(parsed);
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Gets the line and column (in characters, starting from 1) of the byte offset in the input.
+fn position_at(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests chaining the helpers to parse a line from left to right.
+    #[test]
+    fn test_parse_helpers_chain() {
+        let s = "x-12/4 <-> end";
+        let rest = expect_literal(s, "x").unwrap();
+        let (a, rest) = take_integer::<i64>(rest).unwrap();
+        let rest = expect_literal(rest, "/").unwrap();
+        let (b, rest) = take_integer::<usize>(rest).unwrap();
+        let (left, right) = split_tuple(rest, " <-> ").unwrap();
+        assert_eq!((-12, 4), (a, b));
+        assert_eq!(("", "end"), (left, right));
+        assert_eq!(('e', "nd"), take_char(right).unwrap());
+        assert_eq!(Ok(42u64), parse_integer("42"));
+    }
+
+    /// Tests that the helpers report an error on malformed input.
+    #[test]
+    fn test_parse_helpers_errors() {
+        assert!(take_integer::<i64>("-").is_err());
+        assert!(take_integer::<u64>("-5").is_err());
+        assert!(take_integer::<u8>("256").is_err());
+        assert!(take_char("").is_err());
+        assert!(expect_literal("p=<", "v=<").is_err());
+        assert!(parse_integer::<u64>("12a").is_err());
+        assert!(split_tuple("0: 3", " <-> ").is_err());
+    }
+
+    /// Tests the line and separated list helpers, including the error context for bad input.
+    #[test]
+    fn test_parse_lines_and_split() {
+        assert_eq!(Ok(vec![0, 3, -1]), parse_lines::<i64>("0\n 3 \n\n-1\n"));
+        assert_eq!(
+            Err(AocError::LineParse {
+                line: 3,
+                text: String::from("x")
+            }),
+            parse_lines::<i64>("0\n\nx\n")
+        );
+        assert_eq!(Ok(vec![3, 4, 1]), parse_split::<u64>("3, 4,1,", ","));
+        assert_eq!(Ok(vec![5, 9, 2]), parse_split::<u64>("5\t9  2\n", " "));
+        assert_eq!(
+            Err(AocError::ElementParse {
+                element: 2,
+                text: String::from("b")
+            }),
+            parse_split::<u64>("1,b,3", ",")
+        );
+        assert_eq!(Ok(vec![1, 1, 2, 2]), parse_digits("1122\n"));
+        assert!(parse_digits("12a").is_err());
+        assert_eq!(
+            Err(AocError::ValueParse {
+                line: 1,
+                column: 3,
+                text: String::from("٣")
+            }),
+            parse_digits("12٣4")
+        );
+        assert_eq!(
+            Err(AocError::ValueParse {
+                line: 2,
+                column: 4,
+                text: String::from("x")
+            }),
+            parse_digits("\n 12x3")
+        );
+        assert_eq!(
+            Ok(vec![vec![5, 1], vec![7]]),
+            parse_rows::<u64>("5 1\n\n\t7\n")
+        );
+        assert_eq!(
+            Err(AocError::ValueParse {
+                line: 2,
+                column: 4,
+                text: String::from("2.5")
+            }),
+            parse_rows::<u64>("1 2\n3\t 2.5 4")
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Best state found by a depth-first search, along with its score.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DfsResult<S, V> {
+    /// State with the highest score. If several states share the highest score, the first state
+    /// visited is kept.
+    pub state: S,
+    /// Score of the best state.
+    pub score: V,
+    /// Number of states visited during the search, excluding any that were pruned.
+    pub visited: usize,
+}
+
+/// Runs a depth-first (backtracking) search from the start state, returning the state with the
+/// highest score, or None if no visited state has a score.
+///
+/// The search is driven by three functions:
+///
+/// - `expand` gets the states reachable from a state. These are visited in the order given, each
+///   fully explored before the next.
+/// - `prune` is checked before each state is visited, given the state and the best score found so
+///   far. If it returns true, the state and everything reachable from it are skipped. This allows
+///   branch-and-bound searches to skip states that cannot improve on the best score.
+/// - `score` gets the score of a state, or None if the state is not a candidate solution.
+///
+/// States are not remembered, so a state reachable along several paths is visited once for each
+/// path (see [`dfs_memoized`]). The search does not terminate if `expand` produces a cycle.
+pub fn dfs<S, V, I>(
+    start: S,
+    expand: impl FnMut(&S) -> I,
+    prune: impl FnMut(&S, Option<&V>) -> bool,
+    score: impl FnMut(&S) -> Option<V>,
+) -> Option<DfsResult<S, V>>
+where
+    V: PartialOrd,
+    I: IntoIterator<Item = S>,
+{
+    search(start, expand, prune, score, |_| true)
+}
+
+/// Runs a depth-first search in the same way as [`dfs`], except that each distinct state is only
+/// visited once, however many paths lead to it. This also allows searches over graphs with cycles.
+pub fn dfs_memoized<S, V, I>(
+    start: S,
+    expand: impl FnMut(&S) -> I,
+    prune: impl FnMut(&S, Option<&V>) -> bool,
+    score: impl FnMut(&S) -> Option<V>,
+) -> Option<DfsResult<S, V>>
+where
+    S: Clone + Eq + Hash,
+    V: PartialOrd,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen: HashSet<S> = HashSet::new();
+    search(start, expand, prune, score, |state| {
+        seen.insert(state.clone())
+    })
+}
+
+/// Runs the depth-first search, only visiting the states accepted by the `first_visit` function.
+fn search<S, V, I>(
+    start: S,
+    mut expand: impl FnMut(&S) -> I,
+    mut prune: impl FnMut(&S, Option<&V>) -> bool,
+    mut score: impl FnMut(&S) -> Option<V>,
+    mut first_visit: impl FnMut(&S) -> bool,
+) -> Option<DfsResult<S, V>>
+where
+    V: PartialOrd,
+    I: IntoIterator<Item = S>,
+{
+    let mut best: Option<(S, V)> = None;
+    let mut visited = 0;
+    let mut stack = vec![start];
+    while let Some(state) = stack.pop() {
+        if prune(&state, best.as_ref().map(|(_, score)| score)) || !first_visit(&state) {
+            continue;
+        }
+        visited += 1;
+        // Push in reverse so that the next states are visited in the order given
+        let next = expand(&state).into_iter().collect::<Vec<S>>();
+        stack.extend(next.into_iter().rev());
+        if let Some(value) = score(&state) {
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| value > *best_score)
+            {
+                best = Some((state, value));
+            }
+        }
+    }
+    best.map(|(state, score)| DfsResult {
+        state,
+        score,
+        visited,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Components (pairs of port sizes) from the example given in the AOC 2017 Day 24 problem
+    /// statement.
+    const COMPONENTS: [(u64, u64); 8] = [
+        (0, 2),
+        (2, 2),
+        (2, 3),
+        (3, 4),
+        (3, 5),
+        (0, 1),
+        (10, 1),
+        (9, 10),
+    ];
+
+    /// Gets the bridges that extend the bridge (indices of the components used, in order) by one
+    /// component, matching the free port at the end of the bridge.
+    fn extend_bridge(bridge: &Vec<usize>) -> Vec<Vec<usize>> {
+        let mut port = 0;
+        for &i in bridge {
+            let (a, b) = COMPONENTS[i];
+            port = if a == port { b } else { a };
+        }
+        (0..COMPONENTS.len())
+            .filter(|i| !bridge.contains(i))
+            .filter(|&i| COMPONENTS[i].0 == port || COMPONENTS[i].1 == port)
+            .map(|i| {
+                let mut next = bridge.clone();
+                next.push(i);
+                next
+            })
+            .collect()
+    }
+
+    /// Calculates the strength of the bridge - the sum of the port sizes of its components.
+    fn strength(bridge: &[usize]) -> u64 {
+        bridge
+            .iter()
+            .map(|&i| COMPONENTS[i].0 + COMPONENTS[i].1)
+            .sum()
+    }
+
+    /// Tests the search by building bridges from the AOC 2017 Day 24 example, finding the strongest
+    /// bridge and the strongest of the longest bridges.
+    #[test]
+    fn test_dfs_bridges() {
+        let strongest = dfs(vec![], extend_bridge, |_, _| false, |b| Some(strength(b))).unwrap();
+        assert_eq!(31, strongest.score);
+        assert_eq!(vec![5, 6, 7], strongest.state);
+        // Every bridge is visited, including the empty bridge at the start
+        assert_eq!(12, strongest.visited);
+        let longest = dfs(
+            vec![],
+            extend_bridge,
+            |_, _| false,
+            |b| Some((b.len(), strength(b))),
+        )
+        .unwrap();
+        assert_eq!((4, 19), longest.score);
+    }
+
+    /// Tests that pruning states which cannot beat the best score skips part of the search without
+    /// changing the result, and that states without a score are never returned.
+    #[test]
+    fn test_dfs_pruning() {
+        // Upper bound on the strength of any bridge extending the given bridge
+        let total = strength(&(0..COMPONENTS.len()).collect::<Vec<usize>>());
+        let bound = |b: &Vec<usize>, best: Option<&u64>| {
+            let remaining = total - strength(b);
+            best.is_some_and(|best| strength(b) + remaining <= *best)
+        };
+        let unpruned = dfs(vec![], extend_bridge, |_, _| false, |b| Some(strength(b))).unwrap();
+        let pruned = dfs(vec![], extend_bridge, bound, |b| Some(strength(b))).unwrap();
+        assert_eq!(unpruned.score, pruned.score);
+        assert!(pruned.visited <= unpruned.visited);
+        let all_pruned = dfs(vec![], extend_bridge, |_, _| true, |b| Some(strength(b)));
+        assert_eq!(None, all_pruned);
+        let only_long = dfs(
+            vec![],
+            extend_bridge,
+            |_, _| false,
+            |b| (b.len() >= 3).then(|| strength(b)),
+        )
+        .unwrap();
+        assert_eq!(31, only_long.score);
+        assert!(only_long.state.len() >= 3);
+    }
+
+    /// Tests that memoization visits each state once, allowing a search over a graph with cycles.
+    #[test]
+    fn test_dfs_memoized() {
+        // Each number n leads to (n * 2) % 11 and (n + 3) % 11, which reaches every number from 1
+        let expand = |n: &u64| [(n * 2) % 11, (n + 3) % 11];
+        let result = dfs_memoized(1, expand, |_, _| false, |n| Some(*n)).unwrap();
+        assert_eq!(10, result.score);
+        assert_eq!(11, result.visited);
+        let result = dfs_memoized(1, expand, |n, _| *n > 7, |n| (n % 2 == 0).then_some(*n));
+        assert_eq!(Some(6), result.map(|r| r.score));
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::utils::cartography::Point2D;
+
+/// Gets the location of square n in the spiral memory from AOC 2017 Day 03, relative to square 1
+/// at the origin. The spiral starts by moving right, then winds anticlockwise with y increasing
+/// downwards.
+///
+/// Ring k of the spiral ends with the square (2k + 1)^2 in its bottom right corner, and its sides
+/// have length 2k, so the location is found directly from the side and offset of the square within
+/// its ring. Square 0 does not exist, so is treated as square 1.
+pub fn spiral_coordinates(n: u64) -> Point2D {
+    if n <= 1 {
+        return Point2D::new(0, 0);
+    }
+    // Smallest ring whose last square is at or beyond n
+    let ring = (n - 1).isqrt().div_ceil(2);
+    let side = 2 * ring;
+    let offset = n - (side - 1).pow(2) - 1;
+    let (k, t) = (ring as i64, (offset % side) as i64);
+    match offset / side {
+        0 => Point2D::new(k, k - 1 - t),   // right side, moving up
+        1 => Point2D::new(k - 1 - t, -k),  // top side, moving left
+        2 => Point2D::new(-k, -k + 1 + t), // left side, moving down
+        _ => Point2D::new(-k + 1 + t, k),  // bottom side, moving right
+    }
+}
+
+/// Gets the first value written to the stress test spiral from AOC 2017 Day 03 that is larger than
+/// the target. Each square is written with the sum of the values in all of the adjacent squares
+/// (including diagonals) that have already been written, starting with 1 in square 1.
+pub fn first_spiral_sum_exceeding(target: u64) -> u64 {
+    let mut spiral: HashMap<Point2D, u64> = HashMap::from([(Point2D::new(0, 0), 1)]);
+    let mut value = 1;
+    let mut n = 1;
+    while value <= target {
+        n += 1;
+        let loc = spiral_coordinates(n);
+        value = loc.surrounding().filter_map(|sloc| spiral.get(&sloc)).sum();
+        spiral.insert(loc, value);
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the spiral coordinates against the squares given in the problem statement, and that
+    /// consecutive squares are always adjacent.
+    #[test]
+    fn test_spiral_coordinates() {
+        let origin = Point2D::new(0, 0);
+        for (n, steps) in [(1, 0), (12, 3), (23, 2), (1024, 31)] {
+            assert_eq!(steps, spiral_coordinates(n).manhattan(&origin));
+        }
+        assert_eq!(Point2D::new(1, 0), spiral_coordinates(2));
+        assert_eq!(Point2D::new(-2, -2), spiral_coordinates(17));
+        assert_eq!(Point2D::new(1, 1), spiral_coordinates(9));
+        for n in 1..2000 {
+            assert_eq!(
+                1,
+                spiral_coordinates(n).manhattan(&spiral_coordinates(n + 1))
+            );
+        }
+    }
+
+    /// Tests the stress test spiral against the values given in the problem statement.
+    #[test]
+    fn test_first_spiral_sum_exceeding() {
+        let values = [
+            1, 2, 4, 5, 10, 11, 23, 25, 26, 54, 57, 59, 122, 133, 142, 147, 304,
+        ];
+        for pair in values.windows(2) {
+            assert_eq!(pair[1], first_spiral_sum_exceeding(pair[0]));
+        }
+        assert_eq!(806, first_spiral_sum_exceeding(747));
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Kinds of element that can be left open at the end of a stream.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StreamElement {
+    Group,
+    Garbage,
+}
+
+impl fmt::Display for StreamElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamElement::Group => write!(f, "group"),
+            StreamElement::Garbage => write!(f, "garbage"),
+        }
+    }
+}
+
+/// Custom error type indicating that a stream is malformed (see [`StreamMode::Strict`]), or could
+/// not be read. Positions are byte offsets from the start of the stream, starting at 0.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StreamParseError {
+    /// The stream ended with a group or garbage still open. The position is where the outermost
+    /// open group, or the open garbage, started.
+    Unterminated {
+        kind: StreamElement,
+        position: usize,
+    },
+    /// A "}" was found outside of garbage with no group open.
+    UnexpectedClose { position: usize },
+    /// The stream could not be read.
+    Io(io::ErrorKind),
+}
+
+impl fmt::Display for StreamParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamParseError::Unterminated { kind, position } => write!(
+                f,
+                "Stream parse failed: {kind} opened at position {position} is not closed"
+            ),
+            StreamParseError::UnexpectedClose { position } => write!(
+                f,
+                "Stream parse failed: unexpected \"}}\" at position {position} with no group open"
+            ),
+            StreamParseError::Io(kind) => write!(f, "Stream parse failed: {kind}"),
+        }
+    }
+}
+
+/// How a [`StreamState`] treats malformed streams.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum StreamMode {
+    /// Malformed streams are rejected with a [`StreamParseError`].
+    #[default]
+    Strict,
+    /// Malformed streams are accepted - a "}" with no group open is ignored, and the counts
+    /// accumulated so far are kept if the stream ends with groups or garbage left open.
+    Lenient,
+}
+
+/// Incremental parser for a stream of nested groups and garbage (AOC 2017 Day 9). Bytes are fed in
+/// one at a time, so the stream never needs to be held in memory in full.
+///
+/// Groups are delimited by "{" and "}", garbage by "<" and ">", and "!" within garbage cancels the
+/// next character. The score of each group is its depth of nesting, starting from 1 for the
+/// outermost groups.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct StreamState {
+    mode: StreamMode,
+    position: usize,
+    depth: u64,
+    score: u64,
+    garbage_count: u64,
+    in_garbage: bool,
+    cancel_next: bool,
+    group_start: usize,
+    garbage_start: usize,
+}
+
+impl StreamState {
+    /// Creates a new [`StreamState`] for the start of a stream, rejecting malformed streams.
+    pub fn new() -> StreamState {
+        StreamState::default()
+    }
+
+    /// Creates a new [`StreamState`] for the start of a stream, treating malformed streams as
+    /// given by the mode.
+    pub fn with_mode(mode: StreamMode) -> StreamState {
+        StreamState {
+            mode,
+            ..StreamState::default()
+        }
+    }
+
+    /// Processes the next byte of the stream.
+    ///
+    /// UTF-8 continuation bytes are not counted as garbage, so multi-byte characters within
+    /// garbage each count once (and are cancelled in full by a preceding "!").
+    ///
+    /// In strict mode, a [`StreamParseError`] is returned if the byte is a "}" with no group open.
+    pub fn feed(&mut self, byte: u8) -> Result<(), StreamParseError> {
+        let position = self.position;
+        self.position += 1;
+        if is_continuation_byte(byte) {
+            return Ok(());
+        }
+        if self.cancel_next {
+            self.cancel_next = false;
+            return Ok(());
+        }
+        if self.in_garbage {
+            match byte {
+                b'!' => self.cancel_next = true,
+                b'>' => self.in_garbage = false,
+                _ => self.garbage_count += 1,
+            }
+            return Ok(());
+        }
+        match byte {
+            b'{' => {
+                if self.depth == 0 {
+                    self.group_start = position;
+                }
+                self.depth += 1;
+            }
+            b'}' if self.depth > 0 => {
+                self.score += self.depth;
+                self.depth -= 1;
+            }
+            b'}' if self.mode == StreamMode::Strict => {
+                return Err(StreamParseError::UnexpectedClose { position })
+            }
+            b'<' => {
+                self.garbage_start = position;
+                self.in_garbage = true;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Processes a chunk of bytes from the stream, stopping at the first error (see
+    /// [`StreamState::feed`]).
+    pub fn feed_all(&mut self, bytes: &[u8]) -> Result<(), StreamParseError> {
+        bytes.iter().try_for_each(|&byte| self.feed(byte))
+    }
+
+    /// Finishes the stream, returning the final state.
+    ///
+    /// In strict mode, a [`StreamParseError`] is returned if any garbage or groups are left open.
+    pub fn finish(self) -> Result<StreamState, StreamParseError> {
+        if self.mode == StreamMode::Lenient {
+            return Ok(self);
+        }
+        if self.in_garbage {
+            return Err(StreamParseError::Unterminated {
+                kind: StreamElement::Garbage,
+                position: self.garbage_start,
+            });
+        }
+        if self.depth > 0 {
+            return Err(StreamParseError::Unterminated {
+                kind: StreamElement::Group,
+                position: self.group_start,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Gets the total score of the groups closed so far.
+    pub fn score(&self) -> u64 {
+        self.score
+    }
+
+    /// Gets the number of non-cancelled characters within garbage seen so far.
+    pub fn garbage_count(&self) -> u64 {
+        self.garbage_count
+    }
+
+    /// Gets the number of groups currently open.
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    /// Checks if the stream seen so far is complete, with all groups and garbage closed.
+    pub fn is_complete(&self) -> bool {
+        self.depth == 0 && !self.in_garbage && !self.cancel_next
+    }
+}
+
+/// Parses the stream held by the reader chunk by chunk, treating malformed streams as given by the
+/// mode, and returns the final [`StreamState`].
+///
+/// Returns a [`StreamParseError`] if the stream cannot be read or (in strict mode) is malformed.
+pub fn parse_stream(
+    mut reader: impl BufRead,
+    mode: StreamMode,
+) -> Result<StreamState, StreamParseError> {
+    let mut state = StreamState::with_mode(mode);
+    loop {
+        let chunk = reader
+            .fill_buf()
+            .map_err(|e| StreamParseError::Io(e.kind()))?;
+        if chunk.is_empty() {
+            return state.finish();
+        }
+        state.feed_all(chunk)?;
+        let len = chunk.len();
+        reader.consume(len);
+    }
+}
+
+/// Checks if the byte is a UTF-8 continuation byte (i.e. not the first byte of a character).
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests the parser against the examples given in the AOC 2017 Day 9 problem statement, fed
+    /// through a reader with a small buffer so the streams are split across chunks.
+    #[test]
+    fn test_parse_stream_examples() {
+        let score_examples = [
+            ("{}", 1),
+            ("{{{}}}", 6),
+            ("{{},{}}", 5),
+            ("{{{},{},{{}}}}", 16),
+            ("{<a>,<a>,<a>,<a>}", 1),
+            ("{{<ab>},{<ab>},{<ab>},{<ab>}}", 9),
+            ("{{<!!>},{<!!>},{<!!>},{<!!>}}", 9),
+            ("{{<a!>},{<a!>},{<a!>},{<ab>}}", 3),
+        ];
+        for (stream, score) in score_examples {
+            let reader = io::BufReader::with_capacity(3, stream.as_bytes());
+            let state = parse_stream(reader, StreamMode::Strict).unwrap();
+            assert_eq!(score, state.score());
+            assert!(state.is_complete());
+        }
+        let garbage_examples = [
+            ("<>", 0),
+            ("<random characters>", 17),
+            ("<<<<>", 3),
+            ("<{!>}>", 2),
+            ("<!!>", 0),
+            ("<!!!>>", 0),
+            ("<{o\"i!a,<{i<a>", 10),
+            ("<é!ü>", 1),
+        ];
+        for (stream, garbage_count) in garbage_examples {
+            let reader = io::BufReader::with_capacity(3, stream.as_bytes());
+            let state = parse_stream(reader, StreamMode::Strict).unwrap();
+            assert_eq!(garbage_count, state.garbage_count());
+        }
+        let mut state = StreamState::new();
+        state.feed_all(b"{{<!").unwrap();
+        assert_eq!(2, state.depth());
+        assert!(!state.is_complete());
+    }
+
+    /// Tests that malformed streams are rejected in strict mode, giving the position of the element
+    /// left open, and accepted with the counts accumulated so far in lenient mode.
+    #[test]
+    fn test_parse_stream_malformed() {
+        let malformed = [
+            (
+                "{{<ab>},{<a",
+                StreamParseError::Unterminated {
+                    kind: StreamElement::Garbage,
+                    position: 9,
+                },
+                (2, 3),
+            ),
+            (
+                "{<a>},{{}",
+                StreamParseError::Unterminated {
+                    kind: StreamElement::Group,
+                    position: 6,
+                },
+                (3, 1),
+            ),
+            (
+                "{<!>}>",
+                StreamParseError::Unterminated {
+                    kind: StreamElement::Group,
+                    position: 0,
+                },
+                (0, 1),
+            ),
+            (
+                "{}},{}",
+                StreamParseError::UnexpectedClose { position: 2 },
+                (2, 0),
+            ),
+            (
+                "<!",
+                StreamParseError::Unterminated {
+                    kind: StreamElement::Garbage,
+                    position: 0,
+                },
+                (0, 0),
+            ),
+        ];
+        for (stream, error, (score, garbage_count)) in malformed {
+            let reader = io::BufReader::with_capacity(3, stream.as_bytes());
+            assert_eq!(Err(error), parse_stream(reader, StreamMode::Strict));
+            let reader = io::BufReader::with_capacity(3, stream.as_bytes());
+            let state = parse_stream(reader, StreamMode::Lenient).unwrap();
+            assert_eq!(
+                (score, garbage_count),
+                (state.score(), state.garbage_count())
+            );
+        }
+        assert_eq!(
+            "Stream parse failed: garbage opened at position 9 is not closed",
+            malformed[0].1.to_string()
+        );
+    }
+}
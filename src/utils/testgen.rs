@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+
+/// Days for which random inputs can be generated by [`generate_input`].
+pub const GENERATED_DAYS: &[u64] = &[4, 5, 12, 20];
+
+/// Deterministic pseudo-random number generator (SplitMix64), used so that the same seed always
+/// produces the same input regardless of platform or dependency versions.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Creates a new [`SeededRng`] from the seed.
+    pub fn new(seed: u64) -> SeededRng {
+        SeededRng { state: seed }
+    }
+
+    /// Gets the next 64-bit value from the generator.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a value from the (non-empty) range. The slight bias towards lower values for ranges
+    /// that do not divide 2^64 is not significant for generating test inputs.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        match range.end().abs_diff(*range.start()).checked_add(1) {
+            Some(span) => range.start().wrapping_add((self.next_u64() % span) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// Gets an index less than the (non-zero) length.
+    pub fn index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+/// Generates a random input for the day from the seed, in the same format as the problem input
+/// file. The size gives the number of lines (or particles, programs etc.) to generate.
+///
+/// Returns None if inputs cannot be generated for the day (see [`GENERATED_DAYS`]).
+pub fn generate_input(day: u64, seed: u64, size: usize) -> Option<String> {
+    match day {
+        4 => Some(passphrases(seed, size)),
+        5 => Some(jump_offsets(seed, size)),
+        12 => Some(program_graph(seed, size)),
+        20 => Some(particle_swarm(seed, size)),
+        _ => None,
+    }
+}
+
+/// Generates passphrases made up of lowercase words (AOC 2017 Day 4). Roughly a quarter of the
+/// passphrases repeat a word and another quarter contain an anagram of an earlier word, so that
+/// both validity policies reject some passphrases.
+pub fn passphrases(seed: u64, count: usize) -> String {
+    let mut rng = SeededRng::new(seed);
+    let mut lines = Vec::with_capacity(count);
+    for _ in 0..count {
+        let word_count = rng.range(4..=10) as usize;
+        let mut words = (0..word_count)
+            .map(|_| random_word(&mut rng))
+            .collect::<Vec<String>>();
+        match rng.range(0..=3) {
+            0 => {
+                let word = words[rng.index(words.len())].clone();
+                words.push(word);
+            }
+            1 => {
+                let mut letters = words[rng.index(words.len())].chars().collect::<Vec<char>>();
+                letters.rotate_left(1);
+                words.push(letters.into_iter().collect());
+            }
+            _ => (),
+        }
+        lines.push(words.join(" "));
+    }
+    join_lines(&lines)
+}
+
+/// Generates a random lowercase word of 2 to 7 letters.
+fn random_word(rng: &mut SeededRng) -> String {
+    let len = rng.range(2..=7);
+    (0..len)
+        .map(|_| (b'a' + rng.range(0..=25) as u8) as char)
+        .collect()
+}
+
+/// Generates a list of jump offsets (AOC 2017 Day 5). As in the problem input, most offsets are
+/// negative, but no offset jumps back past the start of the list.
+pub fn jump_offsets(seed: u64, count: usize) -> String {
+    let mut rng = SeededRng::new(seed);
+    let lines = (0..count as i64)
+        .map(|i| rng.range(-i..=2).to_string())
+        .collect::<Vec<String>>();
+    join_lines(&lines)
+}
+
+/// Generates the pipes connecting the programs in a village (AOC 2017 Day 12). Each program is
+/// connected to up to two others chosen at random, so the programs form several groups. Pipes are
+/// listed in both directions, and programs without any pipes are connected to themselves.
+pub fn program_graph(seed: u64, count: usize) -> String {
+    let mut rng = SeededRng::new(seed);
+    let mut pipes = vec![BTreeSet::new(); count];
+    for program in 0..count {
+        for _ in 0..rng.range(0..=2) {
+            let other = rng.index(count);
+            pipes[program].insert(other);
+            pipes[other].insert(program);
+        }
+    }
+    let lines = pipes
+        .iter()
+        .enumerate()
+        .map(|(program, connected)| {
+            let connected = match connected.is_empty() {
+                true => program.to_string(),
+                false => connected
+                    .iter()
+                    .map(|other| other.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            };
+            format!("{program} <-> {connected}")
+        })
+        .collect::<Vec<String>>();
+    join_lines(&lines)
+}
+
+/// Generates a swarm of particles (AOC 2017 Day 20). A single particle, chosen at random, has no
+/// acceleration while every other particle does, so that exactly one particle stays closest to
+/// the origin in the long term.
+pub fn particle_swarm(seed: u64, count: usize) -> String {
+    let mut rng = SeededRng::new(seed);
+    let still = rng.index(count.max(1));
+    let lines = (0..count)
+        .map(|i| {
+            let p = random_vector(&mut rng, 3000);
+            let v = random_vector(&mut rng, 150);
+            let a = match i == still {
+                true => [0, 0, 0],
+                false => loop {
+                    let a = random_vector(&mut rng, 15);
+                    if a != [0, 0, 0] {
+                        break a;
+                    }
+                },
+            };
+            format!(
+                "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+                p[0], p[1], p[2], v[0], v[1], v[2], a[0], a[1], a[2]
+            )
+        })
+        .collect::<Vec<String>>();
+    join_lines(&lines)
+}
+
+/// Generates a vector with each component in the range [-limit, limit].
+fn random_vector(rng: &mut SeededRng, limit: i64) -> [i64; 3] {
+    [0; 3].map(|_| rng.range(-limit..=limit))
+}
+
+/// Joins the lines into the contents of an input file, ending with a newline.
+fn join_lines(lines: &[String]) -> String {
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tests that the generators are deterministic for a seed, and that each generated input
+    /// meets the constraints described for its generator.
+    #[test]
+    fn test_generate_input() {
+        for &day in GENERATED_DAYS {
+            let input = generate_input(day, 42, 200).unwrap();
+            assert_eq!(Some(&input), generate_input(day, 42, 200).as_ref());
+            assert_ne!(Some(&input), generate_input(day, 43, 200).as_ref());
+            assert_eq!(200, input.lines().count());
+        }
+        assert_eq!(None, generate_input(1, 42, 10));
+        // Jumps never move back past the start of the list
+        let jumps = jump_offsets(7, 100);
+        for (i, line) in jumps.lines().enumerate() {
+            assert!(line.parse::<i64>().unwrap() + i as i64 >= 0);
+        }
+        // Pipes are listed in both directions
+        let graph = program_graph(7, 100);
+        let pipes = graph
+            .lines()
+            .map(|line| line.split_once(" <-> ").unwrap().1.split(", ").collect())
+            .collect::<Vec<Vec<&str>>>();
+        for (program, connected) in pipes.iter().enumerate() {
+            for other in connected {
+                let other = other.parse::<usize>().unwrap();
+                assert!(pipes[other].contains(&program.to_string().as_str()));
+            }
+        }
+        // Exactly one particle has no acceleration
+        let swarm = particle_swarm(7, 100);
+        assert_eq!(1, swarm.matches("a=<0,0,0>").count());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod weightedtree;
+
+pub use weightedtree::{TreeBuildError, Unbalanced, WeightedTree};
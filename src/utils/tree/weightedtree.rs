@@ -0,0 +1,563 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+use crate::utils::intern::{Interner, Symbol};
+
+/// Custom error type indicating that the nodes given to [`WeightedTree::from_nodes`] (or
+/// [`WeightedTree::from_symbols`]) do not form a single tree.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TreeBuildError {
+    /// More than one node was given with the same name.
+    DuplicateNode { name: String },
+    /// A node referred to a child that was never defined.
+    UndefinedChild { parent: String, child: String },
+    /// A node was given as the child of more than one parent (or more than once by one parent).
+    SharedChild { child: String, parents: Vec<String> },
+    /// The nodes form a cycle, given in parent-to-child order starting from the first node given.
+    Cycle { names: Vec<String> },
+    /// No node was given without a parent.
+    NoRoot,
+    /// More than one node was given without a parent.
+    MultipleRoots { names: Vec<String> },
+}
+
+impl fmt::Display for TreeBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeBuildError::DuplicateNode { name } => {
+                write!(f, "Tree build failed: duplicate node \"{name}\"")
+            }
+            TreeBuildError::UndefinedChild { parent, child } => write!(
+                f,
+                "Tree build failed: \"{parent}\" refers to undefined child \"{child}\""
+            ),
+            TreeBuildError::SharedChild { child, parents } => write!(
+                f,
+                "Tree build failed: \"{child}\" is a child of {}",
+                parents.join(", ")
+            ),
+            TreeBuildError::Cycle { names } => {
+                write!(
+                    f,
+                    "Tree build failed: cycle {} -> {}",
+                    names.join(" -> "),
+                    names[0]
+                )
+            }
+            TreeBuildError::NoRoot => write!(f, "Tree build failed: no root node"),
+            TreeBuildError::MultipleRoots { names } => {
+                write!(
+                    f,
+                    "Tree build failed: multiple root nodes {}",
+                    names.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Node of a [`WeightedTree`] that has a different subtree weight to its siblings, along with the
+/// weight its own node would need to be to balance the tree.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Unbalanced {
+    pub node: usize,
+    pub corrected_weight: u64,
+}
+
+/// Tree of named nodes, each with its own weight. The total weight of the subtree rooted at each
+/// node is calculated when the tree is built.
+///
+/// Nodes are referred to by their index, in the order they were given to
+/// [`WeightedTree::from_nodes`]. Node names are held as [`Symbol`]s, which can be resolved to the
+/// names using the tree's [`Interner`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WeightedTree {
+    interner: Interner,
+    symbols: Vec<Symbol>,
+    nodes_by_symbol: Vec<Option<usize>>,
+    weights: Vec<u64>,
+    children: Vec<Vec<usize>>,
+    subtree_weights: Vec<u64>,
+    root: usize,
+}
+
+impl WeightedTree {
+    /// Builds a tree from the name, weight and child names of each node.
+    ///
+    /// Returns a [`TreeBuildError`] if the nodes do not form a single tree - if any node is defined
+    /// more than once, refers to an undefined child, has more than one parent or is part of a cycle,
+    /// or if there is not exactly one root node.
+    pub fn from_nodes(
+        nodes: &[(String, u64, Vec<String>)],
+    ) -> Result<WeightedTree, TreeBuildError> {
+        let mut interner = Interner::new();
+        let nodes = nodes
+            .iter()
+            .map(|(name, weight, children)| {
+                let children = children.iter().map(|c| interner.intern(c)).collect();
+                (interner.intern(name), *weight, children)
+            })
+            .collect::<Vec<(Symbol, u64, Vec<Symbol>)>>();
+        WeightedTree::from_symbols(interner, &nodes)
+    }
+
+    /// Builds a tree in the same way as [`WeightedTree::from_nodes`], from nodes with names already
+    /// interned by the given [`Interner`]. The interner is kept by the tree to resolve the names.
+    pub fn from_symbols(
+        interner: Interner,
+        nodes: &[(Symbol, u64, Vec<Symbol>)],
+    ) -> Result<WeightedTree, TreeBuildError> {
+        let name = |symbol: Symbol| interner.resolve(symbol).to_string();
+        let mut nodes_by_symbol: Vec<Option<usize>> = vec![None; interner.len()];
+        for (i, (symbol, _, _)) in nodes.iter().enumerate() {
+            if nodes_by_symbol[symbol.index()].replace(i).is_some() {
+                return Err(TreeBuildError::DuplicateNode {
+                    name: name(*symbol),
+                });
+            }
+        }
+        // Resolve child names and check that each node has at most one parent
+        let mut children: Vec<Vec<usize>> = vec![];
+        let mut parents: Vec<Vec<usize>> = vec![vec![]; nodes.len()];
+        for (i, (symbol, _, child_symbols)) in nodes.iter().enumerate() {
+            let mut node_children = vec![];
+            for &child in child_symbols {
+                let child_index = nodes_by_symbol[child.index()].ok_or_else(|| {
+                    TreeBuildError::UndefinedChild {
+                        parent: name(*symbol),
+                        child: name(child),
+                    }
+                })?;
+                parents[child_index].push(i);
+                node_children.push(child_index);
+            }
+            children.push(node_children);
+        }
+        if let Some((child, node_parents)) = parents
+            .iter()
+            .enumerate()
+            .find(|(_, node_parents)| node_parents.len() > 1)
+        {
+            return Err(TreeBuildError::SharedChild {
+                child: name(nodes[child].0),
+                parents: node_parents.iter().map(|&p| name(nodes[p].0)).collect(),
+            });
+        }
+        let parents = parents
+            .iter()
+            .map(|node_parents| node_parents.first().copied())
+            .collect::<Vec<Option<usize>>>();
+        if let Some(cycle) = find_cycle(&parents) {
+            return Err(TreeBuildError::Cycle {
+                names: cycle.iter().map(|&i| name(nodes[i].0)).collect(),
+            });
+        }
+        // Tree must have exactly one node without a parent
+        let roots = (0..nodes.len())
+            .filter(|&i| parents[i].is_none())
+            .collect::<Vec<usize>>();
+        let root = match roots[..] {
+            [] => return Err(TreeBuildError::NoRoot),
+            [root] => root,
+            _ => {
+                return Err(TreeBuildError::MultipleRoots {
+                    names: roots.iter().map(|&i| name(nodes[i].0)).collect(),
+                })
+            }
+        };
+        let mut tree = WeightedTree {
+            interner,
+            symbols: nodes.iter().map(|(symbol, _, _)| *symbol).collect(),
+            nodes_by_symbol,
+            weights: nodes.iter().map(|(_, weight, _)| *weight).collect(),
+            children,
+            subtree_weights: vec![0; nodes.len()],
+            root,
+        };
+        tree.calculate_subtree_weights();
+        Ok(tree)
+    }
+
+    /// Gets the index of the root node.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Gets the number of nodes in the tree.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Checks if the tree has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Gets the index of the node with the given name, if it exists.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.nodes_by_symbol[self.interner.get(name)?.index()]
+    }
+
+    /// Gets the name of the node.
+    pub fn name(&self, node: usize) -> &str {
+        self.interner.resolve(self.symbols[node])
+    }
+
+    /// Gets the interned name of the node.
+    pub fn symbol(&self, node: usize) -> Symbol {
+        self.symbols[node]
+    }
+
+    /// Gets the [`Interner`] holding the names of the nodes.
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
+
+    /// Gets the weight of the node on its own.
+    pub fn weight(&self, node: usize) -> u64 {
+        self.weights[node]
+    }
+
+    /// Gets the total weight of the subtree rooted at the node.
+    pub fn subtree_weight(&self, node: usize) -> u64 {
+        self.subtree_weights[node]
+    }
+
+    /// Gets the children of the node.
+    pub fn children(&self, node: usize) -> &[usize] {
+        &self.children[node]
+    }
+
+    /// Finds the single node with the wrong weight, by following the child with a different
+    /// subtree weight to its siblings from the root until the children are all balanced.
+    ///
+    /// Returns None if the tree is balanced, or if the unbalanced child cannot be identified (e.g.
+    /// a node with only two children of different subtree weights).
+    pub fn find_unbalanced(&self) -> Option<Unbalanced> {
+        let mut node = self.root;
+        let mut expected_subtree_weight = None;
+        while let Some((child, expected)) = self.odd_child(node) {
+            node = child;
+            expected_subtree_weight = Some(expected);
+        }
+        let corrected_weight = (self.weights[node] + expected_subtree_weight?)
+            .checked_sub(self.subtree_weights[node])?;
+        Some(Unbalanced {
+            node,
+            corrected_weight,
+        })
+    }
+
+    /// Renders the tree as ASCII art, with one node per line giving its name, weight and subtree
+    /// weight. Nodes with a different subtree weight to their siblings are flagged along with the
+    /// subtree weight expected from the siblings.
+    pub fn render_tree(&self) -> String {
+        let mut output = String::new();
+        writeln!(output, "{}", self.render_node(self.root, None)).unwrap();
+        self.render_children(self.root, "", &mut output);
+        output
+    }
+
+    /// Renders the children of the node below it, indenting them with the given prefix.
+    fn render_children(&self, node: usize, prefix: &str, output: &mut String) {
+        let odd_child = self.odd_child(node);
+        let children = &self.children[node];
+        for (i, &child) in children.iter().enumerate() {
+            let is_last = i + 1 == children.len();
+            let expected = odd_child
+                .filter(|(odd, _)| *odd == child)
+                .map(|(_, expected)| expected);
+            let branch = if is_last { "└── " } else { "├── " };
+            writeln!(
+                output,
+                "{prefix}{branch}{}",
+                self.render_node(child, expected)
+            )
+            .unwrap();
+            let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+            self.render_children(child, &child_prefix, output);
+        }
+    }
+
+    /// Exports the tree as a Graphviz DOT directed graph, with an edge from each node to each of its
+    /// children. Nodes are labelled with their name, weight and subtree weight.
+    pub fn to_dot(&self) -> String {
+        let mut output = String::from("digraph tower {\n");
+        for node in self.preorder() {
+            writeln!(
+                output,
+                "    {} [label={}];",
+                quote(self.name(node)),
+                quote(&format!(
+                    "{} ({}) [{}]",
+                    self.name(node),
+                    self.weights[node],
+                    self.subtree_weights[node]
+                ))
+            )
+            .unwrap();
+            for &child in &self.children[node] {
+                writeln!(
+                    output,
+                    "    {} -> {};",
+                    quote(self.name(node)),
+                    quote(self.name(child))
+                )
+                .unwrap();
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Exports the tree as a JSON object giving the name of the root node and the name, weight,
+    /// subtree weight and child names of each node (one node per line).
+    pub fn to_json(&self) -> String {
+        let nodes = self
+            .preorder()
+            .iter()
+            .map(|&node| {
+                let children = self.children[node]
+                    .iter()
+                    .map(|&child| quote(self.name(child)))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!(
+                    "    {{\"name\": {}, \"weight\": {}, \"subtree_weight\": {}, \"children\": [{children}]}}",
+                    quote(self.name(node)),
+                    self.weights[node],
+                    self.subtree_weights[node]
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",\n");
+        format!(
+            "{{\n  \"root\": {},\n  \"nodes\": [\n{nodes}\n  ]\n}}\n",
+            quote(self.name(self.root))
+        )
+    }
+
+    /// Gets the nodes of the tree in depth-first order, with each node followed by its children.
+    fn preorder(&self) -> Vec<usize> {
+        let mut order = vec![];
+        let mut stack = vec![self.root];
+        while let Some(node) = stack.pop() {
+            order.push(node);
+            stack.extend(self.children[node].iter().rev());
+        }
+        order
+    }
+
+    /// Renders a single node, flagging it as unbalanced if an expected subtree weight is given.
+    fn render_node(&self, node: usize, expected: Option<u64>) -> String {
+        let mut line = format!(
+            "{} ({}) [{}]",
+            self.name(node),
+            self.weights[node],
+            self.subtree_weights[node]
+        );
+        if let Some(expected) = expected {
+            line.push_str(&format!(" <-- unbalanced, expected [{expected}]"));
+        }
+        line
+    }
+
+    /// Finds the child of the node with a different subtree weight to all of its siblings, which
+    /// share the same subtree weight. Returned value is the child and the subtree weight of its
+    /// siblings.
+    fn odd_child(&self, node: usize) -> Option<(usize, u64)> {
+        let children = &self.children[node];
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        for &child in children {
+            *counts.entry(self.subtree_weights[child]).or_insert(0) += 1;
+        }
+        if counts.len() != 2 {
+            return None;
+        }
+        let (&odd_weight, _) = counts.iter().find(|(_, count)| **count == 1)?;
+        let (&expected, _) = counts.iter().find(|(_, count)| **count > 1)?;
+        let &odd = children
+            .iter()
+            .find(|&&child| self.subtree_weights[child] == odd_weight)?;
+        Some((odd, expected))
+    }
+
+    /// Calculates the subtree weight of every node, visiting children before their parents.
+    fn calculate_subtree_weights(&mut self) {
+        let mut order = vec![self.root];
+        let mut i = 0;
+        while i < order.len() {
+            order.extend(self.children[order[i]].iter().copied());
+            i += 1;
+        }
+        for &node in order.iter().rev() {
+            self.subtree_weights[node] = self.weights[node]
+                + self.children[node]
+                    .iter()
+                    .map(|&child| self.subtree_weights[child])
+                    .sum::<u64>();
+        }
+    }
+}
+
+/// Quotes the string for use in DOT and JSON output, escaping quotes, backslashes and control
+/// characters.
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Finds a cycle in the parent links of the nodes, where each node has at most one parent.
+///
+/// Returned value is the nodes in the first cycle found (in parent-to-child order, starting from the
+/// earliest node in the cycle), or None if there are no cycles.
+fn find_cycle(parents: &[Option<usize>]) -> Option<Vec<usize>> {
+    // Nodes known to lead to a root without passing through a cycle
+    let mut leads_to_root = vec![false; parents.len()];
+    for start in 0..parents.len() {
+        let mut path: Vec<usize> = vec![];
+        let mut node = Some(start);
+        while let Some(current) = node {
+            if leads_to_root[current] {
+                break;
+            }
+            if let Some(pos) = path.iter().position(|&n| n == current) {
+                // Path has looped back on itself, so reverse the child-to-parent path
+                let mut cycle = path[pos..].iter().rev().copied().collect::<Vec<usize>>();
+                let first = cycle.iter().enumerate().min_by_key(|(_, &n)| n).unwrap().0;
+                cycle.rotate_left(first);
+                return Some(cycle);
+            }
+            path.push(current);
+            node = parents[current];
+        }
+        for n in path {
+            leads_to_root[n] = true;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builds the example tower given in the AOC 2017 Day 07 problem statement.
+    fn example_tree() -> WeightedTree {
+        let nodes = [
+            ("pbga", 66, vec![]),
+            ("xhth", 57, vec![]),
+            ("ebii", 61, vec![]),
+            ("havc", 66, vec![]),
+            ("ktlj", 57, vec![]),
+            ("fwft", 72, vec!["ktlj", "cntj", "xhth"]),
+            ("qoyq", 66, vec![]),
+            ("padx", 45, vec!["pbga", "havc", "qoyq"]),
+            ("tknk", 41, vec!["ugml", "padx", "fwft"]),
+            ("jptl", 61, vec![]),
+            ("ugml", 68, vec!["gyxo", "ebii", "jptl"]),
+            ("gyxo", 61, vec![]),
+            ("cntj", 57, vec![]),
+        ]
+        .map(|(name, weight, children)| {
+            (
+                name.to_string(),
+                weight,
+                children.iter().map(|c| c.to_string()).collect(),
+            )
+        });
+        WeightedTree::from_nodes(&nodes).unwrap()
+    }
+
+    /// Tests the root, subtree weights and unbalanced node found for the example tower.
+    #[test]
+    fn test_weighted_tree_example() {
+        let tree = example_tree();
+        assert_eq!("tknk", tree.name(tree.root()));
+        let ugml = tree.find("ugml").unwrap();
+        assert_eq!(251, tree.subtree_weight(ugml));
+        assert_eq!(778, tree.subtree_weight(tree.root()));
+        assert_eq!(
+            Some(Unbalanced {
+                node: ugml,
+                corrected_weight: 60
+            }),
+            tree.find_unbalanced()
+        );
+    }
+
+    /// Tests that each of the ways the nodes can fail to form a single tree is reported.
+    #[test]
+    fn test_weighted_tree_build_errors() {
+        let build = |nodes: &[(&str, &[&str])]| {
+            let nodes = nodes
+                .iter()
+                .map(|(name, children)| {
+                    let children = children.iter().map(|c| c.to_string()).collect();
+                    (name.to_string(), 1, children)
+                })
+                .collect::<Vec<(String, u64, Vec<String>)>>();
+            WeightedTree::from_nodes(&nodes)
+        };
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect();
+        assert_eq!(
+            Err(TreeBuildError::DuplicateNode {
+                name: String::from("a")
+            }),
+            build(&[("a", &[]), ("b", &["a"]), ("a", &[])])
+        );
+        assert_eq!(
+            Err(TreeBuildError::UndefinedChild {
+                parent: String::from("a"),
+                child: String::from("x")
+            }),
+            build(&[("a", &["b", "x"]), ("b", &[])])
+        );
+        assert_eq!(
+            Err(TreeBuildError::SharedChild {
+                child: String::from("c"),
+                parents: names(&["a", "b"])
+            }),
+            build(&[("a", &["b", "c"]), ("b", &["c"]), ("c", &[])])
+        );
+        assert_eq!(
+            Err(TreeBuildError::Cycle {
+                names: names(&["d", "b", "c"])
+            }),
+            build(&[("a", &[]), ("d", &["b"]), ("b", &["c"]), ("c", &["d"])])
+        );
+        assert_eq!(
+            Err(TreeBuildError::MultipleRoots {
+                names: names(&["a", "c"])
+            }),
+            build(&[("a", &["b"]), ("b", &[]), ("c", &[])])
+        );
+        assert_eq!(Err(TreeBuildError::NoRoot), build(&[]));
+    }
+
+    /// Tests the ASCII rendering of the example tower, including the flag on the unbalanced node.
+    #[test]
+    fn test_weighted_tree_render() {
+        let rendered = example_tree().render_tree();
+        let lines = rendered.lines().collect::<Vec<&str>>();
+        assert_eq!(13, lines.len());
+        assert_eq!("tknk (41) [778]", lines[0]);
+        assert_eq!(
+            "├── ugml (68) [251] <-- unbalanced, expected [243]",
+            lines[1]
+        );
+        assert_eq!("│   ├── gyxo (61) [61]", lines[2]);
+        assert_eq!("    └── xhth (57) [57]", lines[12]);
+    }
+}
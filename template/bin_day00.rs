@@ -0,0 +1,8 @@
+use aoc2017_core::days::day00::Day00;
+use aoc2017_cli::scaffold::run;
+
+/// Processes the AOC 2017 Day 00 input file and solves both parts of the problem. Solutions are
+/// printed to stdout.
+pub fn main() {
+    run::<Day00>();
+}
@@ -1,65 +1,58 @@
 use std::fs;
-use std::time::Instant;
+
+use crate::scaffold::{Answer, Solver};
 
 const PROBLEM_NAME: &str = "###";
 const PROBLEM_INPUT_FILE: &str = "./input/day00.txt";
 const PROBLEM_DAY: u64 = 0;
 
-/// Processes the AOC 2017 Day 00 input file and solves both parts of the problem. Solutions are
-/// printed to stdout.
-pub fn main() {
-    let start = Instant::now();
-    // Input processing
-    let input = process_input_file(PROBLEM_INPUT_FILE);
-    let input_parser_timestamp = Instant::now();
-    let input_parser_duration = input_parser_timestamp.duration_since(start);
-    // Solve part 1
-    let p1_solution = solve_part1(&input);
-    let p1_timestamp = Instant::now();
-    let p1_duration = p1_timestamp.duration_since(input_parser_timestamp);
-    // Solve part 2
-    let p2_solution = solve_part2(&input);
-    let p2_timestamp = Instant::now();
-    let p2_duration = p2_timestamp.duration_since(p1_timestamp);
-    // Print results
-    println!("==================================================");
-    println!("AOC 2017 Day {PROBLEM_DAY} - \"{PROBLEM_NAME}\"");
-    println!("[+] Part 1: {p1_solution}");
-    println!("[+] Part 2: {p2_solution}");
-    println!("~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~");
-    println!("Execution times:");
-    println!("[+] Input:  {input_parser_duration:.2?}");
-    println!("[+] Part 1: {p1_duration:.2?}");
-    println!("[+] Part 2: {p2_duration:.2?}");
-    println!(
-        "[*] TOTAL:  {:.2?}",
-        input_parser_duration + p1_duration + p2_duration
-    );
-    println!("==================================================");
+/// Solver for AOC 2017 Day 00.
+pub struct Day00;
+
+impl Solver for Day00 {
+    type Input = String;
+
+    const DAY: u64 = PROBLEM_DAY;
+    const NAME: &'static str = PROBLEM_NAME;
+    const INPUT_FILE: &'static str = PROBLEM_INPUT_FILE;
+
+    fn process_input_file(filename: &str) -> Self::Input {
+        process_input_file(filename)
+    }
+
+    fn solve_part1(input: &Self::Input) -> Option<Answer> {
+        // Replace with `Some(solve_part1(input).into())` once the part is implemented
+        solve_part1(input).map(Answer::from)
+    }
+
+    fn solve_part2(input: &Self::Input) -> Option<Answer> {
+        // Replace with `Some(solve_part2(input).into())` once the part is implemented
+        solve_part2(input).map(Answer::from)
+    }
 }
 
 /// Processes the AOC 2017 Day 00 input file in the format required by the solver functions.
-/// 
+///
 /// Returned value is ###.
 fn process_input_file(filename: &str) -> String {
     // Read contents of problem input file
-    let _raw_input = fs::read_to_string(filename).unwrap();
+    let raw_input = fs::read_to_string(filename).unwrap();
     // Process input file contents into data structure
-    unimplemented!();
+    raw_input.trim().to_string()
 }
 
 /// Solves AOC 2017 Day 00 Part 1.
-/// 
+///
 /// ###
-fn solve_part1(_input: &String) -> String {
-    unimplemented!();
+fn solve_part1(_input: &str) -> Option<String> {
+    None
 }
 
 /// Solves AOC 2017 Day 00 Part 2.
-/// 
+///
 /// ###
-fn solve_part2(_input: &String) -> String {
-    unimplemented!();
+fn solve_part2(_input: &str) -> Option<String> {
+    None
 }
 
 #[cfg(test)]
@@ -70,17 +63,17 @@ mod test {
     #[test]
     fn test_day00_part1_actual() {
         let input = process_input_file(PROBLEM_INPUT_FILE);
-        let _solution = solve_part1(&input);
-        unimplemented!();
-        // assert_eq!("###", solution);
+        let solution = solve_part1(&input);
+        // Replace with the actual problem solution once known
+        assert_eq!(None, solution);
     }
 
     /// Tests the Day 00 Part 2 solver method against the actual problem solution.
     #[test]
     fn test_day00_part2_actual() {
         let input = process_input_file(PROBLEM_INPUT_FILE);
-        let _solution = solve_part2(&input);
-        unimplemented!();
-        // assert_eq!("###", solution);
+        let solution = solve_part2(&input);
+        // Replace with the actual problem solution once known
+        assert_eq!(None, solution);
     }
 }